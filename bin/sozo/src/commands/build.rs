@@ -38,6 +38,10 @@ pub struct BuildArgs {
     #[arg(help = "Generate Unreal Engine bindings.")]
     pub unrealengine: bool,
 
+    #[arg(long)]
+    #[arg(help = "Generate a JSON manifest of the models, events and contracts.")]
+    pub json: bool,
+
     #[arg(long)]
     #[arg(help = "Output directory.", default_value = "bindings")]
     pub bindings_output: String,
@@ -138,6 +142,10 @@ impl BuildArgs {
             builtin_plugins.push(BuiltinPlugins::UnrealEngine);
         }
 
+        if self.json {
+            builtin_plugins.push(BuiltinPlugins::Json);
+        }
+
         // Custom plugins are always empty for now.
         let bindgen = PluginManager {
             profile_name: ws.current_profile().expect("Profile expected").to_string(),
@@ -223,6 +231,7 @@ impl Default for BuildArgs {
             recs: false,
             unity: false,
             unrealengine: false,
+            json: false,
             bindings_output: "bindings".to_string(),
             stats: StatOptions::default(),
             packages: None,
@@ -8,10 +8,11 @@ use cainome::parser::{AbiParser, TokenizedAbi};
 use camino::Utf8PathBuf;
 use dojo_world::config::ProfileConfig;
 pub mod error;
-use dojo_world::local::{ResourceLocal, WorldLocal};
+use dojo_world::local::{Member, ResourceLocal, WorldLocal};
 use error::BindgenResult;
 
 mod plugins;
+use plugins::json::JsonPlugin;
 use plugins::recs::TypescriptRecsPlugin;
 use plugins::typescript::TypescriptPlugin;
 use plugins::typescript_v2::TypeScriptV2Plugin;
@@ -27,6 +28,8 @@ pub struct DojoModel {
     /// List of tokens found in the model contract ABI.
     /// Only structs and enums are currently used.
     pub tokens: TokenizedAbi,
+    /// The members of the model, in declaration order.
+    pub members: Vec<Member>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -36,6 +39,8 @@ pub struct DojoEvent {
     /// List of tokens found in the event contract ABI.
     /// Only structs and enums are currently used.
     pub tokens: TokenizedAbi,
+    /// The members of the event, in declaration order.
+    pub members: Vec<Member>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -105,6 +110,7 @@ impl PluginManager {
                 BuiltinPlugins::UnrealEngine => Box::new(UnrealEnginePlugin::new()),
                 BuiltinPlugins::TypeScriptV2 => Box::new(TypeScriptV2Plugin::new()),
                 BuiltinPlugins::Recs => Box::new(TypescriptRecsPlugin::new()),
+                BuiltinPlugins::Json => Box::new(JsonPlugin::new()),
             };
 
             let files = builder.generate_code(&data).await?;
@@ -173,12 +179,14 @@ fn gather_dojo_data(
             ResourceLocal::Model(m) => {
                 let tokens = AbiParser::collect_tokens(&m.common.class.abi, &HashMap::new())?;
                 let tag = r.tag();
-                models.insert(tag.clone(), DojoModel { tag, tokens: filter_model_tokens(&tokens) });
+                let tokens = filter_model_tokens(&tokens);
+                models.insert(tag.clone(), DojoModel { tag, tokens, members: m.members.clone() });
             }
-            ResourceLocal::Event(m) => {
-                let tokens = AbiParser::collect_tokens(&m.common.class.abi, &HashMap::new())?;
+            ResourceLocal::Event(e) => {
+                let tokens = AbiParser::collect_tokens(&e.common.class.abi, &HashMap::new())?;
                 let tag = r.tag();
-                events.insert(tag.clone(), DojoEvent { tag, tokens: filter_model_tokens(&tokens) });
+                let tokens = filter_model_tokens(&tokens);
+                events.insert(tag.clone(), DojoEvent { tag, tokens, members: e.members.clone() });
             }
             _ => {}
         }
@@ -270,6 +278,8 @@ mod tests {
 
         let pos = data.models.get("ns-Position").unwrap();
         assert_eq!(pos.tag, "ns-Position");
+        assert_eq!(pos.members[0].name, "player");
+        assert!(pos.members[0].key);
 
         let moves = data.models.get("ns-Moves").unwrap();
         assert_eq!(moves.tag, "ns-Moves");
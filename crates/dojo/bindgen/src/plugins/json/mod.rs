@@ -0,0 +1,177 @@
+//! Generates a machine-readable manifest of the models, events and contracts of the world, to be
+//! consumed by external code generators.
+//!
+//! The members of models and events are listed in declaration order, which is also the order in
+//! which they are serialized on-chain.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use dojo_world::contracts::naming::compute_selector_from_tag;
+use dojo_world::local::Member;
+use serde::Serialize;
+use starknet::core::types::Felt;
+use starknet::core::utils::get_selector_from_name;
+
+use crate::error::BindgenResult;
+use crate::plugins::BuiltinPlugin;
+use crate::DojoData;
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+#[derive(Debug)]
+pub struct JsonPlugin {}
+
+impl JsonPlugin {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Manifest<'a> {
+    world: &'a str,
+    models: Vec<ResourceManifest<'a>>,
+    events: Vec<ResourceManifest<'a>>,
+    contracts: Vec<ContractManifest<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct ResourceManifest<'a> {
+    tag: &'a str,
+    selector: Felt,
+    members: Vec<MemberManifest<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct MemberManifest<'a> {
+    name: &'a str,
+    #[serde(rename = "type")]
+    ty: &'a str,
+    key: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ContractManifest<'a> {
+    tag: &'a str,
+    selector: Felt,
+    systems: Vec<SystemManifest<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct SystemManifest<'a> {
+    name: &'a str,
+    selector: Felt,
+}
+
+impl<'a> ResourceManifest<'a> {
+    fn new(tag: &'a str, members: &'a [Member]) -> Self {
+        let members = members
+            .iter()
+            .map(|m| MemberManifest { name: &m.name, ty: &m.ty, key: m.key })
+            .collect();
+        Self { tag, selector: compute_selector_from_tag(tag), members }
+    }
+}
+
+#[async_trait]
+impl BuiltinPlugin for JsonPlugin {
+    async fn generate_code(&self, data: &DojoData) -> BindgenResult<HashMap<PathBuf, Vec<u8>>> {
+        let mut models = data
+            .models
+            .values()
+            .map(|m| ResourceManifest::new(&m.tag, &m.members))
+            .collect::<Vec<_>>();
+
+        let mut events = data
+            .events
+            .values()
+            .map(|e| ResourceManifest::new(&e.tag, &e.members))
+            .collect::<Vec<_>>();
+
+        let mut contracts = data
+            .contracts
+            .values()
+            .map(|c| {
+                let systems = c
+                    .systems
+                    .iter()
+                    .filter_map(|s| s.to_function().ok())
+                    .map(|f| SystemManifest {
+                        name: &f.name,
+                        selector: get_selector_from_name(&f.name).expect("invalid system name"),
+                    })
+                    .collect();
+
+                ContractManifest {
+                    tag: &c.tag,
+                    selector: compute_selector_from_tag(&c.tag),
+                    systems,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Sort the resources based on their tag to ensure deterministic output.
+        models.sort_by(|a, b| a.tag.cmp(b.tag));
+        events.sort_by(|a, b| a.tag.cmp(b.tag));
+        contracts.sort_by(|a, b| a.tag.cmp(b.tag));
+
+        let manifest = Manifest { world: &data.world.name, models, events, contracts };
+        let content = serde_json::to_vec_pretty(&manifest)?;
+
+        Ok(HashMap::from([(PathBuf::from(MANIFEST_FILE), content)]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cainome::parser::TokenizedAbi;
+    use serde_json::Value;
+
+    use super::*;
+    use crate::{DojoContract, DojoModel, DojoWorld};
+
+    #[tokio::test]
+    async fn generates_manifest() {
+        let members = vec![
+            Member { name: "player".into(), ty: "ContractAddress".into(), key: true },
+            Member { name: "x".into(), ty: "u32".into(), key: false },
+        ];
+
+        let model =
+            DojoModel { tag: "ns-Position".into(), tokens: TokenizedAbi::default(), members };
+        let contract = DojoContract {
+            tag: "ns-actions".into(),
+            tokens: TokenizedAbi::default(),
+            systems: vec![],
+        };
+
+        let data = DojoData {
+            world: DojoWorld { name: "ns".into() },
+            models: HashMap::from([(model.tag.clone(), model)]),
+            contracts: HashMap::from([(contract.tag.clone(), contract)]),
+            events: HashMap::new(),
+        };
+
+        let files = JsonPlugin::new().generate_code(&data).await.unwrap();
+        let manifest: Value =
+            serde_json::from_slice(&files[&PathBuf::from(MANIFEST_FILE)]).unwrap();
+
+        assert_eq!(manifest["world"], "ns");
+        assert_eq!(manifest["events"], Value::Array(vec![]));
+
+        let model = &manifest["models"][0];
+        assert_eq!(model["tag"], "ns-Position");
+        assert_eq!(model["selector"], format!("{:#x}", compute_selector_from_tag("ns-Position")));
+        assert_eq!(model["members"][0]["name"], "player");
+        assert_eq!(model["members"][0]["type"], "ContractAddress");
+        assert_eq!(model["members"][0]["key"], true);
+        assert_eq!(model["members"][1]["name"], "x");
+        assert_eq!(model["members"][1]["key"], false);
+
+        let contract = &manifest["contracts"][0];
+        assert_eq!(contract["tag"], "ns-actions");
+        assert_eq!(contract["selector"], format!("{:#x}", compute_selector_from_tag("ns-actions")));
+    }
+}
@@ -9,6 +9,7 @@ use cainome::parser::tokens::{Composite, Function};
 use crate::error::BindgenResult;
 use crate::{DojoContract, DojoData};
 
+pub mod json;
 pub mod recs;
 pub mod typescript;
 pub mod typescript_v2;
@@ -22,6 +23,7 @@ pub enum BuiltinPlugins {
     UnrealEngine,
     TypeScriptV2,
     Recs,
+    Json,
 }
 
 impl fmt::Display for BuiltinPlugins {
@@ -32,6 +34,7 @@ impl fmt::Display for BuiltinPlugins {
             BuiltinPlugins::UnrealEngine => write!(f, "unrealengine"),
             BuiltinPlugins::TypeScriptV2 => write!(f, "typescript_v2"),
             BuiltinPlugins::Recs => write!(f, "recs"),
+            BuiltinPlugins::Json => write!(f, "json"),
         }
     }
 }
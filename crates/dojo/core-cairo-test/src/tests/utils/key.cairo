@@ -1,4 +1,6 @@
-use dojo::utils::{entity_id_from_serialized_keys, combine_key};
+use dojo::utils::{
+    entity_id_from_serialized_keys, combine_key, entity_id_from_keys, entity_ids_from_key_range,
+};
 
 #[test]
 fn test_entity_id_from_keys() {
@@ -15,3 +17,14 @@ fn test_combine_key() {
         combine_key(1, 2) == core::poseidon::poseidon_hash_span([1, 2].span()), 'combine key error',
     );
 }
+
+#[test]
+fn test_entity_ids_from_key_range() {
+    let ids = entity_ids_from_key_range(10, 3);
+    assert(ids.len() == 3, 'bad ids length');
+    assert(*ids[0] == entity_id_from_keys(@10_u32), 'bad first ID');
+    assert(*ids[1] == entity_id_from_keys(@11_u32), 'bad second ID');
+    assert(*ids[2] == entity_id_from_keys(@12_u32), 'bad third ID');
+
+    assert(entity_ids_from_key_range(10, 0).len() == 0, 'expected no ids');
+}
@@ -5,7 +5,7 @@ use dojo::world::{
     IWorldDispatcher, IWorldDispatcherTrait, IUpgradeableWorldDispatcher,
     IUpgradeableWorldDispatcherTrait, WorldStorageTrait,
 };
-use dojo::model::ModelStorage;
+use dojo::model::{Model, ModelStorage};
 use dojo::event::{Event, EventStorage};
 
 use crate::tests::helpers::{
@@ -51,6 +51,29 @@ fn test_delete() {
     assert(deleted.b == 0, 'data not deleted');
 }
 
+#[test]
+fn test_delete_emits_store_del_record() {
+    let (world, bar_contract) = deploy_world_and_bar();
+
+    bar_contract.set_foo(1337, 1337);
+    drop_all_events(world.dispatcher.contract_address);
+
+    bar_contract.delete_foo();
+
+    let event = starknet::testing::pop_log::<WorldEvent>(world.dispatcher.contract_address);
+
+    assert(event.is_some(), 'no event');
+
+    if let WorldEvent::StoreDelRecord(event) = event.unwrap() {
+        let ptr = Model::<Foo>::ptr_from_keys(starknet::get_caller_address());
+
+        assert(event.selector == Model::<Foo>::selector(world.namespace_hash), 'bad model selector');
+        assert(event.entity_id == ptr.id, 'bad entity id');
+    } else {
+        core::panic_with_felt252('no StoreDelRecord event');
+    }
+}
+
 #[test]
 #[available_gas(6000000)]
 fn test_contract_getter() {
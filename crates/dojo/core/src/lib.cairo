@@ -76,7 +76,9 @@ pub mod utils {
     };
 
     pub mod key;
-    pub use key::{entity_id_from_serialized_keys, combine_key, entity_id_from_keys};
+    pub use key::{
+        entity_id_from_serialized_keys, combine_key, entity_id_from_keys, entity_ids_from_key_range,
+    };
 
     pub mod layout;
     pub use layout::{find_field_layout, find_model_field_layout};
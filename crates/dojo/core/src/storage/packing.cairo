@@ -1,3 +1,21 @@
+//! Bit packing of model values.
+//!
+//! Models deriving `DojoPacked` have a `Layout::Fixed` layout, where every item of the layout
+//! is the size in bits of a member. Instead of using one storage slot per member, the members are
+//! packed together so that small members (eg. a `u8` status or a `bool` flag) share a slot.
+//!
+//! The packing is deterministic, so it can be decoded off-chain from the layout only:
+//! - members are packed in the order of the layout, starting from the least significant bit of
+//!   the first felt,
+//! - a member is stored at the bit offset following the previous member of the same felt,
+//! - only the 251 lower bits of a felt are used, and a member never spans two felts: if it
+//!   doesn't fit in the remaining bits, the current felt is closed and the member starts at the
+//!   bit 0 of the next felt,
+//! - the packed felts are then written to consecutive storage slots.
+//!
+//! Packing only applies within a single model: the members of different models never share a
+//! slot, even if they belong to the same entity.
+
 use core::array::{ArrayTrait, SpanTrait};
 use core::option::OptionTrait;
 use core::traits::{Into, TryInto};
@@ -67,7 +85,7 @@ pub fn unpack(ref unpacked: Array<felt252>, ref packed: Span<felt252>, ref layou
     }
 }
 
-/// Pack the proposal fields into a single felt252.
+/// Packs `self` on `size` bits into the `packing` felt, moving to a new felt if it doesn't fit.
 pub fn pack_inner(
     self: @felt252,
     size: u8,
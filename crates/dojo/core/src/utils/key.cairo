@@ -22,3 +22,27 @@ pub fn combine_key(parent_key: felt252, child_key: felt252) -> felt252 {
 pub fn entity_id_from_keys<K, +Serde<K>>(keys: @K) -> felt252 {
     entity_id_from_serialized_keys(serialize_inline::<K>(keys))
 }
+
+/// Computes the entity ids of `count` entities identified by sequential keys, starting
+/// from `base_key`.
+///
+/// The entity at index `i` is identified by the single key `base_key + i`, so its id is
+/// the same as `entity_id_from_keys(@(base_key + i))`.
+///
+/// # Arguments
+///
+/// * `base_key` - The key of the first entity.
+/// * `count` - The number of entities.
+///
+/// # Returns
+///
+/// The entity ids, in increasing key order.
+pub fn entity_ids_from_key_range(base_key: felt252, count: u32) -> Array<felt252> {
+    let mut ids = array![];
+    let mut i: u32 = 0;
+    while i < count {
+        ids.append(entity_id_from_serialized_keys([base_key + i.into()].span()));
+        i += 1;
+    };
+    ids
+}
@@ -13,7 +13,7 @@ use super::attribute_macros::{
     DOJO_MODEL_ATTR,
 };
 use super::derive_macros::{dojo_derive_all, DOJO_INTROSPECT_DERIVE, DOJO_PACKED_DERIVE};
-use super::inline_macros::{BytearrayHashMacro, SelectorFromTagMacro};
+use super::inline_macros::{BytearrayHashMacro, EmitMacro, SelectorFromTagMacro, WithMacroName};
 use crate::attribute_macros::DojoLibrary;
 
 // #[cfg(test)]
@@ -29,8 +29,9 @@ pub fn dojo_plugin_suite() -> PluginSuite {
 
     suite
         .add_plugin::<BuiltinDojoPlugin>()
-        .add_inline_macro_plugin::<SelectorFromTagMacro>()
-        .add_inline_macro_plugin::<BytearrayHashMacro>();
+        .add_inline_macro_plugin::<WithMacroName<SelectorFromTagMacro>>()
+        .add_inline_macro_plugin::<WithMacroName<BytearrayHashMacro>>()
+        .add_inline_macro_plugin::<WithMacroName<EmitMacro>>();
 
     suite
 }
@@ -69,7 +70,24 @@ impl MacroPlugin for BuiltinDojoPlugin {
                 }
             }
             ast::ModuleItem::Enum(enum_ast) => {
-                dojo_derive_all(db, enum_ast.attributes(db).query_attr(db, "derive"), &item_ast)
+                // Models and events are identified by their keys, which an enum can't have. Enums
+                // must be stored as members of a model or an event instead.
+                let attrs = enum_ast.attributes(db);
+                for attr in [DOJO_MODEL_ATTR, DOJO_EVENT_ATTR] {
+                    if attrs.has_attr(db, attr) {
+                        return PluginResult::diagnostic_only(PluginDiagnostic {
+                            stable_ptr: enum_ast.stable_ptr().0,
+                            message: format!(
+                                "The enum {} can't be a {attr}. Use it as a member of a struct \
+                                 with the {attr} attribute instead.",
+                                enum_ast.name(db).text(db)
+                            ),
+                            severity: Severity::Error,
+                        });
+                    }
+                }
+
+                dojo_derive_all(db, attrs.query_attr(db, "derive"), &item_ast)
             }
             ast::ModuleItem::Struct(struct_ast) => {
                 let n_model_attrs = struct_ast.attributes(db).query_attr(db, DOJO_MODEL_ATTR).len();
@@ -113,3 +131,54 @@ impl MacroPlugin for BuiltinDojoPlugin {
         vec![DOJO_INTROSPECT_DERIVE.to_string(), DOJO_PACKED_DERIVE.to_string()]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cairo_lang_filesystem::cfg::CfgSet;
+    use cairo_lang_filesystem::db::Edition;
+    use cairo_lang_parser::utils::SimpleParserDatabase;
+    use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+
+    use super::*;
+
+    /// Runs the dojo plugin on the first item of the given source.
+    fn generate_code(source: &str) -> PluginResult {
+        let db = SimpleParserDatabase::default();
+        let root = db.parse_virtual(source).unwrap();
+        let file = ast::SyntaxFile::from_syntax_node(&db, root);
+        let item = file.items(&db).elements(&db).remove(0);
+
+        let cfg_set = CfgSet::new();
+        let declared_derives = OrderedHashSet::default();
+        let allowed_features = OrderedHashSet::default();
+        let metadata = MacroPluginMetadata {
+            cfg_set: &cfg_set,
+            declared_derives: &declared_derives,
+            allowed_features: &allowed_features,
+            edition: Edition::default(),
+        };
+
+        BuiltinDojoPlugin.generate_code(&db, item, &metadata)
+    }
+
+    #[test]
+    fn reject_model_and_event_enums() {
+        for attr in [DOJO_MODEL_ATTR, DOJO_EVENT_ATTR] {
+            let result = generate_code(&format!("#[{attr}]\nenum Direction {{ Left, Right }}"));
+
+            assert!(result.code.is_none());
+            assert_eq!(result.diagnostics.len(), 1);
+            assert_eq!(
+                result.diagnostics[0].message,
+                format!(
+                    "The enum Direction can't be a {attr}. Use it as a member of a struct with \
+                     the {attr} attribute instead."
+                )
+            );
+        }
+
+        // enums without those attributes are still accepted
+        let result = generate_code("#[derive(Drop)]\nenum Direction { Left, Right }");
+        assert!(result.diagnostics.is_empty());
+    }
+}
@@ -42,28 +42,27 @@ impl InlineMacroExprPlugin for EmitMacro {
             };
         }
 
-        let world = &args[0];
+        let ast::ArgClause::Unnamed(world) = args[0].arg_clause(db) else {
+            return unsupported_arg_diagnostic(db, syntax);
+        };
 
-        let ast::ArgClause::Unnamed(models) = args[1].arg_clause(db) else {
+        let ast::ArgClause::Unnamed(events) = args[1].arg_clause(db) else {
             return unsupported_arg_diagnostic(db, syntax);
         };
 
         let mut bundle = vec![];
 
-        match models.value(db) {
+        match events.value(db) {
             ast::Expr::Parenthesized(parens) => {
-                let syntax_node = parens.expr(db).as_syntax_node();
-                bundle.push(syntax_node.get_text(db));
+                bundle.push(parens.expr(db).as_syntax_node());
             }
             ast::Expr::Tuple(list) => {
                 list.expressions(db).elements(db).into_iter().for_each(|expr| {
-                    let syntax_node = expr.as_syntax_node();
-                    bundle.push(syntax_node.get_text(db));
+                    bundle.push(expr.as_syntax_node());
                 })
             }
             ast::Expr::StructCtorCall(ctor) => {
-                let syntax_node = ctor.as_syntax_node();
-                bundle.push(syntax_node.get_text(db));
+                bundle.push(ctor.as_syntax_node());
             }
             _ => {
                 return InlinePluginResult {
@@ -81,22 +80,21 @@ impl InlineMacroExprPlugin for EmitMacro {
             return InlinePluginResult {
                 code: None,
                 diagnostics: vec![PluginDiagnostic {
-                    message: "Invalid arguments: No models provided.".to_string(),
+                    message: "Invalid arguments: No events provided.".to_string(),
                     stable_ptr: arg_list.arguments(db).stable_ptr().untyped(),
                     severity: Severity::Error,
                 }],
             };
         }
 
+        // the world and events are added as nodes, so that diagnostics on the generated code
+        // point to them in the user code.
         for event in bundle {
-            builder.add_str(&format!(
-                "
-                let __event_instance__ = {};
-                dojo::event::Event::emit(@__event_instance__, {});
-                ",
-                event,
-                world.as_syntax_node().get_text(db),
-            ));
+            builder.add_str("\nlet __event_instance__ = ");
+            builder.add_node(event);
+            builder.add_str(";\ndojo::event::EventStorage::emit_event(ref ");
+            builder.add_node(world.value(db).as_syntax_node());
+            builder.add_str(", @__event_instance__);\n");
         }
 
         builder.add_str("}");
@@ -115,3 +113,52 @@ impl InlineMacroExprPlugin for EmitMacro {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cairo_lang_defs::plugin::NamedPlugin;
+
+    use super::EmitMacro;
+    use crate::cairo_plugin::dojo_plugin_suite;
+    use crate::inline_macros::test_utils::expand_inline_macro;
+
+    const EMIT_EVENT: &str =
+        "dojo::event::EventStorage::emit_event(ref world, @__event_instance__);";
+
+    #[test]
+    fn emit_is_registered() {
+        assert!(dojo_plugin_suite().inline_macro_plugins.contains_key(EmitMacro::NAME));
+    }
+
+    #[test]
+    fn emit_single_event() {
+        let result =
+            expand_inline_macro(&EmitMacro, "emit!(world, (Moved { player, direction }));");
+        assert!(result.diagnostics.is_empty());
+
+        let code = result.code.unwrap().content;
+        assert!(code.contains("let __event_instance__ = Moved { player, direction };"));
+        assert_eq!(code.matches(EMIT_EVENT).count(), 1);
+    }
+
+    #[test]
+    fn emit_several_events() {
+        let result = expand_inline_macro(
+            &EmitMacro,
+            "emit!(world, (Moved { player, direction }, Died { player }));",
+        );
+        assert!(result.diagnostics.is_empty());
+
+        let code = result.code.unwrap().content;
+        assert!(code.contains("let __event_instance__ = Moved { player, direction };"));
+        assert!(code.contains("let __event_instance__ = Died { player };"));
+        assert_eq!(code.matches(EMIT_EVENT).count(), 2);
+    }
+
+    #[test]
+    fn emit_without_events() {
+        let result = expand_inline_macro(&EmitMacro, "emit!(world, ());");
+        assert!(result.code.is_none());
+        assert_eq!(result.diagnostics[0].message, "Invalid arguments: No events provided.");
+    }
+}
@@ -2,7 +2,9 @@
 //!
 //! An inline macros is a macro that is used directly within the code.
 
-use cairo_lang_defs::plugin::{InlinePluginResult, PluginDiagnostic};
+use cairo_lang_defs::plugin::{
+    InlineMacroExprPlugin, InlinePluginResult, MacroPluginMetadata, NamedPlugin, PluginDiagnostic,
+};
 use cairo_lang_diagnostics::Severity;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{ast, Terminal, TypedStablePtr, TypedSyntaxNode};
@@ -29,6 +31,37 @@ pub use spawn_test_world::SpawnTestWorld;
 
 const CAIRO_ERR_MSG_LEN: usize = 31;
 
+/// Wraps an inline macro plugin to prefix all its diagnostics with the name of the macro
+/// (eg. "in `selector_from_tag!`: ..."), which makes it clear which macro failed when several
+/// of them are used in the same function.
+#[derive(Debug, Default)]
+pub struct WithMacroName<T>(T);
+
+impl<T: NamedPlugin> NamedPlugin for WithMacroName<T> {
+    const NAME: &'static str = T::NAME;
+}
+
+impl<T: InlineMacroExprPlugin + NamedPlugin> InlineMacroExprPlugin for WithMacroName<T> {
+    fn generate_code(
+        &self,
+        db: &dyn SyntaxGroup,
+        syntax: &ast::ExprInlineMacro,
+        metadata: &MacroPluginMetadata<'_>,
+    ) -> InlinePluginResult {
+        let mut result = self.0.generate_code(db, syntax, metadata);
+
+        for diagnostic in &mut result.diagnostics {
+            diagnostic.message = format!("in `{}!`: {}", T::NAME, diagnostic.message);
+        }
+
+        result
+    }
+
+    fn documentation(&self) -> Option<String> {
+        self.0.documentation()
+    }
+}
+
 pub fn extract_models(
     db: &dyn SyntaxGroup,
     expression: &ast::Expr,
@@ -111,3 +144,66 @@ pub fn unsupported_arg_diagnostic(
         }],
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use cairo_lang_defs::plugin::{InlineMacroExprPlugin, InlinePluginResult, MacroPluginMetadata};
+    use cairo_lang_filesystem::cfg::CfgSet;
+    use cairo_lang_filesystem::db::Edition;
+    use cairo_lang_parser::utils::SimpleParserDatabase;
+    use cairo_lang_syntax::node::kind::SyntaxKind;
+    use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
+    use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+
+    /// Expands the first inline macro of the given function body with `plugin`.
+    pub fn expand_inline_macro(
+        plugin: &impl InlineMacroExprPlugin,
+        function_body: &str,
+    ) -> InlinePluginResult {
+        let db = SimpleParserDatabase::default();
+        let root = db.parse_virtual(format!("fn test() {{ {function_body} }}")).unwrap();
+        let node = root
+            .descendants(&db)
+            .find(|node| node.kind(&db) == SyntaxKind::ExprInlineMacro)
+            .expect("no inline macro");
+        let syntax = ast::ExprInlineMacro::from_syntax_node(&db, node);
+
+        let cfg_set = CfgSet::new();
+        let declared_derives = OrderedHashSet::default();
+        let allowed_features = OrderedHashSet::default();
+        let metadata = MacroPluginMetadata {
+            cfg_set: &cfg_set,
+            declared_derives: &declared_derives,
+            allowed_features: &allowed_features,
+            edition: Edition::default(),
+        };
+
+        plugin.generate_code(&db, &syntax, &metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::expand_inline_macro;
+    use super::*;
+
+    #[test]
+    fn diagnostics_are_prefixed_with_macro_name() {
+        let plugin = WithMacroName::<SelectorFromTagMacro>::default();
+
+        let result = expand_inline_macro(&plugin, r#"selector_from_tag!("invalid tag");"#);
+        assert!(result.code.is_none());
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(
+            result.diagnostics[0].message,
+            "in `selector_from_tag!`: Invalid tag. Tag must be in the format of `namespace-name`."
+        );
+
+        // the expansion itself is left untouched
+        let result = expand_inline_macro(&plugin, r#"selector_from_tag!("ns-Position");"#);
+        assert!(result.diagnostics.is_empty());
+        let expected =
+            expand_inline_macro(&SelectorFromTagMacro, r#"selector_from_tag!("ns-Position");"#);
+        assert_eq!(result.code.unwrap().content, expected.code.unwrap().content);
+    }
+}
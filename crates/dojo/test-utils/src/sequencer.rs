@@ -109,13 +109,18 @@ impl TestSequencer {
         self.handle.rpc.stop()
     }
 
+    /// Stops the node and waits until it has shut down, releasing its database.
+    pub async fn shutdown(self) -> anyhow::Result<()> {
+        self.handle.stop().await
+    }
+
     pub fn url(&self) -> Url {
         self.url.clone()
     }
 }
 
 pub fn get_default_test_config(sequencing: SequencingConfig) -> Config {
-    let dev = DevConfig { fee: false, account_validation: true, fixed_gas_prices: None };
+    let dev = DevConfig { fee: false, account_validation: true, ..Default::default() };
     let mut chain =
         katana_chain_spec::dev::ChainSpec { id: ChainId::SEPOLIA, ..Default::default() };
     chain.genesis.sequencer_address = *DEFAULT_SEQUENCER_ADDRESS;
@@ -48,6 +48,10 @@ pub enum PackingError {
 
 /// Unpacks a vector of packed values according to a given layout.
 ///
+/// This is the off-chain counterpart of the `dojo::storage::packing` module: the values are read
+/// in the order of the layout, starting from the least significant bit of the first felt, and a
+/// value that doesn't fit in the 251 remaining bits of a felt starts at the bit 0 of the next one.
+///
 /// # Arguments
 ///
 /// * `packed_values` - A vector of FieldElement values that are packed.
@@ -312,6 +316,23 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn unpack_small_values_sharing_a_felt() {
+        // [u8, bool, u32] share the first felt, the u251 doesn't fit and moves to the second one.
+        let layout = [8, 1, 32, 251].map(Felt::from).to_vec();
+        let first = Felt::from(0x12_u8) + Felt::from(1_u16 << 8) + Felt::from(0xdeadbeef_u64 << 9);
+        let packed = vec![first, Felt::from(42)];
+
+        let unpacked = unpack(packed, layout).unwrap();
+        assert_eq!(unpacked, [0x12_u32, 1, 0xdeadbeef, 42].map(Felt::from).to_vec());
+    }
+
+    #[test]
+    fn unpack_with_missing_felts() {
+        let layout = [251, 8].map(Felt::from).to_vec();
+        assert!(unpack(vec![Felt::ONE], layout).is_err());
+    }
+
     #[test]
     fn parse_simple_with_invalid_value() {
         let data = [Felt::default()];
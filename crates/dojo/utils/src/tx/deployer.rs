@@ -1,4 +1,11 @@
 //! The deployer is in charge of deploying contracts to starknet.
+//!
+//! Deployments that don't specify a salt use a salt derived deterministically from a seed and a
+//! counter: the n-th such deployment of a [`Deployer`] (starting at 0) uses
+//! `poseidon_hash(seed, n)` as salt. Deploying the same sequence of contracts with the same seed
+//! thus always results in the same addresses.
+
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use starknet::accounts::ConnectedAccount;
 use starknet::core::types::{
@@ -7,6 +14,7 @@ use starknet::core::types::{
 use starknet::core::utils::get_contract_address;
 use starknet::macros::{felt, selector};
 use starknet::providers::{Provider, ProviderError};
+use starknet_crypto::poseidon_hash;
 use tracing::trace;
 
 use crate::{
@@ -17,6 +25,9 @@ const UDC_DEPLOY_SELECTOR: Felt = selector!("deployContract");
 const UDC_ADDRESS: Felt =
     felt!("0x41a78e741e5af2fec34b695679bc6891742439f7afb8484ecd7766661ad02bf");
 
+/// The seed used to derive the salts of the deployments that don't specify one.
+pub const DEFAULT_SALT_SEED: Felt = Felt::ZERO;
+
 #[derive(Debug)]
 pub struct Deployer<A>
 where
@@ -26,6 +37,10 @@ where
     pub account: A,
     /// The transaction configuration.
     pub txn_config: TxnConfig,
+    /// The seed of the salts derived for the deployments that don't specify one.
+    salt_seed: Felt,
+    /// The number of salts derived so far.
+    salt_counter: AtomicU64,
 }
 
 impl<A> Deployer<A>
@@ -34,17 +49,32 @@ where
 {
     /// Creates a new deployer.
     pub fn new(account: A, txn_config: TxnConfig) -> Self {
-        Self { account, txn_config }
+        Self { account, txn_config, salt_seed: DEFAULT_SALT_SEED, salt_counter: AtomicU64::new(0) }
+    }
+
+    /// Sets the seed of the salts derived for the deployments that don't specify one.
+    pub fn with_salt_seed(mut self, seed: Felt) -> Self {
+        self.salt_seed = seed;
+        self
+    }
+
+    /// Derives the salt of the next deployment that doesn't specify one.
+    pub fn next_salt(&self) -> Felt {
+        let n = self.salt_counter.fetch_add(1, Ordering::Relaxed);
+        poseidon_hash(self.salt_seed, Felt::from(n))
     }
 
     /// Get a Call for deploying a contract via the UDC.
+    ///
+    /// If `salt` is `None`, the salt is derived with [`Deployer::next_salt`].
     pub async fn deploy_via_udc_getcall(
         &self,
         class_hash: Felt,
-        salt: Felt,
+        salt: Option<Felt>,
         constructor_calldata: &[Felt],
         deployer_address: Felt,
     ) -> Result<Option<(Felt, Call)>, TransactionError<A::SignError>> {
+        let salt = salt.unwrap_or_else(|| self.next_salt());
         let udc_calldata = [
             vec![class_hash, salt, deployer_address, Felt::from(constructor_calldata.len())],
             constructor_calldata.to_vec(),
@@ -65,10 +95,12 @@ where
     }
 
     /// Deploys a contract via the UDC.
+    ///
+    /// If `salt` is `None`, the salt is derived with [`Deployer::next_salt`].
     pub async fn deploy_via_udc(
         &self,
         class_hash: Felt,
-        salt: Felt,
+        salt: Option<Felt>,
         constructor_calldata: &[Felt],
         deployer_address: Felt,
     ) -> Result<TransactionResult, TransactionError<A::SignError>> {
@@ -127,3 +159,67 @@ where
         Err(e) => Err(e),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use dojo_test_utils::sequencer::{get_default_test_config, TestSequencer};
+    use starknet::accounts::Account;
+    use starknet::core::utils::get_contract_address;
+    use starknet::macros::felt;
+    use starknet_crypto::poseidon_hash;
+
+    use super::Deployer;
+    use crate::TxnConfig;
+
+    #[tokio::test]
+    async fn derived_salts_are_deterministic() {
+        let sequencer = TestSequencer::start(get_default_test_config(Default::default())).await;
+
+        let seed = felt!("0x1337");
+        let class_hash = felt!("0x1234");
+        let calldata = [felt!("0x1")];
+        let deployer_address = sequencer.account().address();
+
+        let deployer =
+            Deployer::new(sequencer.account(), TxnConfig::default()).with_salt_seed(seed);
+
+        let mut addresses = Vec::new();
+        for n in 0..2u64 {
+            let (address, call) = deployer
+                .deploy_via_udc_getcall(class_hash, None, &calldata, deployer_address)
+                .await
+                .unwrap()
+                .unwrap();
+
+            let salt = poseidon_hash(seed, n.into());
+            assert_eq!(call.calldata[1], salt);
+            assert_eq!(
+                address,
+                get_contract_address(salt, class_hash, &calldata, deployer_address)
+            );
+            addresses.push(address);
+        }
+
+        // explicit salts are used as-is and don't advance the derived salts
+        let salt = felt!("0xbeef");
+        let (_, call) = deployer
+            .deploy_via_udc_getcall(class_hash, Some(salt), &calldata, deployer_address)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(call.calldata[1], salt);
+        assert_eq!(deployer.next_salt(), poseidon_hash(seed, 2u64.into()));
+
+        // a new deployer with the same seed derives the same addresses
+        let deployer =
+            Deployer::new(sequencer.account(), TxnConfig::default()).with_salt_seed(seed);
+        for expected in addresses {
+            let (address, _) = deployer
+                .deploy_via_udc_getcall(class_hash, None, &calldata, deployer_address)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(address, expected);
+        }
+    }
+}
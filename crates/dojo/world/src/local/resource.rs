@@ -72,7 +72,7 @@ pub struct NamespaceLocal {
 }
 
 /// Represents a struct resource like member and event.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Member {
     /// Name of the member.
     pub name: String,
@@ -1,11 +1,10 @@
 use std::collections::BTreeMap;
 
 use alloy_primitives::U256;
-use katana_primitives::block::{Block, Header};
+use katana_primitives::block::{Block, BlockHash, BlockHashAlgorithm};
 use katana_primitives::chain::ChainId;
 use katana_primitives::class::ClassHash;
 use katana_primitives::contract::ContractAddress;
-use katana_primitives::da::L1DataAvailabilityMode;
 use katana_primitives::genesis::allocation::{DevAllocationsGenerator, GenesisAllocation};
 use katana_primitives::genesis::constant::{
     get_fee_token_balance_base_storage_address, DEFAULT_ACCOUNT_CLASS_PUBKEY_STORAGE_SLOT,
@@ -17,7 +16,6 @@ use katana_primitives::genesis::constant::{
 use katana_primitives::genesis::Genesis;
 use katana_primitives::state::StateUpdatesWithClasses;
 use katana_primitives::utils::split_u256;
-use katana_primitives::version::CURRENT_STARKNET_VERSION;
 use katana_primitives::Felt;
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
@@ -46,25 +44,20 @@ pub struct ChainSpec {
 
 impl ChainSpec {
     pub fn block(&self) -> Block {
-        let header = Header {
-            state_diff_length: 0,
-            protocol_version: CURRENT_STARKNET_VERSION,
-            number: self.genesis.number,
-            timestamp: self.genesis.timestamp,
-            events_count: 0,
-            transaction_count: 0,
-            events_commitment: Felt::ZERO,
-            receipts_commitment: Felt::ZERO,
-            state_diff_commitment: Felt::ZERO,
-            transactions_commitment: Felt::ZERO,
-            state_root: self.genesis.state_root,
-            parent_hash: self.genesis.parent_hash,
-            l1_da_mode: L1DataAvailabilityMode::Calldata,
-            l1_gas_prices: self.genesis.gas_prices.clone(),
-            l1_data_gas_prices: self.genesis.gas_prices.clone(),
-            sequencer_address: self.genesis.sequencer_address,
-        };
-        Block { header, body: Vec::new() }
+        Block { header: self.genesis.header(), body: Vec::new() }
+    }
+
+    /// Computes the hash of the genesis block with the given algorithm, without having to build
+    /// the chain. The algorithm must be the one configured for the chain, as the genesis block is
+    /// hashed like any other block.
+    ///
+    /// The state root of the header is computed from the genesis classes and allocations, so the
+    /// hash is a fingerprint of the starting state of the chain: the same genesis always yields the
+    /// same hash, and changing any of its allocations changes it.
+    pub fn block_hash(&self, algorithm: BlockHashAlgorithm) -> BlockHash {
+        let mut header = self.genesis.header();
+        header.state_root = katana_trie::compute_state_root(&self.state_updates().state_updates);
+        header.compute_hash_with(algorithm)
     }
 
     // this method will include the ETH and STRK fee tokens, and the UDC
@@ -646,4 +639,32 @@ mod tests {
             "STRK total supply must be calculated from allocations balances correctly"
         );
     }
+
+    #[test]
+    fn genesis_block_hash_is_a_fingerprint_of_the_genesis_state() {
+        let chain_spec = ChainSpec::default();
+
+        let poseidon = chain_spec.block_hash(BlockHashAlgorithm::Poseidon);
+        assert_eq!(poseidon, chain_spec.clone().block_hash(BlockHashAlgorithm::Poseidon));
+        assert_ne!(poseidon, chain_spec.block_hash(BlockHashAlgorithm::Pedersen));
+
+        // the configured state root isn't used, as it's computed from the genesis state
+        let mut other = chain_spec.clone();
+        other.genesis.state_root = felt!("0x1337");
+        assert_eq!(poseidon, other.block_hash(BlockHashAlgorithm::Poseidon));
+
+        let mut other = chain_spec.clone();
+        other.genesis.timestamp += 1;
+        assert_ne!(poseidon, other.block_hash(BlockHashAlgorithm::Poseidon));
+
+        let mut other = chain_spec.clone();
+        other.genesis.extend_allocations([(
+            address!("0x1337"),
+            GenesisAllocation::Contract(GenesisContractAlloc {
+                class_hash: Some(DEFAULT_ACCOUNT_CLASS_HASH),
+                ..Default::default()
+            }),
+        )]);
+        assert_ne!(poseidon, other.block_hash(BlockHashAlgorithm::Poseidon));
+    }
 }
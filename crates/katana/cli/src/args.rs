@@ -3,6 +3,7 @@
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use alloy_primitives::U256;
 use anyhow::{Context, Result};
@@ -20,10 +21,13 @@ use katana_node::config::metrics::MetricsConfig;
 use katana_node::config::rpc::{RpcConfig, RpcModuleKind, RpcModulesList};
 #[cfg(not(feature = "server"))]
 use katana_node::config::rpc::{DEFAULT_RPC_ADDR, DEFAULT_RPC_PORT};
-use katana_node::config::sequencing::SequencingConfig;
+use katana_node::config::sequencing::{
+    NonceValidationMode, PoolConfig, PoolFullPolicy, SequencingConfig, DEFAULT_MAX_POOL_SIZE,
+};
 use katana_node::config::Config;
 #[cfg(feature = "cartridge")]
 use katana_node::config::Paymaster;
+use katana_primitives::env::TxLimits;
 use katana_primitives::genesis::allocation::DevAllocationsGenerator;
 use katana_primitives::genesis::constant::DEFAULT_PREFUNDED_ACCOUNT_BALANCE;
 #[cfg(feature = "server")]
@@ -66,6 +70,30 @@ pub struct NodeArgs {
     #[arg(value_name = "TOTAL")]
     pub block_cairo_steps_limit: Option<u64>,
 
+    /// How transactions with a nonce ahead of the sender's nonce are handled.
+    ///
+    /// With `strict`, they are rejected right away. With `queued`, they wait in the pool until
+    /// the transactions of the preceding nonces are received.
+    #[arg(long = "sequencing.nonce-validation")]
+    #[arg(value_name = "MODE", default_value_t)]
+    #[serde(default)]
+    pub nonce_validation: NonceValidationMode,
+
+    /// The maximum number of transactions held by the transaction pool.
+    #[arg(long = "sequencing.max-pool-size")]
+    #[arg(value_name = "TOTAL")]
+    pub max_pool_size: Option<usize>,
+
+    /// What happens when a transaction is received while the transaction pool is full.
+    ///
+    /// With `reject`, the transaction is rejected. With `evict-lowest-fee`, the last pending
+    /// transaction of a sender with the lowest max fee is evicted if the received transaction is
+    /// valid and pays more.
+    #[arg(long = "sequencing.pool-full-policy")]
+    #[arg(value_name = "POLICY", default_value_t)]
+    #[serde(default)]
+    pub pool_full_policy: PoolFullPolicy,
+
     /// Directory path of the database to initialize from.
     ///
     /// The path must either be an empty directory or a directory which already contains a
@@ -244,6 +272,12 @@ impl NodeArgs {
             block_time: self.block_time,
             no_mining: self.no_mining,
             block_cairo_steps_limit: self.block_cairo_steps_limit,
+            pool: PoolConfig {
+                nonce_validation: self.nonce_validation,
+                max_size: self.max_pool_size.unwrap_or(DEFAULT_MAX_POOL_SIZE),
+                full_policy: self.pool_full_policy,
+                ..Default::default()
+            },
         }
     }
 
@@ -320,6 +354,9 @@ impl NodeArgs {
             max_event_page_size: Some(self.rpc.max_event_page_size),
             max_proof_keys: Some(self.rpc.max_proof_keys),
             max_call_gas: Some(self.rpc.max_call_gas),
+            max_requests_per_second: self.rpc.max_requests_per_second,
+            request_burst: self.rpc.request_burst,
+            timeout: self.rpc.timeout.map(Duration::from_secs),
         })
     }
 
@@ -346,10 +383,18 @@ impl NodeArgs {
 
             // Generate dev accounts.
             // If `cartridge` is enabled, the first account will be the paymaster.
-            let accounts = DevAllocationsGenerator::new(self.development.total_accounts)
+            let mut accounts = DevAllocationsGenerator::new(self.development.total_accounts)
                 .with_seed(parse_seed(&self.development.seed))
-                .with_balance(U256::from(DEFAULT_PREFUNDED_ACCOUNT_BALANCE))
-                .generate();
+                .with_balance(U256::from(DEFAULT_PREFUNDED_ACCOUNT_BALANCE));
+
+            if let Some(path) = &self.development.account_class {
+                let class = utils::read_account_class(path)?;
+                let class_hash = class.class_hash()?;
+                chain_spec.genesis.classes.insert(class_hash, Arc::new(class));
+                accounts = accounts.with_class(class_hash);
+            }
+
+            let accounts = accounts.generate();
 
             chain_spec.genesis.extend_allocations(accounts.into_iter().map(|(k, v)| (k, v.into())));
 
@@ -389,6 +434,9 @@ impl NodeArgs {
             fixed_gas_prices,
             fee: !self.development.no_fee,
             account_validation: !self.development.no_account_validation,
+            deterministic: self.development.deterministic,
+            auto_max_fee: self.development.auto_max_fee.map(utils::max_fee_multiplier_to_bps),
+            dynamic_gas_prices: self.gpo.dynamic,
         }
     }
 
@@ -396,13 +444,26 @@ impl NodeArgs {
         ExecutionConfig {
             invocation_max_steps: self.starknet.environment.invoke_max_steps,
             validation_max_steps: self.starknet.environment.validate_max_steps,
+            max_recursion_depth: self.starknet.environment.max_recursion_depth,
+            class_cache_size: self.starknet.environment.class_cache_size,
+            block_hash_algorithm: self.starknet.environment.block_hash_algorithm,
+            tx_limits: TxLimits {
+                max_calldata_length: self.starknet.environment.max_calldata_length,
+                max_event_data_length: self.starknet.environment.max_event_data_length,
+                max_emitted_events: self.starknet.environment.max_emitted_events,
+            },
             ..Default::default()
         }
     }
 
     fn forking_config(&self) -> Result<Option<ForkingConfig>> {
         if let Some(ref url) = self.forking.fork_provider {
-            let cfg = ForkingConfig { url: url.clone(), block: self.forking.fork_block };
+            let cfg = ForkingConfig {
+                url: url.clone(),
+                block: self.forking.fork_block,
+                pinned: self.forking.fork_pin.clone(),
+                snapshot: self.forking.fork_snapshot.clone(),
+            };
             return Ok(Some(cfg));
         }
 
@@ -455,6 +516,18 @@ impl NodeArgs {
             self.block_time = config.block_time;
         }
 
+        if self.nonce_validation == NonceValidationMode::default() {
+            self.nonce_validation = config.nonce_validation.unwrap_or_default();
+        }
+
+        if self.max_pool_size.is_none() {
+            self.max_pool_size = config.max_pool_size;
+        }
+
+        if self.pool_full_policy == PoolFullPolicy::default() {
+            self.pool_full_policy = config.pool_full_policy.unwrap_or_default();
+        }
+
         if self.db_dir.is_none() {
             self.db_dir = config.db_dir;
         }
@@ -515,9 +588,12 @@ mod test {
         DEFAULT_STRK_L1_GAS_PRICE,
     };
     use katana_node::config::execution::{
-        DEFAULT_INVOCATION_MAX_STEPS, DEFAULT_VALIDATION_MAX_STEPS,
+        DEFAULT_CLASS_CACHE_SIZE, DEFAULT_INVOCATION_MAX_STEPS, DEFAULT_MAX_RECURSION_DEPTH,
+        DEFAULT_VALIDATION_MAX_STEPS,
     };
+    use katana_primitives::block::BlockHashAlgorithm;
     use katana_primitives::chain::ChainId;
+    use katana_primitives::env::TxLimits;
     use katana_primitives::{address, felt, ContractAddress, Felt};
     use katana_rpc::cors::HeaderValue;
 
@@ -530,9 +606,16 @@ mod test {
 
         assert!(config.dev.fee);
         assert!(config.dev.account_validation);
+        assert!(!config.dev.deterministic);
+        assert_eq!(config.dev.auto_max_fee, None);
         assert!(config.forking.is_none());
         assert_eq!(config.execution.invocation_max_steps, DEFAULT_INVOCATION_MAX_STEPS);
         assert_eq!(config.execution.validation_max_steps, DEFAULT_VALIDATION_MAX_STEPS);
+        assert_eq!(config.execution.max_recursion_depth, DEFAULT_MAX_RECURSION_DEPTH);
+        assert_eq!(config.execution.class_cache_size, DEFAULT_CLASS_CACHE_SIZE);
+        assert_eq!(config.execution.block_hash_algorithm, BlockHashAlgorithm::Poseidon);
+        assert_eq!(config.execution.tx_limits, TxLimits::default());
+        assert_eq!(config.sequencing.pool, PoolConfig::default());
         assert_eq!(config.db.dir, None);
         assert_eq!(config.chain.id(), ChainId::parse("KATANA").unwrap());
         assert_eq!(config.chain.genesis().sequencer_address, *DEFAULT_SEQUENCER_ADDRESS);
@@ -545,12 +628,33 @@ mod test {
             "--dev",
             "--dev.no-fee",
             "--dev.no-account-validation",
+            "--dev.deterministic",
+            "--dev.auto-max-fee",
+            "1.5",
             "--chain-id",
             "SN_GOERLI",
             "--invoke-max-steps",
             "200",
             "--validate-max-steps",
             "100",
+            "--max-recursion-depth",
+            "10",
+            "--class-cache-size",
+            "64",
+            "--block-hash-algorithm",
+            "pedersen",
+            "--max-calldata-length",
+            "10",
+            "--max-event-data-length",
+            "20",
+            "--max-emitted-events",
+            "30",
+            "--sequencing.nonce-validation",
+            "queued",
+            "--sequencing.max-pool-size",
+            "50",
+            "--sequencing.pool-full-policy",
+            "evict-lowest-fee",
             "--db-dir",
             "/path/to/db",
         ]);
@@ -558,8 +662,19 @@ mod test {
 
         assert!(!config.dev.fee);
         assert!(!config.dev.account_validation);
+        assert!(config.dev.deterministic);
+        assert_eq!(config.dev.auto_max_fee, Some(15_000));
         assert_eq!(config.execution.invocation_max_steps, 200);
         assert_eq!(config.execution.validation_max_steps, 100);
+        assert_eq!(config.execution.max_recursion_depth, 10);
+        assert_eq!(config.execution.class_cache_size.get(), 64);
+        assert_eq!(config.execution.block_hash_algorithm, BlockHashAlgorithm::Pedersen);
+        assert_eq!(config.execution.tx_limits.max_calldata_length, 10);
+        assert_eq!(config.execution.tx_limits.max_event_data_length, 20);
+        assert_eq!(config.execution.tx_limits.max_emitted_events, 30);
+        assert_eq!(config.sequencing.pool.nonce_validation, NonceValidationMode::Queued);
+        assert_eq!(config.sequencing.pool.max_size, 50);
+        assert_eq!(config.sequencing.pool.full_policy, PoolFullPolicy::EvictLowestFee);
         assert_eq!(config.db.dir, Some(PathBuf::from("/path/to/db")));
         assert_eq!(config.chain.id(), ChainId::GOERLI);
         assert_eq!(config.chain.genesis().sequencer_address, *DEFAULT_SEQUENCER_ADDRESS);
@@ -647,6 +762,37 @@ mod test {
         })
     }
 
+    #[test]
+    fn dynamic_gas_prices() {
+        let config = NodeArgs::parse_from(["katana"]).config().unwrap();
+        assert!(!config.dev.dynamic_gas_prices);
+
+        let config = NodeArgs::parse_from([
+            "katana",
+            "--gpo.dynamic",
+            "--sequencing.block-max-cairo-steps",
+            "1000000",
+        ])
+        .config()
+        .unwrap();
+        assert!(config.dev.dynamic_gas_prices);
+
+        // The prices can only react to the fullness of blocks that have a limit.
+        let result = NodeArgs::try_parse_from(["katana", "--gpo.dynamic"]);
+        assert!(result.is_err());
+
+        // Rollup chains sample their gas prices from the settlement layer instead.
+        let result = NodeArgs::try_parse_from([
+            "katana",
+            "--gpo.dynamic",
+            "--sequencing.block-max-cairo-steps",
+            "1000000",
+            "--chain",
+            "./test-data",
+        ]);
+        assert_eq!(result.unwrap_err().kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
     #[test]
     fn genesis_with_fixed_gas_prices() {
         let config = NodeArgs::parse_from([
@@ -782,6 +928,39 @@ chain_id.Named = "Mainnet"
         );
     }
 
+    #[test]
+    fn rpc_rate_limit() {
+        // Rate limiting is disabled by default.
+        let config = NodeArgs::parse_from(["katana"]).config().unwrap();
+        assert_eq!(config.rpc.max_requests_per_second, None);
+        assert_eq!(config.rpc.request_burst, None);
+
+        let config = NodeArgs::parse_from([
+            "katana",
+            "--rpc.max-requests-per-second",
+            "50",
+            "--rpc.request-burst",
+            "100",
+        ])
+        .config()
+        .unwrap();
+        assert_eq!(config.rpc.max_requests_per_second, Some(50));
+        assert_eq!(config.rpc.request_burst, Some(100));
+
+        // The burst can't be set without a rate limit.
+        let result = NodeArgs::try_parse_from(["katana", "--rpc.request-burst", "100"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rpc_timeout() {
+        let config = NodeArgs::parse_from(["katana"]).config().unwrap();
+        assert_eq!(config.rpc.timeout, None);
+
+        let config = NodeArgs::parse_from(["katana", "--rpc.timeout", "5"]).config().unwrap();
+        assert_eq!(config.rpc.timeout, Some(Duration::from_secs(5)));
+    }
+
     #[test]
     fn test_dev_api_enabled() {
         let args = NodeArgs::parse_from(["katana", "--dev"]);
@@ -790,6 +969,85 @@ chain_id.Named = "Mainnet"
         assert!(config.rpc.apis.contains(&RpcModuleKind::Dev));
     }
 
+    #[test]
+    fn dev_account_class() {
+        use katana_primitives::genesis::allocation::GenesisAllocation;
+        use katana_primitives::genesis::constant::DEFAULT_ACCOUNT_CLASS_HASH;
+        use katana_primitives::utils::class::parse_sierra_class;
+
+        let account_class_hashes = |config: &Config| {
+            config
+                .chain
+                .genesis()
+                .allocations
+                .values()
+                .filter_map(|alloc| match alloc {
+                    GenesisAllocation::Account(account) => Some(account.class_hash()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let config = NodeArgs::parse_from(["katana", "--dev"]).config().unwrap();
+        let hashes = account_class_hashes(&config);
+        assert!(!hashes.is_empty());
+        assert!(hashes.iter().all(|hash| *hash == DEFAULT_ACCOUNT_CLASS_HASH));
+
+        // an account class with the same storage layout as the default one, but a different hash
+        let artifact = include_str!("../../contracts/build/default_account.json");
+        let mut class = serde_json::from_str::<serde_json::Value>(artifact).unwrap();
+        class["abi"].as_array_mut().unwrap().pop();
+        let class = serde_json::to_string(&class).unwrap();
+        let class_hash = parse_sierra_class(&class).unwrap().class_hash().unwrap();
+        assert_ne!(class_hash, DEFAULT_ACCOUNT_CLASS_HASH);
+
+        let path = std::env::temp_dir().join("katana-account-class.json");
+        std::fs::write(&path, class).unwrap();
+
+        let config = NodeArgs::parse_from([
+            "katana",
+            "--dev",
+            "--dev.account-class",
+            path.to_str().unwrap(),
+        ])
+        .config()
+        .unwrap();
+
+        let hashes = account_class_hashes(&config);
+        assert!(!hashes.is_empty());
+        assert!(hashes.iter().all(|hash| *hash == class_hash));
+        assert!(config.chain.genesis().classes.contains_key(&class_hash));
+    }
+
+    #[test]
+    fn fork_pinned_contracts() {
+        let config = NodeArgs::parse_from([
+            "katana",
+            "--fork.provider",
+            "http://localhost:5050",
+            "--fork.pin",
+            "0x1,0x2",
+            "--fork.snapshot",
+            "./snapshot.json",
+        ])
+        .config()
+        .unwrap();
+
+        let forking = config.forking.unwrap();
+        assert_eq!(forking.pinned, vec![address!("0x1"), address!("0x2")]);
+        assert_eq!(forking.snapshot, Some(PathBuf::from("./snapshot.json")));
+
+        // the snapshot can't be taken without pinning any contracts
+        let result = NodeArgs::try_parse_from([
+            "katana",
+            "--fork.provider",
+            "http://localhost:5050",
+            "--fork.snapshot",
+            "./snapshot.json",
+        ]);
+        assert!(result.is_err());
+    }
+
     #[cfg(feature = "cartridge")]
     #[test]
     fn cartridge_paymaster() {
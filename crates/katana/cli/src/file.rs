@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use katana_messaging::MessagingConfig;
+use katana_node::config::sequencing::{NonceValidationMode, PoolFullPolicy};
 use serde::{Deserialize, Serialize};
 
 use crate::options::*;
@@ -13,6 +14,9 @@ pub struct NodeArgsConfig {
     pub no_mining: Option<bool>,
     pub block_time: Option<u64>,
     pub block_cairo_steps_limit: Option<u64>,
+    pub nonce_validation: Option<NonceValidationMode>,
+    pub max_pool_size: Option<usize>,
+    pub pool_full_policy: Option<PoolFullPolicy>,
     pub db_dir: Option<PathBuf>,
     pub messaging: Option<MessagingConfig>,
     pub logging: Option<LoggingOptions>,
@@ -48,6 +52,17 @@ impl TryFrom<NodeArgs> for NodeArgsConfig {
             no_mining: if args.no_mining { Some(true) } else { None },
             block_time: args.block_time,
             block_cairo_steps_limit: args.block_cairo_steps_limit,
+            nonce_validation: if args.nonce_validation == NonceValidationMode::default() {
+                None
+            } else {
+                Some(args.nonce_validation)
+            },
+            max_pool_size: args.max_pool_size,
+            pool_full_policy: if args.pool_full_policy == PoolFullPolicy::default() {
+                None
+            } else {
+                Some(args.pool_full_policy)
+            },
             db_dir: args.db_dir,
             messaging: args.messaging,
             ..Default::default()
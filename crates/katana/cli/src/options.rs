@@ -8,9 +8,14 @@
 //! Currently, the merge is made at the top level of the commands.
 
 use std::net::{IpAddr, Ipv4Addr};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
 
 use clap::Args;
-use katana_node::config::execution::{DEFAULT_INVOCATION_MAX_STEPS, DEFAULT_VALIDATION_MAX_STEPS};
+use katana_node::config::execution::{
+    DEFAULT_CLASS_CACHE_SIZE, DEFAULT_INVOCATION_MAX_STEPS, DEFAULT_MAX_RECURSION_DEPTH,
+    DEFAULT_VALIDATION_MAX_STEPS,
+};
 #[cfg(feature = "server")]
 use katana_node::config::metrics::{DEFAULT_METRICS_ADDR, DEFAULT_METRICS_PORT};
 use katana_node::config::rpc::{
@@ -19,8 +24,12 @@ use katana_node::config::rpc::{
 };
 #[cfg(feature = "server")]
 use katana_node::config::rpc::{DEFAULT_RPC_ADDR, DEFAULT_RPC_PORT};
-use katana_primitives::block::BlockHashOrNumber;
+use katana_primitives::block::{BlockHashAlgorithm, BlockHashOrNumber};
 use katana_primitives::chain::ChainId;
+use katana_primitives::contract::ContractAddress;
+use katana_primitives::env::{
+    DEFAULT_MAX_CALLDATA_LENGTH, DEFAULT_MAX_EMITTED_EVENTS, DEFAULT_MAX_EVENT_DATA_LENGTH,
+};
 use katana_primitives::genesis::Genesis;
 #[cfg(feature = "server")]
 use katana_rpc::cors::HeaderValue;
@@ -29,7 +38,10 @@ use url::Url;
 
 #[cfg(feature = "server")]
 use crate::utils::{deserialize_cors_origins, serialize_cors_origins};
-use crate::utils::{parse_block_hash_or_number, parse_genesis, LogFormat};
+use crate::utils::{
+    deserialize_max_fee_multiplier, parse_block_hash_or_number, parse_genesis,
+    parse_max_fee_multiplier, LogFormat,
+};
 
 const DEFAULT_DEV_SEED: &str = "0";
 const DEFAULT_DEV_ACCOUNTS: u16 = 10;
@@ -165,6 +177,27 @@ pub struct RpcOptions {
     #[arg(default_value_t = DEFAULT_RPC_MAX_CALL_GAS)]
     #[serde(default = "default_max_call_gas")]
     pub max_call_gas: u64,
+
+    /// Maximum number of requests per second allowed on a single connection.
+    ///
+    /// Requests above the limit are rejected with a JSON-RPC error. Rate limiting is disabled
+    /// unless this is set.
+    #[arg(long = "rpc.max-requests-per-second", value_name = "RPS")]
+    pub max_requests_per_second: Option<u32>,
+
+    /// Maximum number of requests a connection can burst before being rate limited.
+    ///
+    /// Defaults to the value of `--rpc.max-requests-per-second`.
+    #[arg(long = "rpc.request-burst", value_name = "BURST")]
+    #[arg(requires = "max_requests_per_second")]
+    pub request_burst: Option<u32>,
+
+    /// Maximum duration of a request (in seconds).
+    ///
+    /// Requests that take longer are aborted with an error. When the node is shutting down, it
+    /// waits at most this long for the in-flight requests to complete. Defaults to 20 seconds.
+    #[arg(long = "rpc.timeout", value_name = "SECONDS")]
+    pub timeout: Option<u64>,
 }
 
 impl Default for RpcOptions {
@@ -177,6 +210,9 @@ impl Default for RpcOptions {
             max_request_body_size: None,
             max_response_body_size: None,
             max_call_gas: DEFAULT_RPC_MAX_CALL_GAS,
+            max_requests_per_second: None,
+            request_burst: None,
+            timeout: None,
         }
     }
 }
@@ -205,6 +241,15 @@ impl RpcOptions {
             if self.max_call_gas == DEFAULT_RPC_MAX_CALL_GAS {
                 self.max_call_gas = other.max_call_gas;
             }
+            if self.max_requests_per_second.is_none() {
+                self.max_requests_per_second = other.max_requests_per_second;
+            }
+            if self.request_burst.is_none() {
+                self.request_burst = other.request_burst;
+            }
+            if self.timeout.is_none() {
+                self.timeout = other.timeout;
+            }
         }
     }
 }
@@ -254,10 +299,65 @@ pub struct EnvironmentOptions {
     pub validate_max_steps: u32,
 
     /// The maximum number of steps available for the account execution logic.
+    ///
+    /// Transactions that exceed it are reverted. Defaults to the Starknet mainnet limit.
     #[arg(long)]
     #[arg(default_value_t = DEFAULT_INVOCATION_MAX_STEPS)]
     #[serde(default = "default_invoke_max_steps")]
     pub invoke_max_steps: u32,
+
+    /// The maximum depth of nested calls.
+    ///
+    /// Transactions calling deeper, eg a contract recursively calling itself, are reverted.
+    /// Defaults to the Starknet mainnet limit.
+    #[arg(long, value_name = "DEPTH")]
+    #[arg(default_value_t = DEFAULT_MAX_RECURSION_DEPTH)]
+    #[serde(default = "default_max_recursion_depth")]
+    pub max_recursion_depth: usize,
+
+    /// The maximum number of compiled classes kept in memory.
+    ///
+    /// Classes are compiled the first time they're executed or requested through
+    /// `starknet_getCompiledCasm`. Once the cache is full, the least recently used classes are
+    /// evicted.
+    #[arg(long)]
+    #[arg(default_value_t = DEFAULT_CLASS_CACHE_SIZE)]
+    #[serde(default = "default_class_cache_size")]
+    pub class_cache_size: NonZeroUsize,
+
+    /// The algorithm used to compute the hash of the produced blocks.
+    ///
+    /// `poseidon` is the block hash of Starknet 0.13.2 onwards, and `pedersen` the one of prior
+    /// versions.
+    #[arg(long, value_name = "ALGORITHM")]
+    #[arg(default_value_t = BlockHashAlgorithm::Poseidon)]
+    #[serde(default)]
+    pub block_hash_algorithm: BlockHashAlgorithm,
+
+    /// The maximum length of the calldata of a transaction.
+    ///
+    /// Transactions with a longer calldata are rejected. Defaults to the Starknet mainnet limit.
+    #[arg(long, value_name = "LENGTH")]
+    #[arg(default_value_t = DEFAULT_MAX_CALLDATA_LENGTH)]
+    #[serde(default = "default_max_calldata_length")]
+    pub max_calldata_length: usize,
+
+    /// The maximum length of the data of an event.
+    ///
+    /// Transactions emitting an event with a longer data are reverted. Defaults to the Starknet
+    /// mainnet limit.
+    #[arg(long, value_name = "LENGTH")]
+    #[arg(default_value_t = DEFAULT_MAX_EVENT_DATA_LENGTH)]
+    #[serde(default = "default_max_event_data_length")]
+    pub max_event_data_length: usize,
+
+    /// The maximum number of events a transaction can emit.
+    ///
+    /// Transactions emitting more events are reverted. Defaults to the Starknet mainnet limit.
+    #[arg(long, value_name = "NUM")]
+    #[arg(default_value_t = DEFAULT_MAX_EMITTED_EVENTS)]
+    #[serde(default = "default_max_emitted_events")]
+    pub max_emitted_events: usize,
 }
 
 impl Default for EnvironmentOptions {
@@ -265,7 +365,13 @@ impl Default for EnvironmentOptions {
         EnvironmentOptions {
             validate_max_steps: DEFAULT_VALIDATION_MAX_STEPS,
             invoke_max_steps: DEFAULT_INVOCATION_MAX_STEPS,
+            max_recursion_depth: DEFAULT_MAX_RECURSION_DEPTH,
+            class_cache_size: DEFAULT_CLASS_CACHE_SIZE,
+            block_hash_algorithm: BlockHashAlgorithm::Poseidon,
             chain_id: None,
+            max_calldata_length: DEFAULT_MAX_CALLDATA_LENGTH,
+            max_event_data_length: DEFAULT_MAX_EVENT_DATA_LENGTH,
+            max_emitted_events: DEFAULT_MAX_EMITTED_EVENTS,
         }
     }
 }
@@ -280,6 +386,30 @@ impl EnvironmentOptions {
             if self.invoke_max_steps == DEFAULT_INVOCATION_MAX_STEPS {
                 self.invoke_max_steps = other.invoke_max_steps;
             }
+
+            if self.max_recursion_depth == DEFAULT_MAX_RECURSION_DEPTH {
+                self.max_recursion_depth = other.max_recursion_depth;
+            }
+
+            if self.class_cache_size == DEFAULT_CLASS_CACHE_SIZE {
+                self.class_cache_size = other.class_cache_size;
+            }
+
+            if self.block_hash_algorithm == BlockHashAlgorithm::default() {
+                self.block_hash_algorithm = other.block_hash_algorithm;
+            }
+
+            if self.max_calldata_length == DEFAULT_MAX_CALLDATA_LENGTH {
+                self.max_calldata_length = other.max_calldata_length;
+            }
+
+            if self.max_event_data_length == DEFAULT_MAX_EVENT_DATA_LENGTH {
+                self.max_event_data_length = other.max_event_data_length;
+            }
+
+            if self.max_emitted_events == DEFAULT_MAX_EMITTED_EVENTS {
+                self.max_emitted_events = other.max_emitted_events;
+            }
         }
     }
 }
@@ -319,6 +449,37 @@ pub struct DevOptions {
     #[arg(long = "dev.no-account-validation")]
     #[serde(default)]
     pub no_account_validation: bool,
+
+    /// Path to the Sierra contract class used by the pre-funded accounts.
+    ///
+    /// The class must read the account's public key from the `Account_public_key` storage
+    /// variable, as in the OpenZeppelin account implementation. Defaults to Katana's built-in
+    /// account class.
+    #[arg(requires = "dev")]
+    #[arg(long = "dev.account-class", value_name = "PATH")]
+    pub account_class: Option<PathBuf>,
+
+    /// Make block production reproducible.
+    ///
+    /// Block timestamps are derived from the genesis timestamp instead of the system clock.
+    /// Together with `--dev.seed`, executing the same sequence of transactions always produces the
+    /// same block hashes, which is useful to reproduce fuzzing failures.
+    #[arg(requires = "dev")]
+    #[arg(long = "dev.deterministic")]
+    #[serde(default)]
+    pub deterministic: bool,
+
+    /// Estimate the max fee of transactions sent without one.
+    ///
+    /// V1 transactions sent with a zero max fee get their max fee set to their estimated fee
+    /// multiplied by the given value, which must be at least 1. The hash of such a transaction
+    /// stays the one of the transaction as it was sent, so it's inconsistent with the max fee the
+    /// transaction is executed with. Only meant for local scripting.
+    #[arg(requires = "dev")]
+    #[arg(long = "dev.auto-max-fee", value_name = "MULTIPLIER")]
+    #[arg(value_parser = parse_max_fee_multiplier)]
+    #[serde(default, deserialize_with = "deserialize_max_fee_multiplier")]
+    pub auto_max_fee: Option<f64>,
 }
 
 impl Default for DevOptions {
@@ -329,6 +490,9 @@ impl Default for DevOptions {
             total_accounts: DEFAULT_DEV_ACCOUNTS,
             no_fee: false,
             no_account_validation: false,
+            account_class: None,
+            deterministic: false,
+            auto_max_fee: None,
         }
     }
 }
@@ -355,6 +519,18 @@ impl DevOptions {
             if !self.no_account_validation {
                 self.no_account_validation = other.no_account_validation;
             }
+
+            if self.account_class.is_none() {
+                self.account_class = other.account_class.clone();
+            }
+
+            if !self.deterministic {
+                self.deterministic = other.deterministic;
+            }
+
+            if self.auto_max_fee.is_none() {
+                self.auto_max_fee = other.auto_max_fee;
+            }
         }
     }
 }
@@ -374,6 +550,20 @@ pub struct ForkingOptions {
     #[arg(long = "fork.block", value_name = "BLOCK", requires = "fork_provider")]
     #[arg(value_parser = parse_block_hash_or_number)]
     pub fork_block: Option<BlockHashOrNumber>,
+
+    /// Comma separated list of contract addresses whose state is eagerly fetched from the forked
+    /// network at startup. Other contracts are still fetched on demand.
+    #[arg(long = "fork.pin", value_name = "ADDRESSES", requires = "fork_provider")]
+    #[arg(value_delimiter = ',')]
+    #[serde(default)]
+    pub fork_pin: Vec<ContractAddress>,
+
+    /// Write the state of the pinned contracts to a genesis JSON file at the given path.
+    ///
+    /// The resulting file can be used with `--genesis` to start a reproducible chain without
+    /// connecting to the forked network.
+    #[arg(long = "fork.snapshot", value_name = "PATH", requires = "fork_pin")]
+    pub fork_snapshot: Option<PathBuf>,
 }
 
 #[derive(Debug, Args, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -415,6 +605,17 @@ pub struct GasPriceOracleOptions {
     #[serde(serialize_with = "cainome_cairo_serde::serialize_as_hex")]
     #[serde(deserialize_with = "cainome_cairo_serde::deserialize_from_hex")]
     pub l1_strk_data_gas_price: u128,
+
+    /// Adjust the L1 gas prices after every block based on how full it was.
+    ///
+    /// The gas prices start at the configured (or default) ones, and go up after blocks using more
+    /// than half of `--sequencing.block-max-cairo-steps`, and down otherwise. Not available for
+    /// rollup chains, whose gas prices are sampled from their settlement layer.
+    #[arg(long = "gpo.dynamic")]
+    #[arg(requires = "block_cairo_steps_limit")]
+    #[arg(conflicts_with = "chain")]
+    #[serde(default)]
+    pub dynamic: bool,
 }
 
 #[cfg(feature = "slot")]
@@ -542,6 +743,26 @@ fn default_invoke_max_steps() -> u32 {
     DEFAULT_INVOCATION_MAX_STEPS
 }
 
+fn default_class_cache_size() -> NonZeroUsize {
+    DEFAULT_CLASS_CACHE_SIZE
+}
+
+fn default_max_calldata_length() -> usize {
+    DEFAULT_MAX_CALLDATA_LENGTH
+}
+
+fn default_max_event_data_length() -> usize {
+    DEFAULT_MAX_EVENT_DATA_LENGTH
+}
+
+fn default_max_emitted_events() -> usize {
+    DEFAULT_MAX_EMITTED_EVENTS
+}
+
+fn default_max_recursion_depth() -> usize {
+    DEFAULT_MAX_RECURSION_DEPTH
+}
+
 #[cfg(feature = "server")]
 fn default_http_addr() -> IpAddr {
     DEFAULT_RPC_ADDR
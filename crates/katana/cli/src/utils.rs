@@ -1,7 +1,7 @@
 use std::fmt::Display;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, ensure, Context, Result};
 use clap::builder::PossibleValue;
 use clap::ValueEnum;
 use console::Style;
@@ -9,7 +9,7 @@ use katana_chain_spec::rollup::ChainConfigDir;
 use katana_chain_spec::ChainSpec;
 use katana_primitives::block::{BlockHash, BlockHashOrNumber, BlockNumber};
 use katana_primitives::chain::ChainId;
-use katana_primitives::class::ClassHash;
+use katana_primitives::class::{ClassHash, ContractClass};
 use katana_primitives::contract::ContractAddress;
 use katana_primitives::genesis::allocation::GenesisAccountAlloc;
 use katana_primitives::genesis::constant::{
@@ -17,6 +17,7 @@ use katana_primitives::genesis::constant::{
 };
 use katana_primitives::genesis::json::GenesisJson;
 use katana_primitives::genesis::Genesis;
+use katana_primitives::utils::class::parse_sierra_class;
 use katana_rpc::cors::HeaderValue;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use tracing::info;
@@ -43,6 +44,15 @@ pub fn parse_genesis(value: &str) -> Result<Genesis> {
     Ok(genesis)
 }
 
+/// Reads a Sierra contract class artifact to be used as the class of the dev accounts.
+pub fn read_account_class(path: &Path) -> Result<ContractClass> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read account class at {}", path.display()))?;
+    let class = parse_sierra_class(&content)
+        .with_context(|| format!("invalid Sierra class artifact at {}", path.display()))?;
+    Ok(class)
+}
+
 /// If the value starts with `0x`, it is parsed as a [`BlockHash`], otherwise as a [`BlockNumber`].
 pub fn parse_block_hash_or_number(value: &str) -> Result<BlockHashOrNumber> {
     if value.starts_with("0x") {
@@ -53,6 +63,36 @@ pub fn parse_block_hash_or_number(value: &str) -> Result<BlockHashOrNumber> {
     }
 }
 
+/// Used as clap value parser for the max fee multiplier of `--dev.auto-max-fee`.
+pub fn parse_max_fee_multiplier(value: &str) -> Result<f64> {
+    let multiplier = value.parse::<f64>().context("could not parse multiplier")?;
+    validate_max_fee_multiplier(multiplier)
+}
+
+pub fn deserialize_max_fee_multiplier<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<f64>::deserialize(deserializer)?
+        .map(validate_max_fee_multiplier)
+        .transpose()
+        .map_err(serde::de::Error::custom)
+}
+
+/// Converts a max fee multiplier to basis points, eg `1.5` to `15_000`.
+pub fn max_fee_multiplier_to_bps(multiplier: f64) -> u64 {
+    (multiplier * 10_000.0).round() as u64
+}
+
+/// The max fee must cover the estimated fee, so the multiplier can't be lower than 1.
+fn validate_max_fee_multiplier(multiplier: f64) -> Result<f64> {
+    ensure!(
+        multiplier.is_finite() && multiplier >= 1.0,
+        "max fee multiplier must be a finite number greater than or equal to 1, got {multiplier}"
+    );
+    Ok(multiplier)
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Default)]
 pub enum LogFormat {
     Json,
@@ -267,4 +307,18 @@ mod tests {
         let path = "./test-data/genesis.json";
         parse_genesis(path).unwrap();
     }
+
+    #[test]
+    fn parse_max_fee_multiplier_bounds() {
+        assert_eq!(parse_max_fee_multiplier("1").unwrap(), 1.0);
+        assert_eq!(parse_max_fee_multiplier("1.5").unwrap(), 1.5);
+
+        for value in ["0.5", "-2", "NaN", "inf", "abc"] {
+            assert!(parse_max_fee_multiplier(value).is_err(), "{value} should be rejected");
+        }
+
+        assert_eq!(max_fee_multiplier_to_bps(1.0), 10_000);
+        assert_eq!(max_fee_multiplier_to_bps(1.5), 15_000);
+        assert_eq!(max_fee_multiplier_to_bps(1.0001), 10_001);
+    }
 }
@@ -0,0 +1,41 @@
+use std::fmt::Debug;
+
+use katana_primitives::receipt::Event;
+use katana_primitives::transaction::TxHash;
+use katana_primitives::Felt;
+use katana_trie::compute_merkle_root;
+use starknet_types_core::hash::{self, StarkHash};
+
+/// Defines how the events commitment of a block is computed.
+///
+/// The commitment is computed in two steps: every event emitted in the block is first hashed
+/// into a leaf, and the leaves, in emission order, are then committed to. Appchains that use a
+/// different scheme than Starknet can provide their own implementation to the
+/// [`Backend`](super::Backend).
+pub trait EventCommitment: Debug + Send + Sync {
+    /// Hashes an event into a leaf of the commitment, `tx_hash` being the hash of the transaction
+    /// that emitted it.
+    fn event_hash(&self, tx_hash: TxHash, event: &Event) -> Felt;
+
+    /// Computes the commitment of the given leaves.
+    ///
+    /// Defaults to the root of a Merkle-Patricia trie using the Poseidon hash function.
+    fn commit(&self, leaves: &[Felt]) -> Felt {
+        compute_merkle_root::<hash::Poseidon>(leaves).unwrap()
+    }
+}
+
+/// The events commitment of Starknet.
+///
+/// An event is hashed as `h(tx_hash, emitter_address, h(keys), h(data))`, where `h` is the
+/// Poseidon hash function.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StarknetEventCommitment;
+
+impl EventCommitment for StarknetEventCommitment {
+    fn event_hash(&self, tx_hash: TxHash, event: &Event) -> Felt {
+        let keys_hash = hash::Poseidon::hash_array(&event.keys);
+        let data_hash = hash::Poseidon::hash_array(&event.data);
+        hash::Poseidon::hash_array(&[tx_hash, event.from_address.into(), keys_hash, data_hash])
+    }
+}
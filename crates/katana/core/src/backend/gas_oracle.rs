@@ -15,11 +15,15 @@ use url::Url;
 const BUFFER_SIZE: usize = 60;
 const INTERVAL: Duration = Duration::from_secs(60);
 const ONE_GWEI: u128 = 1_000_000_000;
+/// The gas prices of the [`DynamicGasOracle`] change by at most 1/8th between two blocks, like the
+/// base fee of EIP-1559.
+const DYNAMIC_PRICE_CHANGE_DENOMINATOR: u128 = 8;
 
 #[derive(Debug)]
 pub enum GasOracle {
     Fixed(FixedGasOracle),
     Sampled(EthereumSampledGasOracle),
+    Dynamic(DynamicGasOracle),
 }
 
 #[derive(Debug)]
@@ -34,6 +38,20 @@ pub struct EthereumSampledGasOracle {
     provider: Url,
 }
 
+/// A gas oracle whose gas prices react to how full the blocks are.
+///
+/// After every block, the gas prices go up if the block used more than half of its Cairo steps
+/// limit and go down otherwise, proportionally to the distance from that target. The prices never
+/// go below their initial values. The data gas prices are fixed.
+#[derive(Debug, Clone)]
+pub struct DynamicGasOracle {
+    gas_prices: Arc<Mutex<GasPrices>>,
+    min_gas_prices: GasPrices,
+    data_gas_prices: GasPrices,
+    /// The number of Cairo steps a block must use for the gas prices to remain the same.
+    target_steps: u128,
+}
+
 #[derive(Debug, Default)]
 pub struct SampledPrices {
     gas_prices: GasPrices,
@@ -59,6 +77,21 @@ impl GasOracle {
         GasOracle::Sampled(EthereumSampledGasOracle { prices, provider: eth_provider })
     }
 
+    /// Creates a new gas oracle whose gas prices start at `gas_prices` and are adjusted after every
+    /// block based on how many of the `block_cairo_steps_limit` steps it used.
+    pub fn dynamic(
+        gas_prices: GasPrices,
+        data_gas_prices: GasPrices,
+        block_cairo_steps_limit: u64,
+    ) -> Self {
+        GasOracle::Dynamic(DynamicGasOracle {
+            gas_prices: Arc::new(Mutex::new(gas_prices.clone())),
+            min_gas_prices: gas_prices,
+            data_gas_prices,
+            target_steps: (block_cairo_steps_limit / 2) as u128,
+        })
+    }
+
     /// This is just placeholder for now, as Starknet doesn't provide a way to get the L2 gas
     /// prices, we just return a fixed gas price values of 0. This is equivalent to calling
     /// [`GasOracle::fixed`] with 0 values for both gas and data prices.
@@ -73,6 +106,7 @@ impl GasOracle {
         match self {
             GasOracle::Fixed(fixed) => fixed.current_gas_prices(),
             GasOracle::Sampled(sampled) => sampled.prices.lock().gas_prices.clone(),
+            GasOracle::Dynamic(dynamic) => dynamic.current_gas_prices(),
         }
     }
 
@@ -81,12 +115,20 @@ impl GasOracle {
         match self {
             GasOracle::Fixed(fixed) => fixed.current_data_gas_prices(),
             GasOracle::Sampled(sampled) => sampled.prices.lock().data_gas_prices.clone(),
+            GasOracle::Dynamic(dynamic) => dynamic.current_data_gas_prices(),
+        }
+    }
+
+    /// Notifies the oracle that a block using `cairo_steps_used` Cairo steps has been mined.
+    pub fn on_block_mined(&self, cairo_steps_used: u128) {
+        if let GasOracle::Dynamic(dynamic) = self {
+            dynamic.update(cairo_steps_used);
         }
     }
 
     pub fn run_worker(&self, task_spawner: TaskSpawner) {
         match self {
-            Self::Fixed(..) => {}
+            Self::Fixed(..) | Self::Dynamic(..) => {}
             Self::Sampled(oracle) => {
                 let prices = oracle.prices.clone();
                 let l1_provider = oracle.provider.clone();
@@ -125,6 +167,44 @@ impl FixedGasOracle {
     }
 }
 
+impl DynamicGasOracle {
+    pub fn current_data_gas_prices(&self) -> GasPrices {
+        self.data_gas_prices.clone()
+    }
+
+    pub fn current_gas_prices(&self) -> GasPrices {
+        self.gas_prices.lock().clone()
+    }
+
+    fn update(&self, cairo_steps_used: u128) {
+        let mut prices = self.gas_prices.lock();
+        let next = |price, min| next_dynamic_price(price, min, cairo_steps_used, self.target_steps);
+        prices.eth = next(prices.eth, self.min_gas_prices.eth);
+        prices.strk = next(prices.strk, self.min_gas_prices.strk);
+    }
+}
+
+/// Computes the price following a block that used `used` steps, the same way EIP-1559 computes
+/// the base fee from the gas used by the parent block.
+fn next_dynamic_price(price: u128, min: u128, used: u128, target: u128) -> u128 {
+    if target == 0 || used == target {
+        return price;
+    }
+
+    // the price always moves by at least 1, so that low prices don't get stuck
+    let delta = |diff: u128| {
+        (price.saturating_mul(diff) / target / DYNAMIC_PRICE_CHANGE_DENOMINATOR).max(1)
+    };
+
+    let next = if used > target {
+        price.saturating_add(delta(used - target))
+    } else {
+        price.saturating_sub(delta(target - used))
+    };
+
+    next.max(min)
+}
+
 pub fn update_gas_price(
     l1_oracle: &mut SampledPrices,
     gas_price_buffer: &mut GasPriceBuffer,
@@ -293,6 +373,33 @@ mod tests {
         assert_eq!(buffer.average(), expected_avg);
     }
 
+    #[test]
+    fn dynamic_prices_follow_block_fullness() {
+        let initial = GasPrices { eth: 800, strk: 1600 };
+        let oracle = GasOracle::dynamic(initial.clone(), GasPrices { eth: 1, strk: 1 }, 200);
+
+        // a block that uses exactly half of its limit doesn't change the prices
+        oracle.on_block_mined(100);
+        assert_eq!(oracle.current_gas_prices(), initial);
+
+        // a full block raises the prices by 1/8th
+        oracle.on_block_mined(200);
+        assert_eq!(oracle.current_gas_prices(), GasPrices { eth: 900, strk: 1800 });
+
+        // an empty block lowers them by 1/8th, but never below the initial prices
+        oracle.on_block_mined(0);
+        assert_eq!(oracle.current_gas_prices(), initial);
+
+        // the data gas prices are fixed
+        assert_eq!(oracle.current_data_gas_prices(), GasPrices { eth: 1, strk: 1 });
+    }
+
+    #[test]
+    fn dynamic_price_always_moves() {
+        assert_eq!(next_dynamic_price(1, 1, 150, 100), 2);
+        assert_eq!(next_dynamic_price(2, 1, 50, 100), 1);
+    }
+
     #[tokio::test]
     #[ignore = "Requires external assumption"]
     async fn test_gas_oracle() {
@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use anyhow::{anyhow, Context};
@@ -6,43 +6,75 @@ use gas_oracle::GasOracle;
 use katana_chain_spec::ChainSpec;
 use katana_executor::{ExecutionOutput, ExecutionResult, ExecutorFactory};
 use katana_primitives::block::{
-    BlockHash, BlockNumber, FinalityStatus, Header, PartialHeader, SealedBlock,
+    BlockHash, BlockHashAlgorithm, BlockNumber, FinalityStatus, Header, PartialHeader, SealedBlock,
     SealedBlockWithStatus,
 };
 use katana_primitives::class::{ClassHash, CompiledClassHash};
 use katana_primitives::da::L1DataAvailabilityMode;
 use katana_primitives::env::BlockEnv;
-use katana_primitives::receipt::{Event, Receipt, ReceiptWithTxHash};
+use katana_primitives::receipt::{Receipt, ReceiptWithTxHash};
 use katana_primitives::state::{compute_state_diff_hash, StateUpdates, StateUpdatesWithClasses};
 use katana_primitives::trace::TxExecInfo;
 use katana_primitives::transaction::{TxHash, TxWithHash};
 use katana_primitives::version::CURRENT_STARKNET_VERSION;
-use katana_primitives::{address, ContractAddress, Felt};
+use katana_primitives::{address, Felt};
 use katana_provider::providers::in_memory::state::EmptyStateProvider;
-use katana_provider::traits::block::{BlockHashProvider, BlockWriter};
+use katana_provider::traits::block::{BlockHashProvider, BlockNumberProvider, BlockWriter};
 use katana_provider::traits::trie::TrieWriter;
-use katana_trie::bonsai::databases::HashMapDb;
-use katana_trie::{
-    compute_contract_state_hash, compute_merkle_root, ClassesTrie, CommitId, ContractLeaf,
-    ContractsTrie, StoragesTrie,
-};
+use katana_trie::compute_merkle_root;
 use parking_lot::RwLock;
 use rayon::prelude::*;
 use starknet::macros::short_string;
 use starknet_types_core::hash::{self, StarkHash};
+use tokio::sync::broadcast;
 use tracing::info;
 
+pub mod commitment;
 pub mod contract;
 pub mod gas_oracle;
+pub mod replay;
 pub mod storage;
 
+use self::commitment::{EventCommitment, StarknetEventCommitment};
 use self::storage::Blockchain;
 use crate::env::BlockContextGenerator;
-use crate::service::block_producer::{BlockProductionError, MinedBlockOutcome};
+use crate::service::block_producer::{BlockError, BlockProductionError, MinedBlockOutcome};
 use crate::utils::get_current_timestamp;
 
 pub(crate) const LOG_TARGET: &str = "katana::core::backend";
 
+/// The number of [`BlockFinalized`] notifications buffered for subscribers that lag behind.
+const FINALIZED_BLOCKS_CHANNEL_CAPACITY: usize = 64;
+
+/// Notification sent once a block has been inserted in the chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockFinalized {
+    pub number: BlockNumber,
+    pub hash: BlockHash,
+}
+
+/// Broadcasts the blocks inserted in the chain to its subscribers.
+#[derive(Debug, Clone)]
+pub struct FinalizedBlocks(broadcast::Sender<BlockFinalized>);
+
+impl FinalizedBlocks {
+    /// Returns a receiver of the blocks inserted from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<BlockFinalized> {
+        self.0.subscribe()
+    }
+
+    fn notify(&self, block: BlockFinalized) {
+        // sending only fails if there are no subscribers
+        let _ = self.0.send(block);
+    }
+}
+
+impl Default for FinalizedBlocks {
+    fn default() -> Self {
+        Self(broadcast::channel(FINALIZED_BLOCKS_CHANNEL_CAPACITY).0)
+    }
+}
+
 #[derive(Debug)]
 pub struct Backend<EF> {
     pub chain_spec: Arc<ChainSpec>,
@@ -54,6 +86,12 @@ pub struct Backend<EF> {
     pub executor_factory: Arc<EF>,
 
     pub gas_oracle: GasOracle,
+
+    /// The scheme used to compute the events commitment of the produced blocks.
+    pub event_commitment: Arc<dyn EventCommitment>,
+
+    /// Notifies the subscribers every time a new block is inserted in the chain.
+    pub finalized_blocks: FinalizedBlocks,
 }
 
 impl<EF> Backend<EF> {
@@ -69,8 +107,25 @@ impl<EF> Backend<EF> {
             gas_oracle,
             executor_factory: Arc::new(executor_factory),
             block_context_generator: RwLock::new(BlockContextGenerator::default()),
+            event_commitment: Arc::new(StarknetEventCommitment),
+            finalized_blocks: FinalizedBlocks::default(),
         }
     }
+
+    /// Sets the scheme used to compute the events commitment of the produced blocks. Default is
+    /// [`StarknetEventCommitment`].
+    pub fn with_event_commitment(mut self, event_commitment: Arc<dyn EventCommitment>) -> Self {
+        self.event_commitment = event_commitment;
+        self
+    }
+
+    /// Subscribes to the blocks inserted in the chain.
+    ///
+    /// A notification is only sent after the block is fully stored, so it can be queried from the
+    /// provider as soon as it's received.
+    pub fn subscribe_finalized_blocks(&self) -> broadcast::Receiver<BlockFinalized> {
+        self.finalized_blocks.subscribe()
+    }
 }
 
 impl<EF: ExecutorFactory> Backend<EF> {
@@ -122,6 +177,8 @@ impl<EF: ExecutorFactory> Backend<EF> {
             transactions,
             &receipts,
             &mut execution_output.states.state_updates,
+            self.executor_factory.cfg().block_hash_algorithm,
+            self.event_commitment.clone(),
         )?;
 
         let block = SealedBlockWithStatus { block, status: FinalityStatus::AcceptedOnL2 };
@@ -131,6 +188,7 @@ impl<EF: ExecutorFactory> Backend<EF> {
         // accept ReceiptWithTxHash instead to avoid this conversion.
         let receipts = receipts.into_iter().map(|r| r.receipt).collect::<Vec<_>>();
         self.store_block(block, execution_output.states, receipts, traces)?;
+        self.gas_oracle.on_block_mined(execution_output.stats.cairo_steps_used);
 
         info!(target: LOG_TARGET, %block_number, %tx_count, "Block mined.");
         Ok(MinedBlockOutcome { block_number, txs: tx_hashes, stats: execution_output.stats })
@@ -143,15 +201,57 @@ impl<EF: ExecutorFactory> Backend<EF> {
         receipts: Vec<Receipt>,
         traces: Vec<TxExecInfo>,
     ) -> Result<(), BlockProductionError> {
+        // the genesis block has no parent to be validated against
+        if block.block.header.number != self.chain_spec.genesis().number {
+            self.validate_block(&block.block)?;
+        }
+
+        let finalized =
+            BlockFinalized { number: block.block.header.number, hash: block.block.hash };
         self.blockchain
             .provider()
             .insert_block_with_states_and_receipts(block, states, receipts, traces)?;
+
+        self.finalized_blocks.notify(finalized);
+        Ok(())
+    }
+
+    /// Ensures that the block directly extends the current chain tip.
+    ///
+    /// The block timestamp isn't checked against the tip's one. `dev_setNextBlockTimestamp` can
+    /// set the next block timestamp in the past, so a block older than its parent is valid here.
+    fn validate_block(&self, block: &SealedBlock) -> Result<(), BlockProductionError> {
+        let provider = self.blockchain.provider();
+
+        let hash = block.hash;
+        let number = block.header.number;
+
+        if let Some(existing) = provider.block_number_by_hash(hash)? {
+            return Err(BlockError::DuplicateHash { hash, number, existing }.into());
+        }
+
+        let tip = provider.latest_number()?;
+
+        if number != tip + 1 {
+            return Err(BlockError::NonSequential { hash, number, tip }.into());
+        }
+
+        let tip_hash = provider.latest_hash()?;
+        let parent_hash = block.header.parent_hash;
+
+        if parent_hash != tip_hash {
+            return Err(BlockError::ParentMismatch { hash, number, parent_hash, tip_hash }.into());
+        }
+
         Ok(())
     }
 
     pub fn update_block_env(&self, block_env: &mut BlockEnv) {
         let mut context_gen = self.block_context_generator.write();
-        let current_timestamp_secs = get_current_timestamp().as_secs() as i64;
+        let current_timestamp_secs = match context_gen.deterministic_clock {
+            Some(start) => (start + block_env.number + 1) as i64,
+            None => get_current_timestamp().as_secs() as i64,
+        };
 
         let timestamp = if context_gen.next_block_start_time == 0 {
             (current_timestamp_secs + context_gen.block_timestamp_offset) as u64
@@ -187,12 +287,13 @@ impl<EF: ExecutorFactory> Backend<EF> {
         chain_spec: &katana_chain_spec::dev::ChainSpec,
     ) -> anyhow::Result<()> {
         let provider = self.blockchain.provider();
+        let hash_algorithm = self.executor_factory.cfg().block_hash_algorithm;
 
         // check whether the genesis block has been initialized
         let local_hash = provider.block_hash_by_num(chain_spec.genesis.number)?;
 
         if let Some(local_hash) = local_hash {
-            let genesis_hash = chain_spec.block().header.compute_hash();
+            let genesis_hash = chain_spec.block_hash(hash_algorithm);
             // check genesis should be the same
             if local_hash != genesis_hash {
                 return Err(anyhow!(
@@ -204,12 +305,9 @@ impl<EF: ExecutorFactory> Backend<EF> {
         } else {
             // Initialize the dev genesis block
 
-            let block = chain_spec.block().seal();
-            let block = SealedBlockWithStatus { block, status: FinalityStatus::AcceptedOnL1 };
+            let mut block = chain_spec.block();
             let states = chain_spec.state_updates();
-
-            let mut block = block;
-            let block_number = block.block.header.number;
+            let block_number = block.header.number;
 
             let class_trie_root = provider
                 .trie_insert_declared_classes(block_number, &states.state_updates.declared_classes)
@@ -225,7 +323,11 @@ impl<EF: ExecutorFactory> Backend<EF> {
                 class_trie_root,
             ]);
 
-            block.block.header.state_root = genesis_state_root;
+            // the state root must be set before hashing the block, for its hash to commit to the
+            // genesis state
+            block.header.state_root = genesis_state_root;
+            let hash = block.header.compute_hash_with(hash_algorithm);
+            let block = block.seal_with_hash_and_status(hash, FinalityStatus::AcceptedOnL1);
             provider.insert_block_with_states_and_receipts(block, states, vec![], vec![])?;
 
             info!("Genesis initialized");
@@ -239,6 +341,7 @@ impl<EF: ExecutorFactory> Backend<EF> {
         chain_spec: &katana_chain_spec::rollup::ChainSpec,
     ) -> anyhow::Result<()> {
         let provider = self.blockchain.provider();
+        let hash_algorithm = self.executor_factory.cfg().block_hash_algorithm;
 
         let block = chain_spec.block();
         let header = block.header.clone();
@@ -273,6 +376,8 @@ impl<EF: ExecutorFactory> Backend<EF> {
                 transactions.clone(),
                 &receipts,
                 &mut output.states.state_updates,
+                hash_algorithm,
+                self.event_commitment.clone(),
             )?;
 
             let provided_genesis_hash = block.hash;
@@ -291,6 +396,8 @@ impl<EF: ExecutorFactory> Backend<EF> {
                 transactions,
                 &receipts,
                 &mut output.states.state_updates,
+                hash_algorithm,
+                self.event_commitment.clone(),
             )?;
 
             let block = SealedBlockWithStatus { block, status: FinalityStatus::AcceptedOnL2 };
@@ -314,6 +421,8 @@ pub struct UncommittedBlock<'a, P: TrieWriter> {
     receipts: &'a [ReceiptWithTxHash],
     state_updates: &'a StateUpdates,
     provider: P,
+    hash_algorithm: BlockHashAlgorithm,
+    event_commitment: Arc<dyn EventCommitment>,
 }
 
 impl<'a, P: TrieWriter> UncommittedBlock<'a, P> {
@@ -324,15 +433,38 @@ impl<'a, P: TrieWriter> UncommittedBlock<'a, P> {
         state_updates: &'a StateUpdates,
         trie_provider: P,
     ) -> Self {
-        Self { header, transactions, receipts, state_updates, provider: trie_provider }
+        Self {
+            header,
+            transactions,
+            receipts,
+            state_updates,
+            provider: trie_provider,
+            hash_algorithm: BlockHashAlgorithm::default(),
+            event_commitment: Arc::new(StarknetEventCommitment),
+        }
+    }
+
+    /// Sets the scheme used to compute the events commitment. Default is
+    /// [`StarknetEventCommitment`].
+    pub fn with_event_commitment(mut self, event_commitment: Arc<dyn EventCommitment>) -> Self {
+        self.event_commitment = event_commitment;
+        self
     }
 
-    pub fn commit(self) -> SealedBlock {
+    /// Sets the algorithm used to compute the block hash. Default is
+    /// [`BlockHashAlgorithm::Poseidon`].
+    pub fn with_hash_algorithm(mut self, algorithm: BlockHashAlgorithm) -> Self {
+        self.hash_algorithm = algorithm;
+        self
+    }
+
+    pub fn commit(self) -> Result<SealedBlock, BlockProductionError> {
         // get the hash of the latest committed block
         let parent_hash = self.header.parent_hash;
-        let events_count = self.receipts.iter().map(|r| r.events().len() as u32).sum::<u32>();
-        let transaction_count = self.transactions.len() as u32;
-        let state_diff_length = self.state_updates.len() as u32;
+        let events_count = self.receipts.iter().map(|r| r.events().len()).sum();
+        let events_count = header_count("events", events_count)?;
+        let transaction_count = header_count("transactions", self.transactions.len())?;
+        let state_diff_length = header_count("state diff entries", self.state_updates.len())?;
 
         // optimisation 1
         let state_root = self.compute_new_state_root();
@@ -360,17 +492,18 @@ impl<'a, P: TrieWriter> UncommittedBlock<'a, P> {
             protocol_version: self.header.protocol_version,
         };
 
-        let hash = header.compute_hash();
+        let hash = header.compute_hash_with(self.hash_algorithm);
 
-        SealedBlock { hash, header, body: self.transactions }
+        Ok(SealedBlock { hash, header, body: self.transactions })
     }
 
-    pub fn commit_parallel(self) -> SealedBlock {
+    pub fn commit_parallel(self) -> Result<SealedBlock, BlockProductionError> {
         // get the hash of the latest committed block
         let parent_hash = self.header.parent_hash;
-        let events_count = self.receipts.iter().map(|r| r.events().len() as u32).sum::<u32>();
-        let transaction_count = self.transactions.len() as u32;
-        let state_diff_length = self.state_updates.len() as u32;
+        let events_count = self.receipts.iter().map(|r| r.events().len()).sum();
+        let events_count = header_count("events", events_count)?;
+        let transaction_count = header_count("transactions", self.transactions.len())?;
+        let state_diff_length = header_count("state diff entries", self.state_updates.len())?;
 
         let mut state_root = Felt::default();
         let mut transactions_commitment = Felt::default();
@@ -405,9 +538,9 @@ impl<'a, P: TrieWriter> UncommittedBlock<'a, P> {
             protocol_version: self.header.protocol_version,
         };
 
-        let hash = header.compute_hash();
+        let hash = header.compute_hash_with(self.hash_algorithm);
 
-        SealedBlock { hash, header, body: self.transactions }
+        Ok(SealedBlock { hash, header, body: self.transactions })
     }
 
     fn compute_transaction_commitment(&self) -> Felt {
@@ -431,45 +564,31 @@ impl<'a, P: TrieWriter> UncommittedBlock<'a, P> {
     }
 
     fn compute_event_commitment(&self) -> Felt {
-        // h(emitter_address, tx_hash, h(keys), h(data))
-        fn event_hash(tx: TxHash, event: &Event) -> Felt {
-            let keys_hash = hash::Poseidon::hash_array(&event.keys);
-            let data_hash = hash::Poseidon::hash_array(&event.data);
-            hash::Poseidon::hash_array(&[tx, event.from_address.into(), keys_hash, data_hash])
-        }
-
         // the iterator will yield all events from all the receipts, each one paired with the
         // transaction hash that emitted it: (tx hash, event).
         let events = self.receipts.iter().flat_map(|r| r.events().iter().map(|e| (r.tx_hash, e)));
         let mut hashes = Vec::new();
         for (tx, event) in events {
-            let event_hash = event_hash(tx, event);
+            let event_hash = self.event_commitment.event_hash(tx, event);
             hashes.push(event_hash);
         }
 
         // compute events commitment
-        compute_merkle_root::<hash::Poseidon>(&hashes).unwrap()
+        self.event_commitment.commit(&hashes)
     }
 
     fn compute_event_commitment_parallel(&self) -> Felt {
-        // h(emitter_address, tx_hash, h(keys), h(data))
-        fn event_hash(tx: TxHash, event: &Event) -> Felt {
-            let keys_hash = hash::Poseidon::hash_array(&event.keys);
-            let data_hash = hash::Poseidon::hash_array(&event.data);
-            hash::Poseidon::hash_array(&[tx, event.from_address.into(), keys_hash, data_hash])
-        }
-
         // the iterator will yield all events from all the receipts, each one paired with the
         // transaction hash that emitted it: (tx hash, event).
         let events = self.receipts.iter().flat_map(|r| r.events().iter().map(|e| (r.tx_hash, e)));
         let hashes = events
             .par_bridge()
             .into_par_iter()
-            .map(|(tx, event)| event_hash(tx, event))
+            .map(|(tx, event)| self.event_commitment.event_hash(tx, event))
             .collect::<Vec<_>>();
 
         // compute events commitment
-        compute_merkle_root::<hash::Poseidon>(&hashes).unwrap()
+        self.event_commitment.commit(&hashes)
     }
 
     // state_commitment = hPos("STARKNET_STATE_V0", contract_trie_root, class_trie_root)
@@ -494,6 +613,11 @@ impl<'a, P: TrieWriter> UncommittedBlock<'a, P> {
 
 // TODO: create a dedicated struct for this contract.
 // https://docs.starknet.io/architecture-and-concepts/network-architecture/starknet-state/#address_0x1
+//
+// The `get_block_hash` syscall of a transaction in block `n` can query the hash of any block up to
+// `n - STORED_BLOCK_HASH_BUFFER`, and reads it from the storage of this contract. The transactions
+// of a block are executed on top of the state of its parent, so when committing block `n`, we
+// store the hash of the last block the transactions of block `n + 1` are allowed to query.
 fn update_block_hash_registry_contract(
     provider: impl BlockHashProvider,
     state_updates: &mut StateUpdates,
@@ -501,12 +625,13 @@ fn update_block_hash_registry_contract(
 ) -> Result<(), BlockProductionError> {
     const STORED_BLOCK_HASH_BUFFER: u64 = 10;
 
-    if block_number >= STORED_BLOCK_HASH_BUFFER {
-        let block_number = block_number - STORED_BLOCK_HASH_BUFFER;
+    let next_block_number = block_number + 1;
+    if next_block_number >= STORED_BLOCK_HASH_BUFFER {
+        let block_number = next_block_number - STORED_BLOCK_HASH_BUFFER;
         let block_hash = provider.block_hash_by_num(block_number)?;
 
         // When in forked mode, we might not have the older block hash in the database. This
-        // could be the case where the `block_number - STORED_BLOCK_HASH_BUFFER` is
+        // could be the case where the `next_block_number - STORED_BLOCK_HASH_BUFFER` is
         // earlier than the forked block, which right now, Katana doesn't
         // yet have the ability to fetch older blocks on the database level. So, we default to
         // `BlockHash::ZERO` in this case.
@@ -527,13 +652,18 @@ fn commit_block<P>(
     transactions: Vec<TxWithHash>,
     receipts: &[ReceiptWithTxHash],
     state_updates: &mut StateUpdates,
+    hash_algorithm: BlockHashAlgorithm,
+    event_commitment: Arc<dyn EventCommitment>,
 ) -> Result<SealedBlock, BlockProductionError>
 where
     P: BlockHashProvider + TrieWriter,
 {
     // Update special contract address 0x1
     update_block_hash_registry_contract(&provider, state_updates, header.number)?;
-    Ok(UncommittedBlock::new(header, transactions, receipts, state_updates, &provider).commit())
+    let block = UncommittedBlock::new(header, transactions, receipts, state_updates, &provider)
+        .with_hash_algorithm(hash_algorithm)
+        .with_event_commitment(event_commitment);
+    block.commit()
 }
 
 fn commit_genesis_block(
@@ -542,8 +672,19 @@ fn commit_genesis_block(
     transactions: Vec<TxWithHash>,
     receipts: &[ReceiptWithTxHash],
     state_updates: &mut StateUpdates,
+    hash_algorithm: BlockHashAlgorithm,
+    event_commitment: Arc<dyn EventCommitment>,
 ) -> Result<SealedBlock, BlockProductionError> {
-    Ok(UncommittedBlock::new(header, transactions, receipts, state_updates, &provider).commit())
+    UncommittedBlock::new(header, transactions, receipts, state_updates, &provider)
+        .with_hash_algorithm(hash_algorithm)
+        .with_event_commitment(event_commitment)
+        .commit()
+}
+
+/// Converts the number of entries of a block to the 32 bits width of its header field, as
+/// truncating it would silently commit to a wrong count in the block hash.
+fn header_count(kind: &'static str, count: usize) -> Result<u32, BlockProductionError> {
+    u32::try_from(count).map_err(|_| BlockProductionError::TooManyEntries { kind, count })
 }
 
 #[derive(Debug)]
@@ -552,74 +693,112 @@ struct GenesisTrieWriter;
 impl TrieWriter for GenesisTrieWriter {
     fn trie_insert_contract_updates(
         &self,
-        block_number: BlockNumber,
+        _block_number: BlockNumber,
         state_updates: &StateUpdates,
     ) -> katana_provider::ProviderResult<Felt> {
-        let mut contract_trie_db = ContractsTrie::new(HashMapDb::<CommitId>::default());
-        let mut contract_leafs: HashMap<ContractAddress, ContractLeaf> = HashMap::new();
-
-        let leaf_hashes = {
-            for (address, nonce) in &state_updates.nonce_updates {
-                contract_leafs.entry(*address).or_default().nonce = Some(*nonce);
-            }
-
-            for (address, class_hash) in &state_updates.deployed_contracts {
-                contract_leafs.entry(*address).or_default().class_hash = Some(*class_hash);
-            }
-
-            for (address, class_hash) in &state_updates.replaced_classes {
-                contract_leafs.entry(*address).or_default().class_hash = Some(*class_hash);
-            }
-
-            for (address, storage_entries) in &state_updates.storage_updates {
-                let mut storage_trie_db =
-                    StoragesTrie::new(HashMapDb::<CommitId>::default(), *address);
-
-                for (key, value) in storage_entries {
-                    storage_trie_db.insert(*key, *value);
-                }
-
-                // Then we commit them
-                storage_trie_db.commit(block_number);
-                let storage_root = storage_trie_db.root();
-
-                // insert the contract address in the contract_leafs to put the storage root
-                // later
-                contract_leafs.entry(*address).or_default().storage_root = Some(storage_root);
-            }
-
-            contract_leafs
-                .into_iter()
-                .map(|(address, leaf)| {
-                    let class_hash = leaf.class_hash.unwrap();
-                    let nonce = leaf.nonce.unwrap_or_default();
-                    let storage_root = leaf.storage_root.unwrap_or_default();
-                    let leaf_hash = compute_contract_state_hash(&class_hash, &storage_root, &nonce);
-                    (address, leaf_hash)
-                })
-                .collect::<Vec<_>>()
-        };
-
-        for (k, v) in leaf_hashes {
-            contract_trie_db.insert(k, v);
-        }
-
-        contract_trie_db.commit(block_number);
-        Ok(contract_trie_db.root())
+        Ok(katana_trie::compute_contracts_root(state_updates))
     }
 
     fn trie_insert_declared_classes(
         &self,
-        block_number: BlockNumber,
+        _block_number: BlockNumber,
         updates: &BTreeMap<ClassHash, CompiledClassHash>,
     ) -> katana_provider::ProviderResult<Felt> {
-        let mut trie = ClassesTrie::new(HashMapDb::default());
+        Ok(katana_trie::compute_classes_root(updates))
+    }
+}
 
-        for (class_hash, compiled_hash) in updates {
-            trie.insert(*class_hash, *compiled_hash);
-        }
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use katana_chain_spec::dev;
+    use katana_executor::implementation::blockifier::BlockifierFactory;
+    use katana_executor::BlockLimits;
+    use katana_primitives::env::CfgEnv;
+    use katana_primitives::felt;
+    use katana_provider::providers::db::DbProvider;
+    use katana_provider::traits::block::HeaderProvider;
+
+    use super::*;
+
+    /// Returns a backend whose chain is made of the genesis block and one empty block.
+    fn backend() -> Backend<BlockifierFactory> {
+        let chain_spec = ChainSpec::Dev(dev::ChainSpec::default());
+        let cfg = CfgEnv { chain_id: chain_spec.id(), ..Default::default() };
+        let executor = BlockifierFactory::new(cfg, Default::default(), BlockLimits::max());
+
+        let backend = Backend::new(
+            chain_spec.into(),
+            Blockchain::new(DbProvider::new_ephemeral()),
+            GasOracle::sampled_starknet(),
+            executor,
+        );
+
+        backend.init_genesis().expect("failed to initialize genesis");
+        let block_env = BlockEnv { number: 1, ..Default::default() };
+        backend.mine_empty_block(&block_env).expect("failed to mine block");
+
+        backend
+    }
+
+    /// Returns the block following the chain tip of `backend`, with the given hash.
+    fn next_block(backend: &Backend<BlockifierFactory>, hash: BlockHash) -> SealedBlock {
+        let provider = backend.blockchain.provider();
+        let mut header = provider.header(1.into()).unwrap().expect("missing header");
+        header.number = 2;
+        header.parent_hash = provider.latest_hash().unwrap();
+        SealedBlock { hash, header, body: Vec::new() }
+    }
+
+    #[test]
+    fn reject_block_with_wrong_parent() {
+        let backend = backend();
+        let tip_hash = backend.blockchain.provider().latest_hash().unwrap();
+
+        let mut block = next_block(&backend, felt!("0x1337"));
+        block.header.parent_hash = felt!("0xdead");
+
+        let err = backend.validate_block(&block).unwrap_err();
+        assert_matches!(
+            err,
+            BlockProductionError::InvalidBlock(BlockError::ParentMismatch {
+                number: 2,
+                parent_hash,
+                tip_hash: expected,
+                ..
+            }) if parent_hash == felt!("0xdead") && expected == tip_hash
+        );
+
+        // the same block is accepted once it points to the chain tip
+        block.header.parent_hash = tip_hash;
+        backend.validate_block(&block).expect("block should extend the chain tip");
+    }
+
+    #[test]
+    fn reject_block_with_duplicate_hash() {
+        let backend = backend();
+        let tip_hash = backend.blockchain.provider().latest_hash().unwrap();
+
+        // a block that correctly extends the chain tip, but whose hash is the one of block 1
+        let block = next_block(&backend, tip_hash);
+
+        let err = backend.validate_block(&block).unwrap_err();
+        assert_matches!(
+            err,
+            BlockProductionError::InvalidBlock(BlockError::DuplicateHash {
+                hash,
+                number: 2,
+                existing: 1,
+            }) if hash == tip_hash
+        );
+    }
 
-        trie.commit(block_number);
-        Ok(trie.root())
+    #[test]
+    fn reject_header_count_over_32_bits() {
+        assert_eq!(header_count("transactions", u32::MAX as usize).unwrap(), u32::MAX);
+        assert_matches!(
+            header_count("transactions", u32::MAX as usize + 1),
+            Err(BlockProductionError::TooManyEntries { kind: "transactions", .. })
+        );
     }
 }
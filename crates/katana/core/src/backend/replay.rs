@@ -0,0 +1,127 @@
+use katana_executor::{ExecutorError, ExecutorFactory};
+use katana_primitives::block::{BlockHash, BlockNumber};
+use katana_primitives::class::ClassHash;
+use katana_primitives::env::BlockEnv;
+use katana_primitives::transaction::{
+    DeclareTxWithClass, ExecutableTx, ExecutableTxWithHash, Tx, TxHash, TxWithHash,
+};
+use katana_provider::error::ProviderError;
+use katana_provider::traits::block::{BlockHashProvider, BlockNumberProvider, BlockProvider};
+use katana_provider::traits::contract::ContractClassProvider;
+use katana_provider::traits::state::{StateFactoryProvider, StateProvider};
+use tracing::info;
+
+use super::{Backend, LOG_TARGET};
+use crate::service::block_producer::BlockProductionError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReplayError {
+    #[error("block {number} diverged: expected hash {expected:#x}, got {actual:#x}")]
+    Divergence { number: BlockNumber, expected: BlockHash, actual: BlockHash },
+
+    #[error("missing block {0} in the source chain")]
+    MissingBlock(BlockNumber),
+
+    #[error("missing class {0:#x} in the source chain")]
+    MissingClass(ClassHash),
+
+    #[error("transaction {0:#x} can't be re-executed")]
+    UnsupportedTransaction(TxHash),
+
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+
+    #[error(transparent)]
+    Executor(#[from] ExecutorError),
+
+    #[error(transparent)]
+    BlockProduction(#[from] BlockProductionError),
+}
+
+impl<EF: ExecutorFactory> Backend<EF> {
+    /// Re-executes the blocks of the `source` chain on top of this backend's chain tip, and checks
+    /// that the recomputed block hashes match the ones stored in `source`.
+    ///
+    /// The backend is expected to be initialized with the same genesis as the `source` chain. The
+    /// replay stops at the first block whose hash diverges.
+    pub fn replay<P>(&self, source: &P) -> Result<(), ReplayError>
+    where
+        P: BlockProvider + StateFactoryProvider,
+    {
+        let provider = self.blockchain.provider();
+
+        let start = provider.latest_number()?;
+        let end = source.latest_number()?;
+
+        // the replay can only start from a block that is shared by both chains
+        let expected = source.block_hash_by_num(start)?.ok_or(ReplayError::MissingBlock(start))?;
+        let actual = provider.latest_hash()?;
+        if expected != actual {
+            return Err(ReplayError::Divergence { number: start, expected, actual });
+        }
+
+        // classes are never removed from the state, so the latest state of the source chain
+        // contains the classes of all the declare transactions.
+        let classes = source.latest()?;
+
+        for number in (start + 1)..=end {
+            let block = source.block(number.into())?.ok_or(ReplayError::MissingBlock(number))?;
+            let expected =
+                source.block_hash_by_num(number)?.ok_or(ReplayError::MissingBlock(number))?;
+
+            let transactions = block
+                .body
+                .into_iter()
+                .map(|tx| executable_tx(tx, &*classes))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let block_env = BlockEnv {
+                number,
+                timestamp: block.header.timestamp,
+                l1_gas_prices: block.header.l1_gas_prices,
+                l1_data_gas_prices: block.header.l1_data_gas_prices,
+                sequencer_address: block.header.sequencer_address,
+            };
+
+            let state = provider.latest()?;
+            let mut executor =
+                self.executor_factory.with_state_and_block_env(state, block_env.clone());
+
+            if let (_, Some(error)) = executor.execute_transactions(transactions)? {
+                return Err(error.into());
+            }
+
+            let output = executor.take_execution_output()?;
+            self.do_mine_block(&block_env, output)?;
+
+            let actual = provider.latest_hash()?;
+            if expected != actual {
+                return Err(ReplayError::Divergence { number, expected, actual });
+            }
+
+            info!(target: LOG_TARGET, block_number = %number, "Block replayed.");
+        }
+
+        Ok(())
+    }
+}
+
+fn executable_tx(
+    tx: TxWithHash,
+    classes: &dyn StateProvider,
+) -> Result<ExecutableTxWithHash, ReplayError> {
+    let transaction = match tx.transaction {
+        Tx::Invoke(tx) => ExecutableTx::Invoke(tx),
+        Tx::L1Handler(tx) => ExecutableTx::L1Handler(tx),
+        Tx::DeployAccount(tx) => ExecutableTx::DeployAccount(tx),
+        Tx::Declare(tx) => {
+            let class_hash = tx.class_hash();
+            let class = classes.class(class_hash)?.ok_or(ReplayError::MissingClass(class_hash))?;
+            ExecutableTx::Declare(DeclareTxWithClass::new(tx, class))
+        }
+        // legacy deploy transactions can't be executed anymore
+        Tx::Deploy(_) => return Err(ReplayError::UnsupportedTransaction(tx.hash)),
+    };
+
+    Ok(ExecutableTxWithHash { hash: tx.hash, transaction })
+}
@@ -1,19 +1,29 @@
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use anyhow::{anyhow, bail, Context, Result};
 use katana_db::mdbx::DbEnv;
 use katana_primitives::block::{
-    BlockHashOrNumber, BlockIdOrTag, BlockNumber, FinalityStatus, SealedBlockWithStatus,
+    BlockHashAlgorithm, BlockHashOrNumber, BlockIdOrTag, BlockNumber, FinalityStatus,
 };
+use katana_primitives::contract::ContractAddress;
 use katana_primitives::da::L1DataAvailabilityMode;
+use katana_primitives::genesis::allocation::{GenesisAllocation, GenesisContractAlloc};
+use katana_primitives::genesis::constant::get_fee_token_balance_base_storage_address;
+use katana_primitives::genesis::Genesis;
 use katana_primitives::hash::{self, StarkHash};
+use katana_primitives::utils::join_u256;
+use katana_primitives::Felt;
 use katana_provider::providers::db::DbProvider;
 use katana_provider::providers::fork::ForkedProvider;
 use katana_provider::traits::block::{BlockProvider, BlockWriter};
-use katana_provider::traits::contract::{ContractClassWriter, ContractClassWriterExt};
+use katana_provider::traits::contract::{
+    ContractClassProvider, ContractClassWriter, ContractClassWriterExt,
+};
 use katana_provider::traits::env::BlockEnvProvider;
 use katana_provider::traits::stage::StageCheckpointProvider;
-use katana_provider::traits::state::{StateFactoryProvider, StateWriter};
+use katana_provider::traits::state::{StateFactoryProvider, StateProvider, StateWriter};
 use katana_provider::traits::state_update::StateUpdateProvider;
 use katana_provider::traits::transaction::{
     ReceiptProvider, TransactionProvider, TransactionStatusProvider, TransactionTraceProvider,
@@ -166,17 +176,82 @@ impl Blockchain {
         Ok((Self::new(database), block_num))
     }
 
-    /// Creates a new [Blockchain] with the given [Database] implementation and genesis state.
+    /// Eagerly fetches the state of the `pinned` contracts and freezes it into a new [Genesis],
+    /// whose block header is taken from `genesis`.
+    ///
+    /// On a forked blockchain, this produces a small and reproducible genesis that is scoped to
+    /// only the contracts of interest. The class, nonce, `fee_token` balance and storage of the
+    /// contracts are included. As the storage of a contract can't be enumerated from the forked
+    /// network, only the storage slots that are known locally are part of the snapshot.
+    pub fn pinned_genesis(
+        &self,
+        genesis: &Genesis,
+        fee_token: ContractAddress,
+        pinned: &[ContractAddress],
+    ) -> Result<Genesis> {
+        let state = self.inner.latest()?;
+
+        let mut classes = BTreeMap::new();
+        let mut allocations = BTreeMap::new();
+
+        for address in pinned {
+            let class_hash = state
+                .class_hash_of_contract(*address)?
+                .with_context(|| format!("pinned contract {address} not found"))?;
+
+            if let Entry::Vacant(entry) = classes.entry(class_hash) {
+                let class = state
+                    .class(class_hash)?
+                    .with_context(|| format!("class {class_hash:#x} not found"))?;
+                entry.insert(Arc::new(class));
+            }
+
+            let nonce = state.nonce(*address)?;
+
+            let balance_key = get_fee_token_balance_base_storage_address(*address);
+            let low = state.storage(fee_token, balance_key)?.unwrap_or_default();
+            let high = state.storage(fee_token, balance_key + Felt::ONE)?.unwrap_or_default();
+
+            let storage = state.storage_entries(*address)?;
+            let storage = (!storage.is_empty()).then(|| storage.into_iter().collect());
+
+            let contract = GenesisContractAlloc {
+                nonce,
+                storage,
+                class_hash: Some(class_hash),
+                balance: Some(join_u256(low, high)),
+            };
+
+            allocations.insert(*address, GenesisAllocation::Contract(contract));
+        }
+
+        info!(contracts = %allocations.len(), "Pinned forked contracts.");
+
+        Ok(Genesis {
+            parent_hash: genesis.parent_hash,
+            state_root: genesis.state_root,
+            number: genesis.number,
+            timestamp: genesis.timestamp,
+            sequencer_address: genesis.sequencer_address,
+            gas_prices: genesis.gas_prices.clone(),
+            classes,
+            allocations,
+        })
+    }
+
+    /// Creates a new [Blockchain] with the given [Database] implementation and genesis state. The
+    /// genesis block is hashed with `hash_algorithm`, which must be the one used by the chain.
     pub fn new_dev(
         provider: impl Database,
         chain_spec: &katana_chain_spec::dev::ChainSpec,
+        hash_algorithm: BlockHashAlgorithm,
     ) -> Result<Self> {
         // check whether the genesis block has been initialized
         let genesis_hash = provider.block_hash_by_num(chain_spec.genesis.number)?;
 
         match genesis_hash {
             Some(db_hash) => {
-                let genesis_hash = chain_spec.block().header.compute_hash();
+                let genesis_hash = chain_spec.block_hash(hash_algorithm);
                 // check genesis should be the same
                 if db_hash == genesis_hash {
                     Ok(Self::new(provider))
@@ -189,12 +264,9 @@ impl Blockchain {
 
             // Initialize the dev genesis block
             None => {
-                let block = chain_spec.block().seal();
-                let block = SealedBlockWithStatus { block, status: FinalityStatus::AcceptedOnL1 };
+                let mut block = chain_spec.block();
                 let states = chain_spec.state_updates();
-
-                let mut block = block;
-                let block_number = block.block.header.number;
+                let block_number = block.header.number;
 
                 let class_trie_root = provider
                     .trie_insert_declared_classes(
@@ -213,7 +285,11 @@ impl Blockchain {
                     class_trie_root,
                 ]);
 
-                block.block.header.state_root = genesis_state_root;
+                // the state root must be set before hashing the block, for its hash to commit to
+                // the genesis state
+                block.header.state_root = genesis_state_root;
+                let hash = block.header.compute_hash_with(hash_algorithm);
+                let block = block.seal_with_hash_and_status(hash, FinalityStatus::AcceptedOnL1);
                 provider.insert_block_with_states_and_receipts(block, states, vec![], vec![])?;
 
                 Ok(Self::new(provider))
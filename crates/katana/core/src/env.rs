@@ -2,4 +2,8 @@
 pub struct BlockContextGenerator {
     pub block_timestamp_offset: i64,
     pub next_block_start_time: u64,
+    /// When set, the system clock is replaced by a clock that starts at this timestamp and moves
+    /// forward by one second for every block, so that producing the same blocks always yields the
+    /// same timestamps.
+    pub deterministic_clock: Option<u64>,
 }
@@ -19,11 +19,16 @@ use std::time::Duration;
 use futures::channel::mpsc::{channel, Receiver, Sender};
 use futures::stream::{Stream, StreamExt};
 use futures::FutureExt;
-use katana_executor::{BlockExecutor, ExecutionResult, ExecutionStats, ExecutorFactory};
+use katana_executor::{
+    BlockExecutor, ExecutionOutput, ExecutionResult, ExecutionStats, ExecutorFactory,
+};
 use katana_pool::validation::stateful::TxValidator;
-use katana_primitives::block::{BlockHashOrNumber, ExecutableBlock, PartialHeader};
+use katana_primitives::block::{
+    BlockHash, BlockHashOrNumber, BlockNumber, ExecutableBlock, PartialHeader,
+};
 use katana_primitives::da::L1DataAvailabilityMode;
 use katana_primitives::receipt::Receipt;
+use katana_primitives::state::StateUpdatesWithClasses;
 use katana_primitives::trace::TxExecInfo;
 use katana_primitives::transaction::{ExecutableTxWithHash, TxHash, TxWithHash};
 use katana_primitives::version::CURRENT_STARKNET_VERSION;
@@ -55,6 +60,37 @@ pub enum BlockProductionError {
 
     #[error("transaction execution error: {0}")]
     TransactionExecutionError(#[from] katana_executor::ExecutorError),
+
+    #[error(transparent)]
+    InvalidBlock(#[from] BlockError),
+
+    #[error("the pending block has transactions")]
+    PendingTransactions,
+
+    #[error("the block has too many {kind} ({count}) for its header")]
+    TooManyEntries { kind: &'static str, count: usize },
+}
+
+/// Errors when a block can't be appended to the chain because it doesn't extend the current
+/// chain tip.
+#[derive(Debug, thiserror::Error)]
+pub enum BlockError {
+    #[error("block {number} ({hash:#x}) is not sequential to the chain tip {tip}")]
+    NonSequential { hash: BlockHash, number: BlockNumber, tip: BlockNumber },
+
+    #[error(
+        "parent hash {parent_hash:#x} of block {number} ({hash:#x}) doesn't match the chain tip \
+         hash {tip_hash:#x}"
+    )]
+    ParentMismatch {
+        hash: BlockHash,
+        number: BlockNumber,
+        parent_hash: BlockHash,
+        tip_hash: BlockHash,
+    },
+
+    #[error("block {number} has the same hash {hash:#x} as the existing block {existing}")]
+    DuplicateHash { hash: BlockHash, number: BlockNumber, existing: BlockNumber },
 }
 
 impl BlockProductionError {
@@ -161,6 +197,23 @@ impl<EF: ExecutorFactory> BlockProducer<EF> {
         }
     }
 
+    /// Mines a block without any transactions that applies the given state updates on top of
+    /// the latest state.
+    ///
+    /// In _interval_ mode, this fails with [`BlockProductionError::PendingTransactions`] if the
+    /// pending block has, or is about to have, transactions. The check is done while holding the
+    /// producer lock, so no transaction can be added to the pending block in the meantime.
+    pub fn apply_state(
+        &self,
+        states: StateUpdatesWithClasses,
+    ) -> Result<MinedBlockOutcome, BlockProductionError> {
+        let mut mode = self.producer.write();
+        match &mut *mode {
+            BlockProducerMode::Instant(producer) => producer.apply_state(states),
+            BlockProducerMode::Interval(producer) => producer.apply_state(states),
+        }
+    }
+
     pub(super) fn poll_next(&self, cx: &mut Context<'_>) -> Poll<Option<BlockProductionResult>> {
         let mut mode = self.producer.write();
         match &mut *mode {
@@ -313,6 +366,33 @@ impl<EF: ExecutorFactory> IntervalBlockProducer<EF> {
         }
     }
 
+    fn apply_state(
+        &mut self,
+        states: StateUpdatesWithClasses,
+    ) -> Result<MinedBlockOutcome, BlockProductionError> {
+        let _permit = self.permit.lock();
+
+        let has_pending_txs = !self.executor.read().transactions().is_empty();
+        if has_pending_txs || self.ongoing_execution.is_some() || !self.queued.is_empty() {
+            return Err(BlockProductionError::PendingTransactions);
+        }
+
+        // the pending block is replaced by a block that only contains the state updates
+        let block_env = self.executor.read().block_env();
+        let output = ExecutionOutput { states, ..Default::default() };
+        let outcome = self.backend.do_mine_block(&block_env, output)?;
+
+        self.executor = self.create_new_executor_for_next_block()?;
+
+        let provider = self.backend.blockchain.provider();
+        let state = self.executor.0.read().state();
+        let num = provider.latest_number()?;
+        let block_env = provider.block_env_at(num.into())?.expect("latest block env");
+        self.validator.update(state, block_env);
+
+        Ok(outcome)
+    }
+
     fn do_mine(
         permit: Arc<Mutex<()>>,
         executor: PendingExecutor,
@@ -630,6 +710,28 @@ impl<EF: ExecutorFactory> InstantBlockProducer<EF> {
         }
     }
 
+    fn apply_state(
+        &mut self,
+        states: StateUpdatesWithClasses,
+    ) -> Result<MinedBlockOutcome, BlockProductionError> {
+        let _permit = self.permit.lock();
+
+        let provider = self.backend.blockchain.provider();
+        let latest_num = provider.latest_number()?;
+        let mut block_env = provider.block_env_at(latest_num.into())?.expect("latest block env");
+        self.backend.update_block_env(&mut block_env);
+
+        let output = ExecutionOutput { states, ..Default::default() };
+        let outcome = self.backend.do_mine_block(&block_env, output)?;
+
+        let state = provider.latest()?;
+        let latest_num = provider.latest_number()?;
+        let block_env = provider.block_env_at(latest_num.into())?.expect("latest block env");
+        self.validator.update(state, block_env);
+
+        Ok(outcome)
+    }
+
     fn do_mine(
         validator: TxValidator,
         permit: Arc<Mutex<()>>,
@@ -1,28 +1,55 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
 use alloy_primitives::U256;
+use assert_matches::assert_matches;
 use katana_chain_spec::rollup::{self, FeeContract};
 use katana_chain_spec::{dev, ChainSpec, SettlementLayer};
+use katana_core::backend::commitment::{EventCommitment, StarknetEventCommitment};
 use katana_core::backend::gas_oracle::GasOracle;
+use katana_core::backend::replay::ReplayError;
 use katana_core::backend::storage::{Blockchain, Database};
-use katana_core::backend::Backend;
+use katana_core::backend::{Backend, BlockFinalized};
+use katana_core::service::block_producer::{BlockError, BlockProductionError};
 use katana_executor::implementation::blockifier::BlockifierFactory;
-use katana_executor::BlockLimits;
+use katana_executor::{BlockLimits, ExecutionOutput, ExecutionResult};
+use katana_primitives::block::{BlockHashAlgorithm, Header};
 use katana_primitives::chain::ChainId;
-use katana_primitives::env::CfgEnv;
-use katana_primitives::felt;
-use katana_primitives::genesis::allocation::DevAllocationsGenerator;
-use katana_primitives::genesis::constant::DEFAULT_PREFUNDED_ACCOUNT_BALANCE;
+use katana_primitives::env::{BlockEnv, CfgEnv};
+use katana_primitives::fee::{PriceUnit, TxFeeInfo};
+use katana_primitives::genesis::allocation::{
+    DevAllocationsGenerator, GenesisAllocation, GenesisContractAlloc,
+};
+use katana_primitives::genesis::constant::{
+    DEFAULT_ACCOUNT_CLASS_HASH, DEFAULT_PREFUNDED_ACCOUNT_BALANCE,
+};
 use katana_primitives::genesis::Genesis;
+use katana_primitives::receipt::{Event, InvokeTxReceipt, Receipt};
+use katana_primitives::transaction::{InvokeTx, InvokeTxV1, Tx, TxHash, TxWithHash};
+use katana_primitives::{address, felt, ContractAddress, Felt};
 use katana_provider::providers::db::DbProvider;
+use katana_provider::traits::block::{BlockHashProvider, BlockNumberProvider, HeaderProvider};
+use katana_provider::traits::env::BlockEnvProvider;
+use katana_provider::traits::state::{StateFactoryProvider, StateProvider};
+use katana_provider::traits::transaction::{ReceiptProvider, TransactionProvider};
 use rstest::rstest;
 use url::Url;
 
 fn executor(chain_spec: &ChainSpec) -> BlockifierFactory {
+    executor_with_hash_algorithm(chain_spec, BlockHashAlgorithm::default())
+}
+
+fn executor_with_hash_algorithm(
+    chain_spec: &ChainSpec,
+    block_hash_algorithm: BlockHashAlgorithm,
+) -> BlockifierFactory {
     BlockifierFactory::new(
         CfgEnv {
             chain_id: chain_spec.id(),
             validate_max_n_steps: u32::MAX,
             invoke_tx_max_n_steps: u32::MAX,
             max_recursion_depth: usize::MAX,
+            block_hash_algorithm,
             ..Default::default()
         },
         Default::default(),
@@ -109,3 +136,343 @@ fn reinitialize_with_different_rollup_chain_spec() {
     let err = backend2.init_genesis().unwrap_err().to_string();
     assert!(err.as_str().contains("Genesis block hash mismatch"));
 }
+
+#[test]
+fn reinitialize_with_different_dev_allocations() {
+    let db = DbProvider::new_ephemeral();
+
+    let chain1 = ChainSpec::Dev(dev_chain_spec());
+    let backend1 = backend_with_db(&chain1, db.clone());
+    backend1.init_genesis().expect("failed to initialize genesis");
+
+    // Only the allocations differ, which must be enough to change the genesis block hash.
+    let chain2 = ChainSpec::Dev(chain_spec_with_contract(address!("0x1337"), felt!("0xabc")));
+
+    let backend2 = backend_with_db(&chain2, db);
+    let err = backend2.init_genesis().unwrap_err().to_string();
+    assert!(err.as_str().contains("Genesis block hash mismatch"));
+}
+
+#[test]
+fn reject_non_sequential_block() {
+    let chain = ChainSpec::Dev(dev_chain_spec());
+    let backend = backend(&chain);
+    backend.init_genesis().expect("failed to initialize genesis");
+
+    // the chain tip is the genesis block, so the next block must be block 1
+    let block_env = BlockEnv { number: 5, ..Default::default() };
+    let err = backend.mine_empty_block(&block_env).unwrap_err();
+
+    assert_matches!(
+        err,
+        BlockProductionError::InvalidBlock(BlockError::NonSequential { number: 5, tip: 0, .. })
+    );
+}
+
+#[rstest]
+#[case::poseidon(BlockHashAlgorithm::Poseidon)]
+#[case::pedersen(BlockHashAlgorithm::Pedersen)]
+fn genesis_with_hash_algorithm(#[case] algorithm: BlockHashAlgorithm) {
+    let db = DbProvider::new_ephemeral();
+    let chain = ChainSpec::Rollup(rollup_chain_spec());
+    let backend = Backend::new(
+        chain.clone().into(),
+        Blockchain::new(db.clone()),
+        GasOracle::sampled_starknet(),
+        executor_with_hash_algorithm(&chain, algorithm),
+    );
+    backend.init_genesis().expect("failed to initialize genesis");
+
+    let provider = backend.blockchain.provider();
+    let header = provider.header(0.into()).unwrap().expect("missing header");
+    assert_eq!(provider.latest_hash().unwrap(), header.compute_hash_with(algorithm));
+
+    // the genesis of an existing database is checked against the same algorithm
+    let backend = Backend::new(
+        chain.clone().into(),
+        Blockchain::new(db),
+        GasOracle::sampled_starknet(),
+        executor_with_hash_algorithm(&chain, algorithm),
+    );
+    backend.init_genesis().expect("genesis should match");
+
+    let chain = dev_chain_spec();
+    let backend = Backend::new(
+        ChainSpec::Dev(chain.clone()).into(),
+        Blockchain::new(DbProvider::new_ephemeral()),
+        GasOracle::sampled_starknet(),
+        executor_with_hash_algorithm(&ChainSpec::Dev(chain.clone()), algorithm),
+    );
+    backend.init_genesis().expect("failed to initialize genesis");
+
+    let provider = backend.blockchain.provider();
+    let header = provider.header(0.into()).unwrap().expect("missing header");
+    assert_eq!(provider.latest_hash().unwrap(), header.compute_hash_with(algorithm));
+    assert_eq!(provider.latest_hash().unwrap(), chain.block_hash(algorithm));
+}
+
+#[rstest]
+#[case::poseidon(BlockHashAlgorithm::Poseidon)]
+#[case::pedersen(BlockHashAlgorithm::Pedersen)]
+fn mine_block_with_hash_algorithm(#[case] algorithm: BlockHashAlgorithm) {
+    let chain = ChainSpec::Dev(dev_chain_spec());
+    let backend = Backend::new(
+        chain.clone().into(),
+        Blockchain::new(DbProvider::new_ephemeral()),
+        GasOracle::sampled_starknet(),
+        executor_with_hash_algorithm(&chain, algorithm),
+    );
+    backend.init_genesis().expect("failed to initialize genesis");
+
+    let block_env = BlockEnv { number: 1, ..Default::default() };
+    backend.mine_empty_block(&block_env).expect("failed to mine block");
+
+    let provider = backend.blockchain.provider();
+    let header = provider.header(1.into()).unwrap().expect("missing header");
+    assert_eq!(provider.latest_hash().unwrap(), header.compute_hash_with(algorithm));
+}
+
+/// An events commitment whose leaves are the addresses of the emitters, committed to by summing
+/// them.
+#[derive(Debug)]
+struct EmitterSumEventCommitment;
+
+impl EventCommitment for EmitterSumEventCommitment {
+    fn event_hash(&self, _: TxHash, event: &Event) -> Felt {
+        event.from_address.into()
+    }
+
+    fn commit(&self, leaves: &[Felt]) -> Felt {
+        leaves.iter().fold(Felt::ZERO, |acc, leaf| acc + *leaf)
+    }
+}
+
+/// Mines block 1 with a single transaction emitting `events`.
+fn mine_block_with_events(backend: &Backend<BlockifierFactory>, events: Vec<Event>) -> Header {
+    let tx = TxWithHash {
+        hash: felt!("0x1337"),
+        transaction: Tx::Invoke(InvokeTx::V1(InvokeTxV1::default())),
+    };
+
+    let receipt = Receipt::Invoke(InvokeTxReceipt {
+        fee: TxFeeInfo { gas_consumed: 0, gas_price: 0, overall_fee: 0, unit: PriceUnit::Wei },
+        events,
+        messages_sent: Vec::new(),
+        revert_error: None,
+        execution_resources: Default::default(),
+    });
+
+    let output = ExecutionOutput {
+        transactions: vec![(tx, ExecutionResult::new_success(receipt, Default::default()))],
+        ..Default::default()
+    };
+
+    let block_env = BlockEnv { number: 1, ..Default::default() };
+    backend.do_mine_block(&block_env, output).expect("failed to mine block");
+
+    let provider = backend.blockchain.provider();
+    provider.header(1.into()).unwrap().expect("missing header")
+}
+
+#[rstest]
+#[case::starknet(Arc::new(StarknetEventCommitment))]
+#[case::custom(Arc::new(EmitterSumEventCommitment))]
+fn mine_block_with_event_commitment(#[case] scheme: Arc<dyn EventCommitment>) {
+    let chain = ChainSpec::Dev(dev_chain_spec());
+    let backend = backend(&chain).with_event_commitment(scheme.clone());
+    backend.init_genesis().expect("failed to initialize genesis");
+
+    let events = vec![
+        Event { from_address: address!("0x1"), keys: vec![felt!("0x2")], data: vec![felt!("0x3")] },
+        Event { from_address: address!("0x4"), keys: vec![felt!("0x5")], data: Vec::new() },
+    ];
+
+    let leaves = events.iter().map(|e| scheme.event_hash(felt!("0x1337"), e)).collect::<Vec<_>>();
+    let header = mine_block_with_events(&backend, events);
+
+    assert_eq!(header.events_count, 2);
+    assert_eq!(header.events_commitment, scheme.commit(&leaves));
+    assert_ne!(header.events_commitment, Felt::ZERO);
+}
+
+#[test]
+fn genesis_with_event_commitment() {
+    let chain = ChainSpec::Rollup(rollup_chain_spec());
+    let scheme = Arc::new(EmitterSumEventCommitment);
+    let backend = backend(&chain).with_event_commitment(scheme.clone());
+    backend.init_genesis().expect("failed to initialize genesis");
+
+    let provider = backend.blockchain.provider();
+    let txs = provider.transactions_by_block(0.into()).unwrap().expect("missing transactions");
+    let receipts = provider.receipts_by_block(0.into()).unwrap().expect("missing receipts");
+
+    let leaves = txs
+        .iter()
+        .zip(&receipts)
+        .flat_map(|(tx, receipt)| receipt.events().iter().map(|e| scheme.event_hash(tx.hash, e)))
+        .collect::<Vec<_>>();
+
+    // the genesis block is committed to with the configured scheme, like any other block
+    let header = provider.header(0.into()).unwrap().expect("missing header");
+    assert!(!leaves.is_empty(), "the genesis block should emit events");
+    assert_eq!(header.events_commitment, scheme.commit(&leaves));
+}
+
+#[test]
+fn notify_finalized_blocks() {
+    let chain = ChainSpec::Dev(dev_chain_spec());
+    let backend = backend(&chain);
+    backend.init_genesis().expect("failed to initialize genesis");
+
+    let mut subscriber = backend.subscribe_finalized_blocks();
+
+    let block_env = BlockEnv { number: 1, ..Default::default() };
+    backend.mine_empty_block(&block_env).expect("failed to mine block");
+
+    // the block is already stored when the notification is received
+    let provider = backend.blockchain.provider();
+    let hash = provider.latest_hash().unwrap();
+    assert_eq!(subscriber.try_recv().unwrap(), BlockFinalized { number: 1, hash });
+    assert!(subscriber.try_recv().is_err());
+}
+
+#[test]
+fn store_queryable_block_hashes() {
+    let chain = ChainSpec::Dev(dev_chain_spec());
+    let backend = backend(&chain);
+    backend.init_genesis().expect("failed to initialize genesis");
+
+    for number in 1..=10 {
+        let block_env = BlockEnv { number, ..Default::default() };
+        backend.mine_empty_block(&block_env).expect("failed to mine block");
+    }
+
+    // the transactions of block 11 can query the hashes of the blocks up to block 1
+    let provider = backend.blockchain.provider();
+    let state = provider.latest().unwrap();
+    let registry = address!("0x1");
+
+    for number in 0..=1u64 {
+        let hash = provider.block_hash_by_num(number).unwrap();
+        assert_eq!(state.storage(registry, number.into()).unwrap(), hash);
+    }
+
+    assert_eq!(state.storage(registry, Felt::TWO).unwrap(), None);
+}
+
+/// Mines a few empty blocks with the deterministic clock enabled and returns the timestamp and
+/// hash of each of them.
+fn mine_deterministic_blocks(chain: &ChainSpec) -> Vec<(u64, Felt)> {
+    let backend = backend(chain);
+    backend.block_context_generator.write().deterministic_clock = Some(chain.genesis().timestamp);
+    backend.init_genesis().expect("failed to initialize genesis");
+
+    let provider = backend.blockchain.provider();
+    let mut blocks = Vec::new();
+
+    for _ in 0..3 {
+        let latest = provider.latest_number().unwrap();
+        let mut block_env = provider.block_env_at(latest.into()).unwrap().unwrap();
+        backend.update_block_env(&mut block_env);
+        backend.mine_empty_block(&block_env).expect("failed to mine block");
+
+        let header = provider.header(block_env.number.into()).unwrap().unwrap();
+        blocks.push((header.timestamp, provider.latest_hash().unwrap()));
+    }
+
+    blocks
+}
+
+#[test]
+fn deterministic_block_production() {
+    let chain = ChainSpec::Dev(dev_chain_spec());
+
+    let first = mine_deterministic_blocks(&chain);
+    // make sure the wall clock has moved on between the two runs
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    let second = mine_deterministic_blocks(&chain);
+
+    assert_eq!(first, second);
+
+    // the timestamps are derived from the genesis timestamp, not the wall clock
+    let genesis_timestamp = chain.genesis().timestamp;
+    for (number, (timestamp, _)) in (1..).zip(&first) {
+        assert_eq!(*timestamp, genesis_timestamp + number);
+    }
+}
+
+#[test]
+fn replay_chain() {
+    let chain = ChainSpec::Dev(dev_chain_spec());
+
+    let source = backend(&chain);
+    source.init_genesis().expect("failed to initialize genesis");
+
+    for number in 1..=3 {
+        let block_env = BlockEnv { number, timestamp: number * 10, ..Default::default() };
+        source.mine_empty_block(&block_env).expect("failed to mine block");
+    }
+
+    let backend = backend(&chain);
+    backend.init_genesis().expect("failed to initialize genesis");
+    backend.replay(source.blockchain.provider()).expect("failed to replay chain");
+
+    let provider = backend.blockchain.provider();
+    assert_eq!(provider.latest_number().unwrap(), 3);
+    assert_eq!(
+        provider.latest_hash().unwrap(),
+        source.blockchain.provider().latest_hash().unwrap()
+    );
+
+    // replaying on top of a different genesis should fail at the genesis block
+    let chain = ChainSpec::Dev({
+        let mut chain = dev_chain_spec();
+        chain.genesis.timestamp = 1337;
+        chain
+    });
+
+    let backend = backend_with_db(&chain, DbProvider::new_ephemeral());
+    backend.init_genesis().expect("failed to initialize genesis");
+    let err = backend.replay(source.blockchain.provider()).unwrap_err();
+    assert_matches!(err, ReplayError::Divergence { number: 0, .. });
+}
+
+/// Returns a dev chain spec with a contract whose storage slot `0x1` is set to `value`.
+fn chain_spec_with_contract(address: ContractAddress, value: Felt) -> dev::ChainSpec {
+    let contract = GenesisContractAlloc {
+        class_hash: Some(DEFAULT_ACCOUNT_CLASS_HASH),
+        balance: Some(U256::from(1337)),
+        nonce: Some(felt!("0x5")),
+        storage: Some(BTreeMap::from([(Felt::ONE, value)])),
+    };
+
+    let mut chain = dev_chain_spec();
+    chain.genesis.extend_allocations([(address, GenesisAllocation::Contract(contract))]);
+    chain
+}
+
+#[test]
+fn pinned_genesis_snapshot() {
+    let pinned = address!("0x1337");
+
+    let chain = chain_spec_with_contract(pinned, felt!("0xabc"));
+    let blockchain =
+        Blockchain::new_dev(DbProvider::new_ephemeral(), &chain, Default::default()).unwrap();
+    let fee_token = chain.fee_contracts.eth;
+    let genesis = blockchain.pinned_genesis(&chain.genesis, fee_token, &[pinned]).unwrap();
+
+    // only the pinned contract and its class are part of the snapshot, including its storage
+    assert_eq!(genesis.allocations.len(), 1);
+    assert_eq!(genesis.classes.keys().collect::<Vec<_>>(), vec![&DEFAULT_ACCOUNT_CLASS_HASH]);
+    assert_eq!(genesis.allocations.get(&pinned), chain.genesis.allocations.get(&pinned));
+
+    // the snapshots of chains that only differ in the storage of a pinned contract must differ
+    let other = chain_spec_with_contract(pinned, felt!("0xdef"));
+    let blockchain =
+        Blockchain::new_dev(DbProvider::new_ephemeral(), &other, Default::default()).unwrap();
+    let other_genesis = blockchain.pinned_genesis(&other.genesis, fee_token, &[pinned]).unwrap();
+    assert_ne!(genesis.allocations, other_genesis.allocations);
+
+    let err = blockchain.pinned_genesis(&other.genesis, fee_token, &[address!("0xdead")]);
+    assert!(err.unwrap_err().to_string().contains("not found"));
+}
@@ -1,6 +1,7 @@
 use katana_primitives::block::ExecutableBlock;
 use katana_primitives::env::{BlockEnv, CfgEnv};
 use katana_primitives::fee::TxFeeInfo;
+use katana_primitives::state::StateUpdates;
 use katana_primitives::transaction::{ExecutableTxWithHash, TxWithHash};
 use katana_primitives::Felt;
 use katana_provider::traits::state::StateProvider;
@@ -51,6 +52,9 @@ pub trait BlockExecutor<'a>: ExecutorExt + Send + Sync + core::fmt::Debug {
     /// Returns the current state of the executor.
     fn state(&self) -> Box<dyn StateProvider + 'a>;
 
+    /// Returns the state updates of the transactions executed so far, without taking them.
+    fn state_updates(&self) -> StateUpdates;
+
     /// Returns the transactions that have been executed.
     fn transactions(&self) -> &[(TxWithHash, ExecutionResult)];
 
@@ -0,0 +1,97 @@
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+use blockifier::execution::contract_class::ContractClass as BlockifierContractClass;
+use katana_primitives::class::ClassHash;
+use lru::LruCache;
+use parking_lot::Mutex;
+
+/// The default maximum number of compiled classes kept in a [`ClassCache`].
+pub const DEFAULT_CLASS_CACHE_SIZE: NonZeroUsize = NonZeroUsize::new(1024).unwrap();
+
+/// A cache of compiled classes, keyed by their class hash.
+///
+/// Compiling a Sierra class to CASM is expensive, so the compiled classes are kept around and
+/// shared by every executor. Only the least recently used classes are evicted once the cache is
+/// full. Because a class hash uniquely identifies a class, the cache is valid across states,
+/// including forked ones.
+#[derive(Debug, Clone)]
+pub struct ClassCache {
+    inner: Arc<Mutex<LruCache<ClassHash, BlockifierContractClass>>>,
+}
+
+impl ClassCache {
+    /// Creates a new cache that holds at most `capacity` classes.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self { inner: Arc::new(Mutex::new(LruCache::new(capacity))) }
+    }
+
+    /// Returns the compiled class of the given class hash, if it's in the cache.
+    pub fn get(&self, hash: &ClassHash) -> Option<BlockifierContractClass> {
+        self.inner.lock().get(hash).cloned()
+    }
+
+    /// Inserts a compiled class in the cache, evicting the least recently used class if the cache
+    /// is full.
+    pub fn insert(&self, hash: ClassHash, class: BlockifierContractClass) {
+        self.inner.lock().put(hash, class);
+    }
+
+    /// Changes the maximum number of classes held by the cache. If the new capacity is smaller
+    /// than the number of cached classes, the least recently used ones are evicted.
+    pub fn resize(&self, capacity: NonZeroUsize) {
+        self.inner.lock().resize(capacity);
+    }
+
+    /// Returns the number of classes currently in the cache.
+    pub fn len(&self) -> usize {
+        self.inner.lock().len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.lock().is_empty()
+    }
+}
+
+impl Default for ClassCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CLASS_CACHE_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use katana_primitives::genesis::constant::{
+        DEFAULT_ACCOUNT_CLASS, DEFAULT_ACCOUNT_CLASS_HASH, DEFAULT_LEGACY_ERC20_CLASS,
+        DEFAULT_LEGACY_ERC20_CLASS_HASH, DEFAULT_LEGACY_UDC_CLASS, DEFAULT_LEGACY_UDC_CLASS_HASH,
+    };
+
+    use super::ClassCache;
+    use crate::implementation::blockifier::utils::to_class;
+
+    #[test]
+    fn evicts_least_recently_used_class() {
+        let account = to_class(DEFAULT_ACCOUNT_CLASS.clone().compile().unwrap()).unwrap();
+        let erc20 = to_class(DEFAULT_LEGACY_ERC20_CLASS.clone().compile().unwrap()).unwrap();
+        let udc = to_class(DEFAULT_LEGACY_UDC_CLASS.clone().compile().unwrap()).unwrap();
+
+        let cache = ClassCache::new(NonZeroUsize::new(2).unwrap());
+        cache.insert(DEFAULT_ACCOUNT_CLASS_HASH, account);
+        cache.insert(DEFAULT_LEGACY_ERC20_CLASS_HASH, erc20);
+
+        // accessing the account class makes the erc20 class the least recently used one
+        assert!(cache.get(&DEFAULT_ACCOUNT_CLASS_HASH).is_some());
+        cache.insert(DEFAULT_LEGACY_UDC_CLASS_HASH, udc);
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&DEFAULT_LEGACY_ERC20_CLASS_HASH).is_none());
+        assert!(cache.get(&DEFAULT_ACCOUNT_CLASS_HASH).is_some());
+        assert!(cache.get(&DEFAULT_LEGACY_UDC_CLASS_HASH).is_some());
+
+        cache.resize(NonZeroUsize::new(1).unwrap());
+        assert_eq!(cache.len(), 1);
+    }
+}
@@ -11,7 +11,8 @@ use blockifier::transaction::objects::{DeprecatedTransactionInfo, TransactionInf
 use katana_cairo::cairo_vm::vm::runners::cairo_runner::{ExecutionResources, RunResources};
 use katana_cairo::starknet_api::core::EntryPointSelector;
 use katana_cairo::starknet_api::transaction::Calldata;
-use katana_primitives::Felt;
+use katana_primitives::{ContractAddress, Felt};
+use starknet::core::types::Call;
 
 use super::utils::to_blk_address;
 use crate::{EntryPointCall, ExecutionError};
@@ -28,14 +29,50 @@ pub fn execute_call<S: StateReader>(
     Ok(res.execution.retdata.0)
 }
 
+/// Executes the calls of a multicall one after the other on behalf of the `sender` account, and
+/// returns the index of the first call that fails, if any.
+///
+/// The calls are made directly from the account, so the changes made by the calls that precede
+/// the failing one are applied to the `state`.
+pub(super) fn failing_call_index<S: StateReader>(
+    sender: ContractAddress,
+    calls: &[Call],
+    state: &mut CachedState<S>,
+    block_context: &BlockContext,
+    max_gas: u64,
+) -> Option<usize> {
+    calls.iter().position(|call| {
+        let request = EntryPointCall {
+            contract_address: call.to.into(),
+            calldata: call.calldata.clone(),
+            entry_point_selector: call.selector,
+        };
+
+        let result = execute_call_as(sender, request, state, block_context, max_gas);
+        !matches!(result, Ok(info) if !info.execution.failed)
+    })
+}
+
 fn execute_call_inner<S: StateReader>(
     request: EntryPointCall,
     state: &mut CachedState<S>,
     block_context: &BlockContext,
     max_gas: u64,
+) -> EntryPointExecutionResult<CallInfo> {
+    execute_call_as(ContractAddress::default(), request, state, block_context, max_gas)
+}
+
+/// Executes the call as if it was made by the `caller` contract.
+fn execute_call_as<S: StateReader>(
+    caller: ContractAddress,
+    request: EntryPointCall,
+    state: &mut CachedState<S>,
+    block_context: &BlockContext,
+    max_gas: u64,
 ) -> EntryPointExecutionResult<CallInfo> {
     let call = CallEntryPoint {
         initial_gas: max_gas,
+        caller_address: to_blk_address(caller),
         calldata: Calldata(Arc::new(request.calldata)),
         storage_address: to_blk_address(request.contract_address),
         entry_point_selector: EntryPointSelector(request.entry_point_selector),
@@ -77,7 +114,8 @@ mod tests {
     use blockifier::context::BlockContext;
     use blockifier::state::cached_state::{self};
     use katana_primitives::class::ContractClass;
-    use katana_primitives::{address, felt, ContractAddress};
+    use katana_primitives::env::{BlockEnv, CfgEnv};
+    use katana_primitives::{address, felt, ContractAddress, Felt};
     use katana_provider::test_utils;
     use katana_provider::traits::contract::ContractClassWriter;
     use katana_provider::traits::state::{StateFactoryProvider, StateWriter};
@@ -85,6 +123,7 @@ mod tests {
 
     use super::execute_call_inner;
     use crate::implementation::blockifier::state::StateProviderDb;
+    use crate::implementation::blockifier::utils::block_context_from_envs;
     use crate::EntryPointCall;
 
     #[test]
@@ -162,4 +201,66 @@ mod tests {
         // Check that 'call' isn't bounded by the block context max invoke steps
         assert!(max_gas_3 > ctx.versioned_constants().invoke_tx_max_n_steps as u64);
     }
+
+    #[test]
+    fn max_recursion_depth() {
+        // -------------------- Preparations -------------------------------
+
+        let json = include_str!("../../../tests/fixtures/test_contract.json");
+        let class = ContractClass::from_str(json).unwrap();
+        let class_hash = class.class_hash().unwrap();
+        let casm_hash = class.clone().compile().unwrap().class_hash().unwrap();
+
+        // Initialize provider with the test contract
+        let provider = test_utils::test_provider();
+        // Declare test contract
+        provider.set_class(class_hash, class).unwrap();
+        provider.set_compiled_class_hash_of_class_hash(class_hash, casm_hash).unwrap();
+        // Deploy test contract
+        let address = address!("0x1337");
+        provider.set_class_hash_of_contract(address, class_hash).unwrap();
+
+        let state = provider.latest().unwrap();
+        let state = StateProviderDb::new(state, Default::default());
+
+        // ---------------------------------------------------------------
+
+        let mut state = cached_state::CachedState::new(state);
+
+        // the default depth of the node, which is the Starknet mainnet limit
+        let max_recursion_depth = 50;
+        let cfg = CfgEnv { max_recursion_depth, ..Default::default() };
+        let ctx = block_context_from_envs(&BlockEnv::default(), &cfg);
+        let max_gas = 1_000_000_000;
+
+        // Builds a call that nests `depth` calls in total, including itself: `test_call_contract`
+        // calls itself on the same contract until the innermost call, which is a no-op.
+        let nested_call = |depth: usize| {
+            let mut selector = selector!("segment_arena_builtin");
+            let mut calldata: Vec<Felt> = Vec::new();
+
+            for _ in 1..depth {
+                let mut outer = vec![address.into(), selector, calldata.len().into()];
+                outer.extend(calldata);
+                calldata = outer;
+                selector = selector!("test_call_contract");
+            }
+
+            EntryPointCall { calldata, contract_address: address, entry_point_selector: selector }
+        };
+
+        let result =
+            execute_call_inner(nested_call(max_recursion_depth), &mut state, &ctx, max_gas);
+        assert!(result.is_ok(), "should succeed at the maximum depth: {result:?}");
+
+        // going one call deeper must fail gracefully with a depth error instead of overflowing the
+        // stack
+        let result =
+            execute_call_inner(nested_call(max_recursion_depth + 1), &mut state, &ctx, max_gas);
+        let error = format!("{:?}", result.expect_err("should fail due to the recursion depth"));
+        assert!(
+            error.contains("RecursionDepthExceeded") || error.contains("Recursion depth exceeded"),
+            "unexpected error: {error}"
+        );
+    }
 }
@@ -2,31 +2,31 @@
 pub use blockifier;
 use blockifier::bouncer::{Bouncer, BouncerConfig, BouncerWeights};
 
+pub mod cache;
 pub mod call;
 mod error;
 pub mod state;
 pub mod utils;
 
-use std::collections::HashMap;
 use std::num::NonZeroU128;
-use std::sync::{Arc, LazyLock};
+use std::sync::LazyLock;
 
 use blockifier::blockifier::block::{BlockInfo, GasPrices};
 use blockifier::context::BlockContext;
-use blockifier::execution::contract_class::ContractClass as BlockifierContractClass;
 use blockifier::state::cached_state::{self, MutRefState};
 use blockifier::state::state_api::StateReader;
 use katana_cairo::starknet_api::block::{BlockNumber, BlockTimestamp};
+use katana_cairo::starknet_api::core::ClassHash;
 use katana_primitives::block::{ExecutableBlock, GasPrices as KatanaGasPrices, PartialHeader};
-use katana_primitives::class::ClassHash;
 use katana_primitives::env::{BlockEnv, CfgEnv};
 use katana_primitives::fee::TxFeeInfo;
+use katana_primitives::state::StateUpdates;
 use katana_primitives::transaction::{ExecutableTx, ExecutableTxWithHash, TxWithHash};
 use katana_primitives::Felt;
 use katana_provider::traits::state::StateProvider;
-use parking_lot::Mutex;
 use tracing::info;
 
+use self::cache::ClassCache;
 use self::state::CachedState;
 use crate::{
     BlockExecutor, BlockLimits, EntryPointCall, ExecutionError, ExecutionFlags, ExecutionOutput,
@@ -34,8 +34,8 @@ use crate::{
     ResultAndStates,
 };
 
-pub static COMPILED_CLASS_CACHE: LazyLock<Arc<Mutex<HashMap<ClassHash, BlockifierContractClass>>>> =
-    LazyLock::new(|| Arc::new(Mutex::new(HashMap::default())));
+/// The compiled class cache shared by all the executors.
+pub static COMPILED_CLASS_CACHE: LazyLock<ClassCache> = LazyLock::new(ClassCache::default);
 
 pub(crate) const LOG_TARGET: &str = "katana::executor::blockifier";
 
@@ -247,6 +247,19 @@ impl<'a> BlockExecutor<'a> for StarknetVMProcessor<'a> {
                             }
 
                             if let Some((class_hash, class)) = class_decl_artifacts {
+                                // Cache the class compiled by the declare transaction so that it
+                                // doesn't need to be compiled again when it's first invoked.
+                                let blk_class_hash = ClassHash(class_hash);
+                                if let Ok(compiled) =
+                                    state.cached_state.get_compiled_contract_class(blk_class_hash)
+                                {
+                                    state
+                                        .cached_state
+                                        .state
+                                        .compiled_class_cache
+                                        .insert(class_hash, compiled);
+                                }
+
                                 state.declared_classes.insert(class_hash, class.as_ref().clone());
                             }
 
@@ -281,6 +294,10 @@ impl<'a> BlockExecutor<'a> for StarknetVMProcessor<'a> {
         Box::new(self.state.clone())
     }
 
+    fn state_updates(&self) -> StateUpdates {
+        utils::state_update_from_cached_state(&self.state).state_updates
+    }
+
     fn transactions(&self) -> &[(TxWithHash, ExecutionResult)] {
         &self.transactions
     }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -9,6 +9,7 @@ use blockifier::state::state_api::{StateReader, StateResult};
 use katana_cairo::starknet_api::core::{ClassHash, CompiledClassHash, Nonce};
 use katana_cairo::starknet_api::state::StorageKey;
 use katana_primitives::class::{self, ContractClass};
+use katana_primitives::contract::ContractAddress;
 use katana_primitives::Felt;
 use katana_provider::error::ProviderError;
 use katana_provider::traits::contract::{ContractClassProvider, ContractClassProviderExt};
@@ -17,6 +18,7 @@ use katana_provider::ProviderResult;
 use parking_lot::Mutex;
 use tracing::trace;
 
+use super::cache::ClassCache;
 use super::utils::{self};
 
 #[derive(Debug, Clone)]
@@ -31,10 +33,7 @@ pub(crate) struct CachedStateInner<'a> {
 }
 
 impl<'a> CachedState<'a> {
-    pub(super) fn new(
-        state: impl StateProvider + 'a,
-        compiled_class_cache: Arc<Mutex<HashMap<class::ClassHash, BlockifierContractClass>>>,
-    ) -> Self {
+    pub(super) fn new(state: impl StateProvider + 'a, compiled_class_cache: ClassCache) -> Self {
         let state = StateProviderDb::new(Box::new(state), compiled_class_cache);
         let cached_state = cached_state::CachedState::new(state);
 
@@ -118,6 +117,32 @@ impl<'a> StateProvider for CachedState<'a> {
             Err(e) => Err(ProviderError::Other(e.to_string())),
         }
     }
+
+    fn storage_entries(
+        &self,
+        address: ContractAddress,
+    ) -> ProviderResult<Vec<(Felt, Felt)>> {
+        let mut state = self.inner.lock();
+
+        // the entries of the underlying state, overwritten by the ones written in this state
+        let mut entries = state
+            .cached_state
+            .state
+            .storage_entries(address)?
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+
+        let diff =
+            state.cached_state.to_state_diff().map_err(|e| ProviderError::Other(e.to_string()))?;
+        let address = utils::to_blk_address(address);
+        for ((contract, key), value) in diff.storage {
+            if contract == address {
+                entries.insert(*key.0.key(), value);
+            }
+        }
+
+        Ok(entries.into_iter().filter(|(_, value)| *value != Felt::ZERO).collect())
+    }
 }
 
 impl<'a> StateProofProvider for CachedState<'a> {}
@@ -126,7 +151,7 @@ impl<'a> StateRootProvider for CachedState<'a> {}
 #[derive(Debug)]
 pub struct StateProviderDb<'a> {
     provider: Box<dyn StateProvider + 'a>,
-    compiled_class_cache: Arc<Mutex<HashMap<class::ClassHash, BlockifierContractClass>>>,
+    pub(super) compiled_class_cache: ClassCache,
 }
 
 impl<'a> Deref for StateProviderDb<'a> {
@@ -138,10 +163,7 @@ impl<'a> Deref for StateProviderDb<'a> {
 }
 
 impl<'a> StateProviderDb<'a> {
-    pub fn new(
-        provider: Box<dyn StateProvider + 'a>,
-        compiled_class_cache: Arc<Mutex<HashMap<class::ClassHash, BlockifierContractClass>>>,
-    ) -> Self {
+    pub fn new(provider: Box<dyn StateProvider + 'a>, compiled_class_cache: ClassCache) -> Self {
         Self { provider, compiled_class_cache }
     }
 }
@@ -176,9 +198,7 @@ impl<'a> StateReader for StateProviderDb<'a> {
         &self,
         class_hash: ClassHash,
     ) -> StateResult<BlockifierContractClass> {
-        let mut class_cache = self.compiled_class_cache.lock();
-
-        if let Some(class) = class_cache.get(&class_hash.0) {
+        if let Some(class) = self.compiled_class_cache.get(&class_hash.0) {
             trace!(target: "executor", class = format!("{}", class_hash.to_hex_string()), "Class cache hit");
             return Ok(class.clone());
         }
@@ -193,7 +213,7 @@ impl<'a> StateReader for StateProviderDb<'a> {
             let class =
                 utils::to_class(class).map_err(|e| StateError::StateReadError(e.to_string()))?;
 
-            class_cache.insert(class_hash.0, class.clone());
+            self.compiled_class_cache.insert(class_hash.0, class.clone());
             return Ok(class);
         }
 
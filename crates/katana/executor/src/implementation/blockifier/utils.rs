@@ -45,13 +45,19 @@ use katana_primitives::fee::{PriceUnit, TxFeeInfo};
 use katana_primitives::state::{StateUpdates, StateUpdatesWithClasses};
 use katana_primitives::trace::{L1Gas, TxExecInfo, TxResources};
 use katana_primitives::transaction::{
-    DeclareTx, DeployAccountTx, ExecutableTx, ExecutableTxWithHash, InvokeTx, TxType,
+    DeclareTx, DeployAccountTx, ExecutableTx, ExecutableTxWithHash, InvokeTx, TxHash, TxType,
+};
+use katana_primitives::utils::transaction::{
+    decode_execute_calldata, decode_legacy_execute_calldata,
 };
 use katana_primitives::{class, event, message, trace};
 use katana_provider::traits::contract::ContractClassProvider;
 use starknet::core::utils::parse_cairo_short_string;
+use tracing::Level;
 
+use super::call::failing_call_index;
 use super::state::CachedState;
+use super::LOG_TARGET;
 use crate::abstraction::ExecutionFlags;
 use crate::utils::build_receipt;
 use crate::{ExecutionError, ExecutionResult, ExecutorResult};
@@ -137,10 +143,25 @@ pub fn transact<S: StateReader>(
                 )?;
             }
 
+            if tracing::enabled!(target: LOG_TARGET, Level::TRACE) {
+                trace_state_diff(tx.hash, &mut tx_state);
+            }
+
             tx_state.commit();
 
             // get the trace and receipt from the execution info
-            let trace = to_exec_info(info, tx.r#type());
+            let mut trace = to_exec_info(info, tx.r#type());
+
+            if let (ExecutableTx::Invoke(invoke), Some(error)) =
+                (&tx.transaction, trace.revert_error.as_mut())
+            {
+                annotate_multicall_revert(state, block_context, invoke, error);
+            }
+
+            if let Some(error) = trace.revert_error.as_mut() {
+                annotate_step_limit_revert(error);
+            }
+
             let receipt = build_receipt(tx.tx_ref(), fee, &trace);
             Ok(ExecutionResult::new_success(receipt, trace))
         }
@@ -152,6 +173,50 @@ pub fn transact<S: StateReader>(
     }
 }
 
+/// Logs the state changes made by a transaction, along with the values they replace. Must be
+/// called before the changes are committed to the parent state.
+fn trace_state_diff<S: StateReader>(tx_hash: TxHash, state: &mut cached_state::CachedState<S>) {
+    let Ok(diff) = state.to_state_diff() else { return };
+    let tx_hash = format!("{tx_hash:#x}");
+
+    for ((address, key), new) in diff.storage {
+        let old = state.state.get_storage_at(address, key).unwrap_or_default();
+        tracing::trace!(
+            target: LOG_TARGET,
+            %tx_hash,
+            contract = %to_address(address),
+            key = format!("{:#x}", key.0.key()),
+            old = format!("{old:#x}"),
+            new = format!("{new:#x}"),
+            "Storage updated."
+        );
+    }
+
+    for (address, nonce) in diff.nonces {
+        let old = state.state.get_nonce_at(address).unwrap_or_default();
+        tracing::trace!(
+            target: LOG_TARGET,
+            %tx_hash,
+            contract = %to_address(address),
+            old = format!("{:#x}", old.0),
+            new = format!("{:#x}", nonce.0),
+            "Nonce updated."
+        );
+    }
+
+    for (address, class_hash) in diff.class_hashes {
+        let old = state.state.get_class_hash_at(address).unwrap_or_default();
+        tracing::trace!(
+            target: LOG_TARGET,
+            %tx_hash,
+            contract = %to_address(address),
+            old = format!("{:#x}", old.0),
+            new = format!("{:#x}", class_hash.0),
+            "Class hash updated."
+        );
+    }
+}
+
 pub fn to_executor_tx(tx: ExecutableTxWithHash) -> Transaction {
     let hash = tx.hash;
 
@@ -405,6 +470,8 @@ pub fn block_context_from_envs(block_env: &BlockEnv, cfg_env: &CfgEnv) -> BlockC
     versioned_constants.max_recursion_depth = cfg_env.max_recursion_depth;
     versioned_constants.validate_max_n_steps = cfg_env.validate_max_n_steps;
     versioned_constants.invoke_tx_max_n_steps = cfg_env.invoke_tx_max_n_steps;
+    versioned_constants.tx_event_limits.max_data_length = cfg_env.tx_limits.max_event_data_length;
+    versioned_constants.tx_event_limits.max_n_emitted_events = cfg_env.tx_limits.max_emitted_events;
 
     BlockContext::new(block_info, chain_info, versioned_constants, BouncerConfig::max())
 }
@@ -457,15 +524,23 @@ pub(super) fn state_update_from_cached_state(state: &CachedState<'_>) -> StateUp
         },
     );
 
-    let deployed_contracts =
-        state_diff
-            .class_hashes
-            .into_iter()
-            .map(|(key, value)| (to_address(key), value.0))
-            .collect::<BTreeMap<
-                katana_primitives::contract::ContractAddress,
-                katana_primitives::class::ClassHash,
-            >>();
+    let mut deployed_contracts = BTreeMap::new();
+    let mut replaced_classes = BTreeMap::new();
+
+    for (address, class_hash) in state_diff.class_hashes {
+        let address = to_address(address);
+
+        // A contract that already exists in the state the executions were performed on had its
+        // class replaced using the `replace_class` syscall.
+        let inner = state.inner.lock();
+        let existing = inner.cached_state.state.class_hash_of_contract(address).unwrap();
+
+        if existing.is_some() {
+            replaced_classes.insert(address, class_hash.0);
+        } else {
+            deployed_contracts.insert(address, class_hash.0);
+        }
+    }
 
     StateUpdatesWithClasses {
         classes: declared_contract_classes,
@@ -475,7 +550,7 @@ pub(super) fn state_update_from_cached_state(state: &CachedState<'_>) -> StateUp
             declared_classes,
             deployed_contracts,
             deprecated_declared_classes,
-            replaced_classes: BTreeMap::default(),
+            replaced_classes,
         },
     }
 }
@@ -587,17 +662,76 @@ pub fn to_exec_info(exec_info: TransactionExecutionInfo, r#type: TxType) -> TxEx
             ),
             n_reverted_steps: exec_info.transaction_receipt.resources.n_reverted_steps,
             data_availability: L1Gas {
-                l1_gas: exec_info.transaction_receipt.da_gas.l1_data_gas,
+                l1_gas: exec_info.transaction_receipt.da_gas.l1_gas,
                 l1_data_gas: exec_info.transaction_receipt.da_gas.l1_data_gas,
             },
             total_gas_consumed: L1Gas {
-                l1_gas: exec_info.transaction_receipt.gas.l1_data_gas,
+                l1_gas: exec_info.transaction_receipt.gas.l1_gas,
                 l1_data_gas: exec_info.transaction_receipt.gas.l1_data_gas,
             },
         },
     }
 }
 
+/// Appends the index of the call that caused the revert to the revert error of a multicall
+/// invoke transaction.
+///
+/// The reverted execution doesn't include the call info of the `__execute__` entrypoint, so the
+/// failing call is identified by replaying the calls of the multicall one by one on behalf of the
+/// account, on top of the `state` the transaction was committed to. The replay is discarded once
+/// the failing call is found. Nothing is appended if none of the calls fail when replayed.
+fn annotate_multicall_revert<S: StateReader>(
+    state: &mut cached_state::CachedState<S>,
+    block_context: &BlockContext,
+    tx: &InvokeTx,
+    revert_error: &mut String,
+) {
+    let (sender, calldata) = match tx {
+        InvokeTx::V0(..) => return,
+        InvokeTx::V1(tx) => (tx.sender_address, &tx.calldata),
+        InvokeTx::V3(tx) => (tx.sender_address, &tx.calldata),
+    };
+
+    // Cairo 0 accounts use a different serialization for the `__execute__` calldata
+    let is_legacy = state
+        .get_class_hash_at(to_blk_address(sender))
+        .and_then(|hash| state.get_compiled_contract_class(hash))
+        .is_ok_and(|class| matches!(class, ContractClass::V0(..)));
+
+    let calls = if is_legacy {
+        decode_legacy_execute_calldata(calldata)
+    } else {
+        decode_execute_calldata(calldata)
+    };
+
+    let Some(calls) = calls else { return };
+    // a single call doesn't need to be disambiguated
+    if calls.len() < 2 {
+        return;
+    }
+
+    // the replay state is dropped without being committed
+    let mut replay_state = TransactionalState::create_transactional(state);
+    let max_gas = block_context.versioned_constants().invoke_tx_max_n_steps as u64;
+    let index = failing_call_index(sender, &calls, &mut replay_state, block_context, max_gas);
+
+    if let Some(index) = index {
+        revert_error.push_str(&format!("\nFailed at call index {index} of the multicall."));
+    }
+}
+
+/// The error raised by the Cairo VM when a transaction runs out of its step budget, ie
+/// [`CfgEnv::invoke_tx_max_n_steps`].
+const OUT_OF_STEPS_ERROR: &str = "RunResources has no remaining steps";
+
+/// Appends an explicit message to the revert error of a transaction that was reverted because it
+/// exceeded the step limit, eg due to an unbounded loop.
+fn annotate_step_limit_revert(revert_error: &mut String) {
+    if revert_error.contains(OUT_OF_STEPS_ERROR) {
+        revert_error.push_str("\nExecution exceeded step limit.");
+    }
+}
+
 fn to_call_info(call: CallInfo) -> trace::CallInfo {
     let contract_address = to_address(call.call.storage_address);
     let caller_address = to_address(call.call.caller_address);
@@ -692,6 +826,19 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn step_limit_revert() {
+        let mut error = "Error in the called contract (0x123):\nCould not reach the end of the \
+                         program. RunResources has no remaining steps."
+            .to_string();
+        annotate_step_limit_revert(&mut error);
+        assert!(error.ends_with("\nExecution exceeded step limit."));
+
+        let mut error = "Error in the called contract (0x123):\nInsufficient balance".to_string();
+        annotate_step_limit_revert(&mut error);
+        assert_eq!(error, "Error in the called contract (0x123):\nInsufficient balance");
+    }
+
     #[test]
     fn convert_chain_id() {
         let katana_mainnet = katana_primitives::chain::ChainId::MAINNET;
@@ -850,4 +997,22 @@ mod tests {
         assert_eq!(call.gas_consumed, expected_gas_consumed);
         assert_eq!(call.failed, expected_failed);
     }
+
+    #[test]
+    fn exec_info_gas_consumed() {
+        let mut info = TransactionExecutionInfo::default();
+        info.transaction_receipt.fee = Fee(1337);
+        // distinct values so that mixing up the two kinds of gas doesn't go unnoticed
+        info.transaction_receipt.gas.l1_gas = 7;
+        info.transaction_receipt.gas.l1_data_gas = 128;
+        info.transaction_receipt.da_gas.l1_gas = 3;
+        info.transaction_receipt.da_gas.l1_data_gas = 64;
+
+        let info = to_exec_info(info, TxType::Invoke);
+        let resources = info.actual_resources;
+
+        assert_eq!(info.actual_fee, 1337);
+        assert_eq!(resources.total_gas_consumed, L1Gas { l1_gas: 7, l1_data_gas: 128 });
+        assert_eq!(resources.data_availability, L1Gas { l1_gas: 3, l1_data_gas: 64 });
+    }
 }
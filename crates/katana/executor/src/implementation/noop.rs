@@ -3,6 +3,7 @@ use katana_primitives::class::{ClassHash, CompiledClassHash, ContractClass};
 use katana_primitives::contract::{ContractAddress, Nonce, StorageKey, StorageValue};
 use katana_primitives::env::{BlockEnv, CfgEnv};
 use katana_primitives::fee::TxFeeInfo;
+use katana_primitives::state::StateUpdates;
 use katana_primitives::transaction::{ExecutableTxWithHash, TxWithHash};
 use katana_primitives::Felt;
 use katana_provider::traits::contract::ContractClassProvider;
@@ -113,6 +114,10 @@ impl<'a> BlockExecutor<'a> for NoopExecutor {
         Box::new(NoopStateProvider)
     }
 
+    fn state_updates(&self) -> StateUpdates {
+        StateUpdates::default()
+    }
+
     fn transactions(&self) -> &[(TxWithHash, ExecutionResult)] {
         &[]
     }
@@ -163,6 +168,14 @@ impl StateProvider for NoopStateProvider {
         let _ = storage_key;
         Ok(None)
     }
+
+    fn storage_entries(
+        &self,
+        address: ContractAddress,
+    ) -> ProviderResult<Vec<(StorageKey, StorageValue)>> {
+        let _ = address;
+        Ok(Vec::new())
+    }
 }
 
 impl StateProofProvider for NoopStateProvider {}
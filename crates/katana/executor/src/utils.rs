@@ -137,10 +137,13 @@ fn get_l2_to_l1_messages_recur(info: &CallInfo) -> Vec<MessageToL1> {
 
 #[cfg(test)]
 mod tests {
+    use alloy_primitives::B256;
     use katana_primitives::event::OrderedEvent;
+    use katana_primitives::fee::{PriceUnit, TxFeeInfo};
     use katana_primitives::message::OrderedL2ToL1Message;
-    use katana_primitives::receipt::{Event, MessageToL1};
+    use katana_primitives::receipt::{Event, MessageToL1, Receipt};
     use katana_primitives::trace::CallInfo;
+    use katana_primitives::transaction::{L1HandlerTx, TxRef};
     use starknet::macros::felt;
 
     fn call_info() -> CallInfo {
@@ -238,4 +241,21 @@ mod tests {
 
         similar_asserts::assert_eq!(events, expected_messages)
     }
+
+    #[test]
+    fn l1_handler_receipt_references_message() {
+        let tx = L1HandlerTx {
+            message_hash: B256::repeat_byte(0xab),
+            // the l1 sender address followed by the message payload
+            calldata: vec![felt!("0xbeef"), felt!("0x1"), felt!("0x2")],
+            ..Default::default()
+        };
+
+        let fee = TxFeeInfo { gas_consumed: 0, gas_price: 0, overall_fee: 0, unit: PriceUnit::Wei };
+        let receipt = super::build_receipt(TxRef::L1Handler(&tx), fee, &Default::default());
+
+        let Receipt::L1Handler(receipt) = receipt else { panic!("expected an l1 handler receipt") };
+        assert_eq!(receipt.message_hash, tx.message_hash);
+        assert_eq!(tx.message_payload(), &[felt!("0x1"), felt!("0x2")]);
+    }
 }
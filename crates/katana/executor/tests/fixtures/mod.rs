@@ -242,6 +242,7 @@ pub fn cfg() -> CfgEnv {
         validate_max_n_steps: u32::MAX,
         invoke_tx_max_n_steps: u32::MAX,
         chain_id: ChainId::parse("KATANA").unwrap(),
+        ..Default::default()
     }
 }
 
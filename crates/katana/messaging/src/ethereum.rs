@@ -3,7 +3,7 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
-use alloy_network::Ethereum;
+use alloy_network::{Ethereum, ReceiptResponse};
 use alloy_primitives::{Address, U256};
 use alloy_provider::{Provider, ReqwestProvider};
 use alloy_rpc_types_eth::{BlockNumberOrTag, Filter, FilterBlockOption, FilterSet, Log, Topic};
@@ -18,9 +18,9 @@ use katana_primitives::utils::transaction::{
 };
 use katana_primitives::Felt;
 use starknet::core::types::EthAddress;
-use tracing::{debug, trace};
+use tracing::{debug, error, trace};
 
-use super::{MessagingConfig, Messenger, MessengerResult, LOG_TARGET};
+use super::{Error, MessagingConfig, Messenger, MessengerResult, LOG_TARGET};
 
 sol! {
     #[sol(rpc, rename_all = "snakecase")]
@@ -45,6 +45,8 @@ sol! {
 pub struct EthereumMessaging {
     provider: Arc<ReqwestProvider<Ethereum>>,
     messaging_contract_address: Address,
+    /// The unlocked account used to send the messages, if any.
+    sender_address: Option<Address>,
 }
 
 impl EthereumMessaging {
@@ -54,9 +56,52 @@ impl EthereumMessaging {
                 &config.rpc_url,
             )?)),
             messaging_contract_address: config.contract_address.parse::<Address>()?,
+            sender_address: config.sender_address.map(|a| a.parse::<Address>()).transpose()?,
         })
     }
 
+    /// Returns `true` if an account is configured to send the messages.
+    pub fn can_send_messages(&self) -> bool {
+        self.sender_address.is_some()
+    }
+
+    /// Registers the hashes of the given messages on the messaging contract, so that they can be
+    /// consumed on Ethereum. Returns the hashes of the sent messages.
+    pub async fn send_messages(&self, messages: &[MessageToL1]) -> MessengerResult<Vec<U256>> {
+        let Some(sender) = self.sender_address else { return Ok(vec![]) };
+        if messages.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let hashes = parse_messages(messages);
+        trace!(target: LOG_TARGET, hashes = ?hashes, "Sending hashes to Ethereum.");
+
+        let contract = StarknetMessagingLocal::new(self.messaging_contract_address, &self.provider);
+        let receipt = contract
+            .addMessageHashesFromL2(hashes.clone())
+            .from(sender)
+            .send()
+            .await
+            .map_err(|error| {
+                error!(target: LOG_TARGET, %error, "Sending messages transaction.");
+                Error::SendError
+            })?
+            .get_receipt()
+            .await
+            .map_err(|error| {
+                error!(target: LOG_TARGET, %error, "Waiting for messages transaction receipt.");
+                Error::SendError
+            })?;
+
+        if receipt.status() {
+            Ok(hashes)
+        } else {
+            let tx = receipt.transaction_hash;
+            error!(target: LOG_TARGET, %tx, "Messages transaction reverted.");
+            Err(Error::SendError)
+        }
+    }
+
     /// Fetches logs in given block range and returns a `HashMap` with the list of logs mapped to
     /// their block number.
     ///
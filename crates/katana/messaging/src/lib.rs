@@ -20,6 +20,10 @@
 //! which contains the messages. This proof is then sent to the settlement chain where it is
 //! verified, and the messages are consumed.
 //!
+//! When testing against a local Ethereum node, the proving step can be skipped by configuring a
+//! `sender_address`: the hashes of the messages of every new block are then directly registered
+//! on the `StarknetMessagingLocal` contract, where they can be consumed right away.
+//!
 //! Katana also has starknet messaging built-in, where an opiniated implementation of L2 <-> L3
 //! messaging is implemented using Starknet as settlement chain.
 //! When working with `L2 <> L3` with settlement on Starknet, there is one limitation:
@@ -53,7 +57,7 @@ use katana_primitives::chain::ChainId;
 use serde::{Deserialize, Serialize};
 use tracing::{error, info, trace};
 
-pub use self::service::{MessagingOutcome, MessagingService};
+pub use self::service::{MessagingOutcome, MessagingProvider, MessagingService};
 use self::starknet::StarknetMessaging;
 
 pub(crate) const LOG_TARGET: &str = "messaging";
@@ -71,6 +75,8 @@ pub enum Error {
     UnsupportedChain,
     #[error("Failed to gather messages from settlement chain")]
     GatherError,
+    #[error("Failed to send messages to settlement chain")]
+    SendError,
     #[error(transparent)]
     Provider(ProviderError),
 }
@@ -103,6 +109,13 @@ pub struct MessagingConfig {
     pub interval: u64,
     /// The block on settlement chain from where Katana will start fetching messages.
     pub from_block: u64,
+    /// The account on the settlement chain used to register the messages sent by the blocks of
+    /// Katana on the messaging contract. Messages are only sent if set, and only to Ethereum.
+    ///
+    /// The transactions are signed by the settlement node, so the account must be unlocked there,
+    /// as are the default accounts of Anvil.
+    #[serde(default)]
+    pub sender_address: Option<String>,
 }
 
 impl MessagingConfig {
@@ -127,6 +140,7 @@ impl MessagingConfig {
                 contract_address: core_contract.to_string(),
                 from_block: *block,
                 interval: 2,
+                sender_address: None,
             },
             katana_chain_spec::SettlementLayer::Starknet {
                 rpc_url, core_contract, block, ..
@@ -136,6 +150,7 @@ impl MessagingConfig {
                 contract_address: core_contract.to_string(),
                 from_block: *block,
                 interval: 2,
+                sender_address: None,
             },
             katana_chain_spec::SettlementLayer::Sovereign { .. } => Self {
                 chain: CONFIG_CHAIN_SOVEREIGN.to_string(),
@@ -225,30 +240,39 @@ impl MessengerMode {
 
 #[allow(missing_debug_implementations)]
 #[must_use = "MessagingTask does nothing unless polled"]
-pub struct MessagingTask {
-    messaging: MessagingService,
+pub struct MessagingTask<P: MessagingProvider> {
+    messaging: MessagingService<P>,
 }
 
-impl MessagingTask {
-    pub fn new(messaging: MessagingService) -> Self {
+impl<P: MessagingProvider> MessagingTask<P> {
+    pub fn new(messaging: MessagingService<P>) -> Self {
         Self { messaging }
     }
 }
 
-impl Future for MessagingTask {
+impl<P: MessagingProvider> Future for MessagingTask<P> {
     type Output = ();
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.get_mut();
 
         while let Poll::Ready(Some(outcome)) = this.messaging.poll_next_unpin(cx) {
-            let MessagingOutcome { msg_count, .. } = outcome;
-            {
-                if msg_count > 0 {
-                    info!(target: LOG_TARGET, %msg_count, "Collected messages from settlement chain.");
+            match outcome {
+                MessagingOutcome::Gather { msg_count, .. } => {
+                    let msg = "Collected messages from settlement chain.";
+                    if msg_count > 0 {
+                        info!(target: LOG_TARGET, %msg_count, "{msg}");
+                    }
+                    trace!(target: LOG_TARGET, %msg_count, "{msg}");
                 }
 
-                trace!(target: LOG_TARGET, %msg_count, "Collected messages from settlement chain.");
+                MessagingOutcome::Send { block_num, msg_count } => {
+                    let msg = "Sent messages to settlement chain.";
+                    if msg_count > 0 {
+                        info!(target: LOG_TARGET, %block_num, %msg_count, "{msg}");
+                    }
+                    trace!(target: LOG_TARGET, %block_num, %msg_count, "{msg}");
+                }
             }
         }
 
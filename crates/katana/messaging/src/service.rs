@@ -6,39 +6,65 @@ use std::time::Duration;
 use futures::{Future, FutureExt, Stream};
 use katana_chain_spec::ChainSpec;
 use katana_pool::{TransactionPool, TxPool};
+use katana_primitives::block::BlockNumber;
 use katana_primitives::chain::ChainId;
+use katana_primitives::receipt::MessageToL1;
 use katana_primitives::transaction::{ExecutableTxWithHash, L1HandlerTx, TxHash};
+use katana_provider::traits::block::BlockNumberProvider;
+use katana_provider::traits::transaction::ReceiptProvider;
 use tokio::time::{interval_at, Instant, Interval};
 use tracing::{error, info, warn};
 
-use super::{MessagingConfig, Messenger, MessengerMode, MessengerResult, LOG_TARGET};
+use super::{Error, MessagingConfig, Messenger, MessengerMode, MessengerResult, LOG_TARGET};
 
 type MessagingFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
 type MessageGatheringFuture = MessagingFuture<MessengerResult<(u64, usize)>>;
+type MessageSendingFuture = MessagingFuture<MessengerResult<Option<(u64, usize)>>>;
+
+/// The provider used by the [`MessagingService`] to read the messages sent by the blocks of the
+/// chain.
+pub trait MessagingProvider:
+    BlockNumberProvider + ReceiptProvider + Clone + Unpin + Send + Sync + 'static
+{
+}
+
+impl<T> MessagingProvider for T where
+    T: BlockNumberProvider + ReceiptProvider + Clone + Unpin + Send + Sync + 'static
+{
+}
 
 #[allow(missing_debug_implementations)]
-pub struct MessagingService {
+pub struct MessagingService<P: MessagingProvider> {
     /// The interval at which the service will perform the messaging operations.
     interval: Interval,
     chain_spec: Arc<ChainSpec>,
     pool: TxPool,
+    /// The provider of the local chain, to read the messages to send.
+    provider: P,
     /// The messenger mode the service is running in.
     messenger: Arc<MessengerMode>,
     /// The block number of the settlement chain from which messages will be gathered.
     gather_from_block: u64,
     /// The message gathering future.
     msg_gather_fut: Option<MessageGatheringFuture>,
+    /// The block number of the local chain from which messages will be sent.
+    send_from_block: BlockNumber,
+    /// The message sending future.
+    msg_send_fut: Option<MessageSendingFuture>,
 }
 
-impl MessagingService {
+impl<P: MessagingProvider> MessagingService<P> {
     /// Initializes a new instance from a configuration file's path.
     /// Will panic on failure to avoid continuing with invalid configuration.
     pub async fn new(
         config: MessagingConfig,
         chain_spec: Arc<ChainSpec>,
         pool: TxPool,
+        provider: P,
     ) -> anyhow::Result<Self> {
         let gather_from_block = config.from_block;
+        // only the messages of the blocks produced from now on are sent
+        let send_from_block = provider.latest_number()? + 1;
         let interval = interval_from_seconds(config.interval);
         let messenger = match MessengerMode::from_config(config).await {
             Ok(m) => Arc::new(m),
@@ -50,7 +76,17 @@ impl MessagingService {
             }
         };
 
-        Ok(Self { pool, interval, messenger, chain_spec, gather_from_block, msg_gather_fut: None })
+        Ok(Self {
+            pool,
+            interval,
+            provider,
+            messenger,
+            chain_spec,
+            gather_from_block,
+            msg_gather_fut: None,
+            send_from_block,
+            msg_send_fut: None,
+        })
     }
 
     async fn gather_messages(
@@ -101,29 +137,104 @@ impl MessagingService {
             MessengerMode::Sovereign(_) => Ok((0, 0)),
         }
     }
+
+    /// Sends the messages of the blocks from `from_block` up to the latest block to the
+    /// settlement chain. Returns `None` if there is no new block, or if sending messages isn't
+    /// supported by the messenger.
+    async fn send_messages(
+        messenger: Arc<MessengerMode>,
+        provider: P,
+        from_block: BlockNumber,
+    ) -> MessengerResult<Option<(BlockNumber, usize)>> {
+        let MessengerMode::Ethereum(inner) = messenger.as_ref() else { return Ok(None) };
+        if !inner.can_send_messages() {
+            return Ok(None);
+        }
+
+        let storage_err = |error: katana_provider::error::ProviderError| {
+            error!(target: LOG_TARGET, %error, "Reading messages to send.");
+            Error::SendError
+        };
+
+        let latest_block = provider.latest_number().map_err(storage_err)?;
+        if from_block > latest_block {
+            return Ok(None);
+        }
+
+        // the messages of all the blocks are sent at once, so that they're either all sent or
+        // all sent again on the next attempt
+        let mut messages: Vec<MessageToL1> = Vec::new();
+        for block in from_block..=latest_block {
+            let receipts = provider.receipts_by_block(block.into()).map_err(storage_err)?;
+            for receipt in receipts.unwrap_or_default() {
+                messages.extend(receipt.messages_sent().iter().cloned());
+            }
+        }
+
+        let hashes = inner.send_messages(&messages).await?;
+        Ok(Some((latest_block, hashes.len())))
+    }
 }
 
 #[derive(Debug)]
-pub struct MessagingOutcome {
-    /// The latest block number of the settlement chain from which messages were gathered.
-    pub lastest_block: u64,
-    /// The number of settlement chain messages gathered up until `latest_block`.
-    pub msg_count: usize,
+pub enum MessagingOutcome {
+    Gather {
+        /// The latest block number of the settlement chain from which messages were gathered.
+        lastest_block: u64,
+        /// The number of settlement chain messages gathered up until `latest_block`.
+        msg_count: usize,
+    },
+    Send {
+        /// The latest block number of the local chain whose messages were sent.
+        block_num: BlockNumber,
+        /// The number of messages sent up until `block_num`.
+        msg_count: usize,
+    },
 }
 
-impl Stream for MessagingService {
+impl<P: MessagingProvider> Stream for MessagingService<P> {
     type Item = MessagingOutcome;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let pin = self.get_mut();
 
-        if pin.interval.poll_tick(cx).is_ready() && pin.msg_gather_fut.is_none() {
-            pin.msg_gather_fut = Some(Box::pin(Self::gather_messages(
-                pin.messenger.clone(),
-                pin.pool.clone(),
-                pin.chain_spec.id(),
-                pin.gather_from_block,
-            )));
+        if pin.interval.poll_tick(cx).is_ready() {
+            if pin.msg_gather_fut.is_none() {
+                pin.msg_gather_fut = Some(Box::pin(Self::gather_messages(
+                    pin.messenger.clone(),
+                    pin.pool.clone(),
+                    pin.chain_spec.id(),
+                    pin.gather_from_block,
+                )));
+            }
+
+            if pin.msg_send_fut.is_none() {
+                pin.msg_send_fut = Some(Box::pin(Self::send_messages(
+                    pin.messenger.clone(),
+                    pin.provider.clone(),
+                    pin.send_from_block,
+                )));
+            }
+        }
+
+        // Poll the sending future.
+        if let Some(mut send_fut) = pin.msg_send_fut.take() {
+            match send_fut.poll_unpin(cx) {
+                Poll::Ready(Ok(Some((block_num, msg_count)))) => {
+                    pin.send_from_block = block_num + 1;
+                    return Poll::Ready(Some(MessagingOutcome::Send { block_num, msg_count }));
+                }
+                Poll::Ready(Ok(None)) => {}
+                Poll::Ready(Err(e)) => {
+                    error!(
+                        target: LOG_TARGET,
+                        block = %pin.send_from_block,
+                        error = %e,
+                        "Sending messages of block."
+                    );
+                }
+                Poll::Pending => pin.msg_send_fut = Some(send_fut),
+            }
         }
 
         // Poll the gathering future.
@@ -131,7 +242,7 @@ impl Stream for MessagingService {
             match gather_fut.poll_unpin(cx) {
                 Poll::Ready(Ok((last_block, msg_count))) => {
                     pin.gather_from_block = last_block + 1;
-                    return Poll::Ready(Some(MessagingOutcome {
+                    return Poll::Ready(Some(MessagingOutcome::Gather {
                         lastest_block: last_block,
                         msg_count,
                     }));
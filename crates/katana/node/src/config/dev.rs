@@ -31,6 +31,33 @@ pub struct DevConfig {
     ///
     /// These are the prices that will be used for calculating the gas fee for transactions.
     pub fixed_gas_prices: Option<FixedL1GasPriceConfig>,
+
+    /// Whether to derive the block timestamps from the genesis timestamp instead of the system
+    /// clock.
+    ///
+    /// Every block is one second after its parent, unless the timestamp is explicitly changed
+    /// through the dev API. Together with a fixed seed for the genesis accounts, executing the
+    /// same transactions always produces the same blocks.
+    pub deterministic: bool,
+
+    /// The multiplier applied to the estimated fee of transactions sent without a max fee, in
+    /// basis points, eg `15_000` for 1.5.
+    ///
+    /// If set, a V1 transaction sent with a zero max fee gets its max fee set to its estimated fee
+    /// times this multiplier, instead of being executed without paying any fee. The transaction
+    /// hash is still computed from the transaction as it was sent, so that its signature remains
+    /// valid, which makes it inconsistent with the max fee the transaction is executed with.
+    pub auto_max_fee: Option<u64>,
+
+    /// Whether the L1 gas prices react to how full the blocks are.
+    ///
+    /// If enabled, the gas prices start at the fixed gas prices (or the default ones) and are
+    /// raised after every block that uses more than half of the block's Cairo steps limit, and
+    /// lowered otherwise. They never go below their starting values.
+    ///
+    /// This takes precedence over the gas prices sampled from the settlement layer of a rollup
+    /// chain.
+    pub dynamic_gas_prices: bool,
 }
 
 /// Fixed gas prices for development.
@@ -54,6 +81,13 @@ impl std::default::Default for FixedL1GasPriceConfig {
 
 impl std::default::Default for DevConfig {
     fn default() -> Self {
-        Self { fee: true, account_validation: true, fixed_gas_prices: None }
+        Self {
+            fee: true,
+            account_validation: true,
+            fixed_gas_prices: None,
+            deterministic: false,
+            auto_max_fee: None,
+            dynamic_gas_prices: false,
+        }
     }
 }
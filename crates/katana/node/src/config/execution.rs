@@ -1,4 +1,13 @@
-pub const MAX_RECURSION_DEPTH: usize = 1000;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+use katana_core::backend::commitment::{EventCommitment, StarknetEventCommitment};
+pub use katana_executor::implementation::blockifier::cache::DEFAULT_CLASS_CACHE_SIZE;
+use katana_primitives::block::BlockHashAlgorithm;
+use katana_primitives::env::TxLimits;
+
+/// The maximum depth of nested calls on Starknet mainnet.
+pub const DEFAULT_MAX_RECURSION_DEPTH: usize = 50;
 
 pub const DEFAULT_INVOCATION_MAX_STEPS: u32 = 10_000_000;
 pub const DEFAULT_VALIDATION_MAX_STEPS: u32 = 1_000_000;
@@ -7,15 +16,28 @@ pub const DEFAULT_VALIDATION_MAX_STEPS: u32 = 1_000_000;
 pub struct ExecutionConfig {
     pub invocation_max_steps: u32,
     pub validation_max_steps: u32,
+    /// The maximum depth of nested calls. Transactions going deeper are reverted.
     pub max_recursion_depth: usize,
+    /// The maximum number of compiled classes kept in memory.
+    pub class_cache_size: NonZeroUsize,
+    /// The algorithm used to compute the hash of the produced blocks.
+    pub block_hash_algorithm: BlockHashAlgorithm,
+    /// The scheme used to compute the events commitment of the produced blocks.
+    pub event_commitment: Arc<dyn EventCommitment>,
+    /// Limits on the size of the transactions and of the events they emit.
+    pub tx_limits: TxLimits,
 }
 
 impl std::default::Default for ExecutionConfig {
     fn default() -> Self {
         Self {
-            max_recursion_depth: MAX_RECURSION_DEPTH,
+            max_recursion_depth: DEFAULT_MAX_RECURSION_DEPTH,
             invocation_max_steps: DEFAULT_INVOCATION_MAX_STEPS,
             validation_max_steps: DEFAULT_VALIDATION_MAX_STEPS,
+            class_cache_size: DEFAULT_CLASS_CACHE_SIZE,
+            block_hash_algorithm: BlockHashAlgorithm::default(),
+            event_commitment: Arc::new(StarknetEventCommitment),
+            tx_limits: TxLimits::default(),
         }
     }
 }
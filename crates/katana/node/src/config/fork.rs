@@ -1,4 +1,7 @@
+use std::path::PathBuf;
+
 use katana_primitives::block::BlockHashOrNumber;
+use katana_primitives::contract::ContractAddress;
 use starknet::providers::Url;
 
 /// Node forking configurations.
@@ -8,4 +11,10 @@ pub struct ForkingConfig {
     pub url: Url,
     /// The block number to fork from. If `None`, the latest block will be used.
     pub block: Option<BlockHashOrNumber>,
+    /// The contracts whose state is eagerly fetched from the forked network at startup. Every
+    /// other contract is fetched on demand.
+    pub pinned: Vec<ContractAddress>,
+    /// The path to write the genesis snapshot of the pinned contracts to, in the genesis JSON
+    /// format.
+    pub snapshot: Option<PathBuf>,
 }
@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
 
 use katana_rpc::cors::HeaderValue;
 use serde::{Deserialize, Serialize};
@@ -50,6 +51,16 @@ pub struct RpcConfig {
     pub max_proof_keys: Option<u64>,
     pub max_event_page_size: Option<u64>,
     pub max_call_gas: Option<u64>,
+    /// Maximum number of requests per second allowed on a single connection. The rate limit is
+    /// disabled if `None`.
+    pub max_requests_per_second: Option<u32>,
+    /// Maximum number of requests that a connection can burst before being rate limited. Defaults
+    /// to `max_requests_per_second` if `None`.
+    pub request_burst: Option<u32>,
+    /// Maximum duration of a request. Defaults to [`DEFAULT_RPC_TIMEOUT`] if `None`.
+    ///
+    /// [`DEFAULT_RPC_TIMEOUT`]: katana_rpc::DEFAULT_RPC_TIMEOUT
+    pub timeout: Option<Duration>,
 }
 
 impl RpcConfig {
@@ -72,6 +83,9 @@ impl Default for RpcConfig {
             max_event_page_size: Some(DEFAULT_RPC_MAX_EVENT_PAGE_SIZE),
             max_proof_keys: Some(DEFAULT_RPC_MAX_PROOF_KEYS),
             max_call_gas: Some(DEFAULT_RPC_MAX_CALL_GAS),
+            max_requests_per_second: None,
+            request_burst: None,
+            timeout: None,
         }
     }
 }
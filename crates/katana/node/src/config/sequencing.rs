@@ -1,4 +1,7 @@
 use katana_executor::BlockLimits;
+pub use katana_pool::pool::{
+    NonceValidationMode, PoolConfig, PoolFullPolicy, DEFAULT_MAX_POOL_SIZE,
+};
 
 /// Configurations related to block production.
 #[derive(Debug, Clone, Default)]
@@ -20,6 +23,12 @@ pub struct SequencingConfig {
     ///
     /// See <https://docs.starknet.io/chain-info/#current_limits>.
     pub block_cairo_steps_limit: Option<u64>,
+
+    /// The transaction pool configurations.
+    ///
+    /// In [`NonceValidationMode::Queued`] mode, transactions with a nonce ahead of the sender's
+    /// nonce wait in the pool until the transactions of the preceding nonces are received.
+    pub pool: PoolConfig,
 }
 
 impl SequencingConfig {
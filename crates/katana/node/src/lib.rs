@@ -30,16 +30,18 @@ use katana_core::constants::{
 use katana_core::env::BlockContextGenerator;
 use katana_core::service::block_producer::BlockProducer;
 use katana_db::mdbx::DbEnv;
-use katana_executor::implementation::blockifier::BlockifierFactory;
+use katana_executor::implementation::blockifier::{BlockifierFactory, COMPILED_CLASS_CACHE};
 use katana_executor::ExecutionFlags;
 use katana_pool::ordering::FiFo;
 use katana_pool::TxPool;
 use katana_primitives::block::GasPrices;
 use katana_primitives::env::{CfgEnv, FeeTokenAddressses};
+use katana_primitives::genesis::json::GenesisJson;
 #[cfg(feature = "cartridge")]
 use katana_rpc::cartridge::CartridgeApi;
 use katana_rpc::cors::Cors;
 use katana_rpc::dev::DevApi;
+use katana_rpc::rate_limit::RateLimit;
 use katana_rpc::saya::SayaApi;
 use katana_rpc::starknet::forking::ForkedClient;
 #[cfg(feature = "cartridge")]
@@ -55,7 +57,7 @@ use katana_rpc_api::starknet::{StarknetApiServer, StarknetTraceApiServer, Starkn
 use katana_rpc_api::torii::ToriiApiServer;
 use katana_stage::Sequencing;
 use katana_tasks::TaskManager;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::exit::NodeStoppedFuture;
 
@@ -72,8 +74,10 @@ impl LaunchedNode {
     ///
     /// This will instruct the node to stop and wait until it has actually stop.
     pub async fn stop(&self) -> Result<()> {
-        // TODO: wait for the rpc server to stop instead of just stopping it.
+        // Stop accepting new requests and let the in-flight ones complete before shutting down
+        // the rest of the node, so that they are not served by a half-stopped node.
         self.rpc.stop()?;
+        self.rpc.clone().stopped().await;
         self.node.task_manager.shutdown().await;
         Ok(())
     }
@@ -184,6 +188,8 @@ pub async fn build(mut config: Config) -> Result<Node> {
         invoke_tx_max_n_steps: config.execution.invocation_max_steps,
         validate_max_n_steps: config.execution.validation_max_steps,
         max_recursion_depth: config.execution.max_recursion_depth,
+        block_hash_algorithm: config.execution.block_hash_algorithm,
+        tx_limits: config.execution.tx_limits,
     };
 
     let execution_flags = ExecutionFlags::new()
@@ -198,6 +204,8 @@ pub async fn build(mut config: Config) -> Result<Node> {
             factory.set_max_call_gas(max_call_gas);
         }
 
+        COMPILED_CLASS_CACHE.resize(config.execution.class_cache_size);
+
         Arc::new(factory)
     };
 
@@ -213,6 +221,18 @@ pub async fn build(mut config: Config) -> Result<Node> {
         let (bc, block_num) =
             Blockchain::new_from_forked(cfg.url.clone(), cfg.block, chain_spec).await?;
 
+        if !cfg.pinned.is_empty() {
+            let fee_token = chain_spec.fee_contracts.eth;
+            let genesis = bc.pinned_genesis(&chain_spec.genesis, fee_token, &cfg.pinned)?;
+
+            if let Some(path) = &cfg.snapshot {
+                let json = GenesisJson::try_from(genesis)?;
+                std::fs::write(path, serde_json::to_string_pretty(&json)?)
+                    .with_context(|| format!("failed to write genesis snapshot to {path:?}"))?;
+                info!(path = %path.display(), "Genesis snapshot written.");
+            }
+        }
+
         // TODO: it'd bee nice if the client can be shared on both the rpc and forked backend side
         let forked_client = ForkedClient::new_http(cfg.url.clone(), block_num);
 
@@ -228,7 +248,18 @@ pub async fn build(mut config: Config) -> Result<Node> {
     // --- build l1 gas oracle
 
     // Check if the user specify a fixed gas price in the dev config.
-    let gas_oracle = if let Some(fixed_prices) = &config.dev.fixed_gas_prices {
+    let gas_oracle = if config.dev.dynamic_gas_prices {
+        if config.chain.settlement().is_some() {
+            warn!(
+                target: "node",
+                "Dynamic gas prices override the gas prices sampled from the settlement layer."
+            );
+        }
+
+        let prices = config.dev.fixed_gas_prices.clone().unwrap_or_default();
+        let steps_limit = config.sequencing.block_limits().cairo_steps;
+        GasOracle::dynamic(prices.gas_price, prices.data_gas_price, steps_limit)
+    } else if let Some(fixed_prices) = &config.dev.fixed_gas_prices {
         // Use fixed gas prices if provided in the configuration
         GasOracle::fixed(fixed_prices.gas_price.clone(), fixed_prices.data_gas_price.clone())
     } else if let Some(settlement) = config.chain.settlement() {
@@ -253,13 +284,20 @@ pub async fn build(mut config: Config) -> Result<Node> {
         )
     };
 
-    let block_context_generator = BlockContextGenerator::default().into();
+    let mut block_context_generator = BlockContextGenerator::default();
+    if config.dev.deterministic {
+        block_context_generator.deterministic_clock = Some(config.chain.genesis().timestamp);
+    }
+
+    let block_context_generator = block_context_generator.into();
     let backend = Arc::new(Backend {
         gas_oracle,
         blockchain,
         executor_factory,
         block_context_generator,
         chain_spec: config.chain.clone(),
+        event_commitment: config.execution.event_commitment.clone(),
+        finalized_blocks: Default::default(),
     });
 
     backend.init_genesis().context("failed to initialize genesis")?;
@@ -279,7 +317,7 @@ pub async fn build(mut config: Config) -> Result<Node> {
     // --- build transaction pool
 
     let validator = block_producer.validator();
-    let pool = TxPool::new(validator.clone(), FiFo::new());
+    let pool = TxPool::with_config(validator.clone(), FiFo::new(), config.sequencing.pool);
 
     // --- build rpc server
 
@@ -315,6 +353,8 @@ pub async fn build(mut config: Config) -> Result<Node> {
         let cfg = StarknetApiConfig {
             max_event_page_size: config.rpc.max_event_page_size,
             max_proof_keys: config.rpc.max_proof_keys,
+            auto_max_fee: config.dev.auto_max_fee,
+            class_cache_size: config.execution.class_cache_size,
             #[cfg(feature = "cartridge")]
             paymaster,
         };
@@ -337,7 +377,7 @@ pub async fn build(mut config: Config) -> Result<Node> {
     }
 
     if config.rpc.apis.contains(&RpcModuleKind::Dev) {
-        let api = DevApi::new(backend.clone(), block_producer.clone());
+        let api = DevApi::new(backend.clone(), pool.clone(), block_producer.clone());
         rpc_modules.merge(DevApiServer::into_rpc(api))?;
     }
 
@@ -351,7 +391,16 @@ pub async fn build(mut config: Config) -> Result<Node> {
         rpc_modules.merge(api.into_rpc())?;
     }
 
-    let rpc_server = RpcServer::new().metrics().health_check().cors(cors).module(rpc_modules);
+    let mut rpc_server = RpcServer::new().metrics().health_check().cors(cors).module(rpc_modules);
+
+    if let Some(rps) = config.rpc.max_requests_per_second {
+        let burst = config.rpc.request_burst.unwrap_or(rps);
+        rpc_server = rpc_server.rate_limit(RateLimit::new(rps).burst(burst));
+    }
+
+    if let Some(timeout) = config.rpc.timeout {
+        rpc_server = rpc_server.timeout(timeout);
+    }
 
     Ok(Node {
         db,
@@ -10,6 +10,7 @@ pub mod validation;
 use std::sync::Arc;
 
 use futures::channel::mpsc::Receiver;
+use katana_primitives::contract::{ContractAddress, Nonce};
 use katana_primitives::transaction::{ExecutableTxWithHash, TxHash};
 use ordering::{FiFo, PoolOrd};
 use pending::PendingTransactions;
@@ -28,6 +29,14 @@ pub type PoolResult<T> = Result<T, PoolError>;
 pub enum PoolError {
     #[error("Invalid transaction: {0}")]
     InvalidTransaction(Box<InvalidTransactionError>),
+    #[error("Transaction pool is full, it can't hold more than {max_size} transactions")]
+    PoolFull { max_size: usize },
+    #[error("Transaction {hash:#x} is already in the pool")]
+    DuplicateTransaction { hash: TxHash },
+    #[error("A transaction with nonce {nonce:#x} from {address} is already queued")]
+    DuplicateNonce { address: ContractAddress, nonce: Nonce },
+    #[error("Too many transactions are waiting for the transactions of their preceding nonces")]
+    QueueFull,
     #[error("Internal error: {0}")]
     Internal(Box<dyn std::error::Error>),
 }
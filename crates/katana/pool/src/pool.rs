@@ -1,10 +1,12 @@
 use core::fmt;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::sync::Arc;
 
 use futures::channel::mpsc::{channel, Receiver, Sender};
+use katana_primitives::contract::{ContractAddress, Nonce};
 use katana_primitives::transaction::TxHash;
 use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 use tracing::{error, info, warn};
 
@@ -16,6 +18,97 @@ use crate::validation::error::InvalidTransactionError;
 use crate::validation::{ValidationOutcome, Validator};
 use crate::{PoolError, PoolResult, TransactionPool};
 
+/// Determines how the pool handles transactions whose nonce is ahead of the sender's current
+/// nonce.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    strum_macros::EnumString,
+    strum_macros::Display,
+    Serialize,
+    Deserialize,
+)]
+#[strum(ascii_case_insensitive, serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum NonceValidationMode {
+    /// Reject the transaction right away.
+    #[default]
+    Strict,
+    /// Keep the transaction aside until the transactions of the preceding nonces are added to the
+    /// pool, then add it to the pool.
+    Queued,
+}
+
+/// Determines what happens when a transaction is added to a pool that is already full.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    strum_macros::EnumString,
+    strum_macros::Display,
+    Serialize,
+    Deserialize,
+)]
+#[strum(ascii_case_insensitive, serialize_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
+pub enum PoolFullPolicy {
+    /// Reject the incoming transaction.
+    #[default]
+    Reject,
+    /// Once the incoming transaction is validated, evict the pending transaction with the lowest
+    /// max fee, then tip, as long as it pays less than the incoming transaction. Otherwise, reject
+    /// the incoming transaction.
+    ///
+    /// Only the pending transaction with the highest nonce of a sender can be evicted, so that
+    /// no gap is left in the nonces of its pending transactions.
+    EvictLowestFee,
+}
+
+/// The default maximum number of transactions held by a [Pool].
+pub const DEFAULT_MAX_POOL_SIZE: usize = 10_000;
+
+/// The default maximum number of queued transactions held by a [Pool].
+pub const DEFAULT_MAX_QUEUED: usize = 1_000;
+
+/// The default maximum number of queued transactions of a single sender held by a [Pool].
+pub const DEFAULT_MAX_QUEUED_PER_SENDER: usize = 64;
+
+/// Configurations of a [Pool].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolConfig {
+    /// How transactions with a nonce ahead of their sender's nonce are handled.
+    pub nonce_validation: NonceValidationMode,
+    /// The maximum number of transactions held by the pool, including the queued ones.
+    pub max_size: usize,
+    /// What happens when a transaction is added once the pool holds `max_size` transactions.
+    pub full_policy: PoolFullPolicy,
+    /// The maximum number of queued transactions, only used in [`NonceValidationMode::Queued`]
+    /// mode.
+    pub max_queued: usize,
+    /// The maximum number of queued transactions of a single sender, only used in
+    /// [`NonceValidationMode::Queued`] mode.
+    pub max_queued_per_sender: usize,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            nonce_validation: NonceValidationMode::default(),
+            max_size: DEFAULT_MAX_POOL_SIZE,
+            full_policy: PoolFullPolicy::default(),
+            max_queued: DEFAULT_MAX_QUEUED,
+            max_queued_per_sender: DEFAULT_MAX_QUEUED_PER_SENDER,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Pool<T, V, O>
 where
@@ -42,6 +135,13 @@ struct Inner<T, V, O: PoolOrd> {
 
     /// the ordering mechanism used to order the txs in the pool
     ordering: O,
+
+    /// the pool configurations
+    config: PoolConfig,
+
+    /// txs waiting for the txs of the preceding nonces, only used in
+    /// [`NonceValidationMode::Queued`] mode.
+    queued: RwLock<HashMap<ContractAddress, BTreeMap<Nonce, T>>>,
 }
 
 impl<T, V, O> Pool<T, V, O>
@@ -52,10 +152,17 @@ where
 {
     /// Creates a new [Pool] with the given [Validator] and [PoolOrd] mechanism.
     pub fn new(validator: V, ordering: O) -> Self {
+        Self::with_config(validator, ordering, PoolConfig::default())
+    }
+
+    /// Creates a new [Pool] with the given [Validator], [PoolOrd] mechanism and [PoolConfig].
+    pub fn with_config(validator: V, ordering: O, config: PoolConfig) -> Self {
         Self {
             inner: Arc::new(Inner {
+                config,
                 ordering,
                 validator,
+                queued: Default::default(),
                 transactions: Default::default(),
                 subscribers: Default::default(),
                 listeners: Default::default(),
@@ -63,6 +170,11 @@ where
         }
     }
 
+    /// Returns the number of transactions waiting for the transactions of their preceding nonces.
+    pub fn queued_size(&self) -> usize {
+        self.inner.queued.read().values().map(BTreeMap::len).sum()
+    }
+
     /// Notifies all listeners about the new incoming transaction.
     fn notify_listener(&self, hash: TxHash) {
         let mut listener = self.inner.listeners.write();
@@ -115,30 +227,12 @@ where
         self.notify_subscribers(tx);
     }
 
-    fn subscribe(&self) -> Subscription<T, O> {
-        let (tx, rx) = mpsc::unbounded_channel();
-        let subscriber = Subscription::new(rx);
-        self.inner.subscribers.write().push(tx);
-        subscriber
-    }
-}
-
-impl<T, V, O> TransactionPool for Pool<T, V, O>
-where
-    T: PoolTransaction + fmt::Debug,
-    V: Validator<Transaction = T>,
-    O: PoolOrd<Transaction = T>,
-{
-    type Transaction = T;
-    type Validator = V;
-    type Ordering = O;
-
-    fn add_transaction(&self, tx: T) -> PoolResult<TxHash> {
+    /// Validates the transaction and inserts it in the pool if it's valid. Returns `false` if the
+    /// transaction has been queued instead.
+    fn validate_and_insert(&self, tx: T) -> PoolResult<bool> {
         let hash = tx.hash();
         let id = TxId::new(tx.sender(), tx.nonce());
 
-        info!(target: "pool", hash = format!("{hash:#x}"), "Transaction received.");
-
         match self.inner.validator.validate(tx) {
             Ok(outcome) => {
                 match outcome {
@@ -151,7 +245,7 @@ where
                         self.inner.transactions.write().insert(tx.clone());
                         self.notify(tx);
 
-                        Ok(hash)
+                        Ok(true)
                     }
 
                     // TODO: create a small cache for rejected transactions to respect the rpc spec
@@ -161,16 +255,24 @@ where
                         Err(PoolError::InvalidTransaction(Box::new(error)))
                     }
 
-                    // return as error for now but ideally we should kept the tx in a separate
-                    // queue and revalidate it when the parent tx is added to the pool
                     ValidationOutcome::Dependent { tx, tx_nonce, current_nonce } => {
                         info!(target: "pool", hash = format!("{hash:#x}"), %tx_nonce, %current_nonce, "Dependent transaction.");
-                        let err = InvalidTransactionError::InvalidNonce {
-                            address: tx.sender(),
-                            current_nonce,
-                            tx_nonce,
-                        };
-                        Err(PoolError::InvalidTransaction(Box::new(err)))
+                        match self.inner.config.nonce_validation {
+                            // the tx is revalidated once the txs of the preceding nonces are added
+                            NonceValidationMode::Queued => {
+                                self.enqueue(tx, tx_nonce)?;
+                                Ok(false)
+                            }
+
+                            NonceValidationMode::Strict => {
+                                let err = InvalidTransactionError::InvalidNonce {
+                                    address: tx.sender(),
+                                    current_nonce,
+                                    tx_nonce,
+                                };
+                                Err(PoolError::InvalidTransaction(Box::new(err)))
+                            }
+                        }
                     }
                 }
             }
@@ -182,6 +284,152 @@ where
         }
     }
 
+    /// Keeps the transaction aside until the transactions of the preceding nonces are added to the
+    /// pool. A queued transaction can't be replaced by another one with the same nonce.
+    fn enqueue(&self, tx: T, nonce: Nonce) -> PoolResult<()> {
+        let PoolConfig { max_queued, max_queued_per_sender, .. } = self.inner.config;
+        let address = tx.sender();
+
+        let mut queued = self.inner.queued.write();
+        let sender_txs = queued.get(&address);
+
+        if sender_txs.is_some_and(|txs| txs.contains_key(&nonce)) {
+            return Err(PoolError::DuplicateNonce { address, nonce });
+        }
+
+        let sender_queued = sender_txs.map_or(0, BTreeMap::len);
+        let total_queued = queued.values().map(BTreeMap::len).sum::<usize>();
+        if sender_queued >= max_queued_per_sender || total_queued >= max_queued {
+            warn!(target: "pool", hash = format!("{:#x}", tx.hash()), %address, "Queue is full.");
+            return Err(PoolError::QueueFull);
+        }
+
+        queued.entry(address).or_default().insert(nonce, tx);
+        Ok(())
+    }
+
+    /// Returns `true` if a transaction with the given hash is either pending or queued in the pool.
+    fn is_known(&self, hash: TxHash) -> bool {
+        if self.inner.transactions.read().iter().any(|tx| tx.tx.hash() == hash) {
+            return true;
+        }
+
+        let queued = self.inner.queued.read();
+        queued.values().flat_map(BTreeMap::values).any(|tx| tx.hash() == hash)
+    }
+
+    /// Returns the pending transaction to evict to make room for the incoming transaction, if the
+    /// pool is full and the [PoolFullPolicy] allows it.
+    fn eviction_candidate(&self, tx: &T) -> PoolResult<Option<TxHash>> {
+        let PoolConfig { max_size, full_policy, .. } = self.inner.config;
+        if self.inner.transactions.read().len() + self.queued_size() < max_size {
+            return Ok(None);
+        }
+
+        if full_policy == PoolFullPolicy::EvictLowestFee {
+            let txs = self.inner.transactions.read();
+
+            // the last pending tx of every sender, other than the sender of the incoming tx
+            let mut last_txs: HashMap<ContractAddress, &T> = HashMap::new();
+            for pending in txs.iter().filter(|t| t.tx.sender() != tx.sender()) {
+                let last = last_txs.entry(pending.tx.sender()).or_insert(pending.tx.as_ref());
+                if pending.tx.nonce() > last.nonce() {
+                    *last = pending.tx.as_ref();
+                }
+            }
+
+            let lowest = last_txs.into_values().min_by_key(|t| fee_rank(*t));
+            if let Some(evicted) = lowest.filter(|t| fee_rank(*t) < fee_rank(tx)) {
+                return Ok(Some(evicted.hash()));
+            }
+        }
+
+        warn!(target: "pool", hash = format!("{:#x}", tx.hash()), %max_size, "Pool is full.");
+        Err(PoolError::PoolFull { max_size })
+    }
+
+    /// Removes the pending transaction from the pool, and lets its sender reuse its nonce.
+    fn evict(&self, hash: TxHash) {
+        let evicted = {
+            let mut txs = self.inner.transactions.write();
+            let evicted = txs.iter().find(|t| t.tx.hash() == hash).map(|t| Arc::clone(&t.tx));
+            txs.retain(|t| t.tx.hash() != hash);
+            evicted
+        };
+
+        // the tx may have been removed in the meantime, eg if it has been mined
+        if let Some(tx) = evicted {
+            info!(target: "pool", hash = format!("{hash:#x}"), "Transaction evicted.");
+            self.inner.validator.reset_nonce(tx.sender(), tx.nonce());
+        }
+    }
+
+    /// Adds the queued transactions of the sender to the pool, in nonce order, until one of them
+    /// is still waiting for a preceding nonce.
+    fn promote_queued(&self, sender: ContractAddress) {
+        loop {
+            let next = {
+                let mut queued = self.inner.queued.write();
+                let Some(txs) = queued.get_mut(&sender) else { break };
+                let next = txs.pop_first().map(|(_, tx)| tx);
+                if txs.is_empty() {
+                    queued.remove(&sender);
+                }
+                next
+            };
+
+            // invalid txs are dropped, and the following ones are given a chance anyway
+            match next.map(|tx| self.validate_and_insert(tx)) {
+                Some(Ok(true) | Err(_)) => continue,
+                Some(Ok(false)) | None => break,
+            }
+        }
+    }
+
+    fn subscribe(&self) -> Subscription<T, O> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let subscriber = Subscription::new(rx);
+        self.inner.subscribers.write().push(tx);
+        subscriber
+    }
+}
+
+impl<T, V, O> TransactionPool for Pool<T, V, O>
+where
+    T: PoolTransaction + fmt::Debug,
+    V: Validator<Transaction = T>,
+    O: PoolOrd<Transaction = T>,
+{
+    type Transaction = T;
+    type Validator = V;
+    type Ordering = O;
+
+    fn add_transaction(&self, tx: T) -> PoolResult<TxHash> {
+        let hash = tx.hash();
+        let sender = tx.sender();
+
+        info!(target: "pool", hash = format!("{hash:#x}"), "Transaction received.");
+
+        if self.is_known(hash) {
+            warn!(target: "pool", hash = format!("{hash:#x}"), "Duplicate transaction.");
+            return Err(PoolError::DuplicateTransaction { hash });
+        }
+
+        // a pending tx is only evicted once the incoming tx is known to be valid
+        let evicted = self.eviction_candidate(&tx)?;
+        let inserted = self.validate_and_insert(tx)?;
+
+        if let Some(hash) = evicted {
+            self.evict(hash);
+        }
+
+        if inserted {
+            self.promote_queued(sender);
+        }
+
+        Ok(hash)
+    }
+
     fn pending_transactions(&self) -> PendingTransactions<Self::Transaction, Self::Ordering> {
         // take all the transactions
         PendingTransactions {
@@ -213,8 +461,14 @@ where
 
     fn remove_transactions(&self, hashes: &[TxHash]) {
         // retain only transactions that aren't included in the list
-        let mut txs = self.inner.transactions.write();
-        txs.retain(|t| !hashes.contains(&t.tx.hash()))
+        self.inner.transactions.write().retain(|t| !hashes.contains(&t.tx.hash()));
+
+        // the txs are removed once their block is mined and the validator is updated with its
+        // state, so some of the queued txs may not be waiting for a preceding nonce anymore
+        let senders = self.inner.queued.read().keys().copied().collect::<Vec<_>>();
+        for sender in senders {
+            self.promote_queued(sender);
+        }
     }
 
     fn size(&self) -> usize {
@@ -226,6 +480,12 @@ where
     }
 }
 
+/// The rank of a transaction when deciding which one to evict from a full pool, the transaction
+/// with the lowest rank being evicted first.
+fn fee_rank<T: PoolTransaction>(tx: &T) -> (u128, u64) {
+    (tx.max_fee(), tx.tip())
+}
+
 impl<T, V, O> Clone for Pool<T, V, O>
 where
     T: PoolTransaction,
@@ -291,6 +551,11 @@ pub(crate) mod test_utils {
             self.nonce = nonce;
             self
         }
+
+        pub fn with_max_fee(mut self, max_fee: u128) -> Self {
+            self.max_fee = max_fee;
+            self
+        }
     }
 
     impl PoolTransaction for PoolTx {
@@ -319,17 +584,21 @@ pub(crate) mod test_utils {
 #[cfg(test)]
 mod tests {
 
+    use std::collections::HashMap;
+
     use futures::StreamExt;
     use katana_primitives::contract::{ContractAddress, Nonce};
     use katana_primitives::transaction::TxHash;
     use katana_primitives::Felt;
+    use parking_lot::Mutex;
 
     use super::test_utils::*;
-    use super::Pool;
+    use super::{NonceValidationMode, Pool, PoolConfig, PoolFullPolicy};
     use crate::ordering::FiFo;
     use crate::tx::PoolTransaction;
-    use crate::validation::NoopValidator;
-    use crate::TransactionPool;
+    use crate::validation::error::InvalidTransactionError;
+    use crate::validation::{NoopValidator, ValidationOutcome, ValidationResult, Validator};
+    use crate::{PoolError, TransactionPool};
 
     /// Tx pool that uses a noop validator and a first-come-first-serve ordering.
     type TestPool = Pool<PoolTx, NoopValidator<PoolTx>, FiFo<PoolTx>>;
@@ -461,6 +730,233 @@ mod tests {
         });
     }
 
+    /// Validator that only checks the nonce of the transactions against the nonces of the
+    /// previously validated transactions.
+    #[derive(Debug, Default)]
+    struct NonceValidator {
+        nonces: Mutex<HashMap<ContractAddress, Nonce>>,
+    }
+
+    impl Validator for NonceValidator {
+        type Transaction = PoolTx;
+
+        fn validate(&self, tx: PoolTx) -> ValidationResult<PoolTx> {
+            let mut nonces = self.nonces.lock();
+            let current_nonce = nonces.get(&tx.sender()).copied().unwrap_or_default();
+            let tx_nonce = tx.nonce();
+
+            let outcome = if tx_nonce > current_nonce {
+                ValidationOutcome::Dependent { tx, tx_nonce, current_nonce }
+            } else if tx_nonce < current_nonce {
+                let address = tx.sender();
+                let error =
+                    InvalidTransactionError::InvalidNonce { address, current_nonce, tx_nonce };
+                ValidationOutcome::Invalid { tx, error }
+            } else {
+                nonces.insert(tx.sender(), current_nonce + Felt::ONE);
+                ValidationOutcome::Valid(tx)
+            };
+
+            Ok(outcome)
+        }
+
+        fn reset_nonce(&self, address: ContractAddress, nonce: Nonce) {
+            self.nonces.lock().insert(address, nonce);
+        }
+    }
+
+    #[tokio::test]
+    async fn queued_nonce_validation() {
+        let config =
+            PoolConfig { nonce_validation: NonceValidationMode::Queued, ..Default::default() };
+        let pool = Pool::with_config(NonceValidator::default(), FiFo::new(), config);
+
+        let sender = ContractAddress::from(Felt::from(0x1337u64));
+        let tx = |nonce: u64| PoolTx::new().with_sender(sender).with_nonce(Nonce::from(nonce));
+
+        // txs with a future nonce are kept aside until the gap is filled
+        pool.add_transaction(tx(2)).unwrap();
+        pool.add_transaction(tx(1)).unwrap();
+        assert_eq!(pool.size(), 0);
+        assert_eq!(pool.queued_size(), 2);
+
+        pool.add_transaction(tx(0)).unwrap();
+        assert_eq!(pool.size(), 3);
+        assert_eq!(pool.queued_size(), 0);
+
+        let mut pendings = pool.pending_transactions();
+        for nonce in 0..3u64 {
+            let pending = pendings.next().await.unwrap();
+            assert_eq!(pending.tx.nonce(), Nonce::from(nonce));
+        }
+
+        // a tx with an already used nonce is still rejected
+        let err = pool.add_transaction(tx(1)).unwrap_err();
+        assert!(matches!(err, PoolError::InvalidTransaction(_)));
+    }
+
+    #[test]
+    fn queued_txs_limits() {
+        let config = PoolConfig {
+            nonce_validation: NonceValidationMode::Queued,
+            max_queued: 3,
+            max_queued_per_sender: 2,
+            ..Default::default()
+        };
+        let pool = Pool::with_config(NonceValidator::default(), FiFo::new(), config);
+
+        let tx = |sender: u64, nonce: u64| {
+            let sender = ContractAddress::from(Felt::from(sender));
+            PoolTx::new().with_sender(sender).with_nonce(Nonce::from(nonce))
+        };
+
+        pool.add_transaction(tx(1, 1)).unwrap();
+
+        // a queued tx can't be replaced
+        let err = pool.add_transaction(tx(1, 1)).unwrap_err();
+        assert!(matches!(err, PoolError::DuplicateNonce { .. }));
+
+        // per sender limit
+        pool.add_transaction(tx(1, 2)).unwrap();
+        let err = pool.add_transaction(tx(1, 3)).unwrap_err();
+        assert!(matches!(err, PoolError::QueueFull));
+
+        // global limit
+        pool.add_transaction(tx(2, 1)).unwrap();
+        let err = pool.add_transaction(tx(3, 1)).unwrap_err();
+        assert!(matches!(err, PoolError::QueueFull));
+
+        assert_eq!(pool.queued_size(), 3);
+    }
+
+    #[test]
+    fn promote_queued_txs_after_removal() {
+        let config =
+            PoolConfig { nonce_validation: NonceValidationMode::Queued, ..Default::default() };
+        let pool = Pool::with_config(NonceValidator::default(), FiFo::new(), config);
+
+        let sender = ContractAddress::from(Felt::from(0x1337u64));
+        let tx = PoolTx::new().with_sender(sender).with_nonce(Nonce::TWO);
+        pool.add_transaction(tx.clone()).unwrap();
+        assert_eq!(pool.queued_size(), 1);
+
+        // the sender's nonce is bumped by a block, eg by txs that didn't go through the pool
+        pool.validator().nonces.lock().insert(sender, Nonce::TWO);
+        pool.remove_transactions(&[]);
+
+        assert!(pool.contains(tx.hash()));
+        assert_eq!(pool.queued_size(), 0);
+    }
+
+    #[test]
+    fn reject_duplicate_transactions() {
+        let config =
+            PoolConfig { nonce_validation: NonceValidationMode::Queued, ..Default::default() };
+        let pool = Pool::with_config(NonceValidator::default(), FiFo::new(), config);
+
+        let sender = ContractAddress::from(Felt::from(0x1337u64));
+        let pending = PoolTx::new().with_sender(sender).with_nonce(Nonce::ZERO);
+        let queued = PoolTx::new().with_sender(sender).with_nonce(Nonce::TWO);
+        pool.add_transaction(pending.clone()).unwrap();
+        pool.add_transaction(queued.clone()).unwrap();
+
+        for tx in [pending, queued] {
+            let err = pool.add_transaction(tx.clone()).unwrap_err();
+            assert!(matches!(err, PoolError::DuplicateTransaction { hash } if hash == tx.hash()));
+        }
+
+        assert_eq!(pool.size(), 1);
+        assert_eq!(pool.queued_size(), 1);
+    }
+
+    #[test]
+    fn strict_nonce_validation() {
+        let pool = Pool::new(NonceValidator::default(), FiFo::new());
+
+        let sender = ContractAddress::from(Felt::from(0x1337u64));
+        let tx = PoolTx::new().with_sender(sender).with_nonce(Nonce::ONE);
+
+        let err = pool.add_transaction(tx).unwrap_err();
+        assert!(matches!(err, PoolError::InvalidTransaction(_)));
+        assert_eq!(pool.size(), 0);
+        assert_eq!(pool.queued_size(), 0);
+    }
+
+    #[test]
+    fn reject_when_pool_is_full() {
+        let config = PoolConfig { max_size: 2, ..Default::default() };
+        let pool = Pool::with_config(NoopValidator::new(), FiFo::new(), config);
+
+        pool.add_transaction(PoolTx::new()).unwrap();
+        pool.add_transaction(PoolTx::new()).unwrap();
+
+        let tx = PoolTx::new();
+        let err = pool.add_transaction(tx.clone()).unwrap_err();
+        assert!(matches!(err, PoolError::PoolFull { max_size: 2 }));
+        assert!(!pool.contains(tx.hash()));
+        assert_eq!(pool.size(), 2);
+    }
+
+    #[test]
+    fn evict_lowest_fee_when_pool_is_full() {
+        let full_policy = PoolFullPolicy::EvictLowestFee;
+        let config = PoolConfig { max_size: 2, full_policy, ..Default::default() };
+        let pool = Pool::with_config(NoopValidator::new(), FiFo::new(), config);
+
+        let low = PoolTx::new().with_max_fee(10);
+        let high = PoolTx::new().with_max_fee(30);
+        pool.add_transaction(low.clone()).unwrap();
+        pool.add_transaction(high.clone()).unwrap();
+
+        // the incoming tx pays more than the lowest fee tx in the pool, so it takes its place
+        let tx = PoolTx::new().with_max_fee(20);
+        pool.add_transaction(tx.clone()).unwrap();
+        assert!(!pool.contains(low.hash()));
+        assert!(pool.contains(high.hash()));
+        assert!(pool.contains(tx.hash()));
+
+        // the incoming tx doesn't pay more than any tx in the pool
+        let err = pool.add_transaction(PoolTx::new().with_max_fee(20)).unwrap_err();
+        assert!(matches!(err, PoolError::PoolFull { .. }));
+        assert_eq!(pool.size(), 2);
+    }
+
+    #[test]
+    fn evict_last_tx_of_sender_when_pool_is_full() {
+        let full_policy = PoolFullPolicy::EvictLowestFee;
+        let config = PoolConfig { max_size: 2, full_policy, ..Default::default() };
+        let pool = Pool::with_config(NonceValidator::default(), FiFo::new(), config);
+
+        let sender = ContractAddress::from(Felt::from(0x1337u64));
+        let first = PoolTx::new().with_sender(sender).with_nonce(Nonce::ZERO).with_max_fee(10);
+        let last = PoolTx::new().with_sender(sender).with_nonce(Nonce::ONE).with_max_fee(30);
+        pool.add_transaction(first.clone()).unwrap();
+        pool.add_transaction(last.clone()).unwrap();
+
+        let other = ContractAddress::from(Felt::from(0xdeadu64));
+        let tx = |nonce: Nonce, max_fee: u128| {
+            PoolTx::new().with_sender(other).with_nonce(nonce).with_max_fee(max_fee)
+        };
+
+        // the tx with the lowest fee can't be evicted as it's followed by another tx of its sender
+        let err = pool.add_transaction(tx(Nonce::ZERO, 20)).unwrap_err();
+        assert!(matches!(err, PoolError::PoolFull { .. }));
+
+        // an invalid tx doesn't evict anything
+        let err = pool.add_transaction(tx(Nonce::ONE, 40)).unwrap_err();
+        assert!(matches!(err, PoolError::InvalidTransaction(_)));
+        assert!(pool.contains(last.hash()));
+
+        let incoming = tx(Nonce::ZERO, 40);
+        pool.add_transaction(incoming.clone()).unwrap();
+        assert!(pool.contains(first.hash()));
+        assert!(!pool.contains(last.hash()));
+        assert!(pool.contains(incoming.hash()));
+
+        // the sender of the evicted tx can send a tx with the same nonce again
+        assert_eq!(pool.validator().nonces.lock().get(&sender), Some(&Nonce::ONE));
+    }
+
     #[tokio::test]
     #[ignore = "Txs dependency management not fully implemented yet"]
     async fn dependent_txs_linear_insertion() {
@@ -2,6 +2,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use katana_primitives::contract::{ContractAddress, Nonce};
+use katana_primitives::fee::ResourceBoundsMapping;
 use katana_primitives::transaction::{
     DeclareTx, DeployAccountTx, ExecutableTx, ExecutableTxWithHash, InvokeTx, TxHash,
 };
@@ -20,7 +21,8 @@ pub trait PoolTransaction: Clone {
     /// return the tx sender.
     fn sender(&self) -> ContractAddress;
 
-    /// return the max fee that tx is willing to pay.
+    /// return the max fee that tx is willing to pay. for txs that don't have a max fee (ie v3 txs),
+    /// this is the max fee allowed by their resource bounds.
     fn max_fee(&self) -> u128;
 
     /// return the tx tip.
@@ -157,18 +159,18 @@ impl PoolTransaction for ExecutableTxWithHash {
             ExecutableTx::Invoke(tx) => match tx {
                 InvokeTx::V0(v0) => v0.max_fee,
                 InvokeTx::V1(v1) => v1.max_fee,
-                InvokeTx::V3(_) => 0, // V3 doesn't have max_fee
+                InvokeTx::V3(v3) => max_fee_of_bounds(&v3.resource_bounds),
             },
             ExecutableTx::L1Handler(tx) => tx.paid_fee_on_l1,
             ExecutableTx::Declare(tx) => match &tx.transaction {
                 DeclareTx::V0(v0) => v0.max_fee,
                 DeclareTx::V1(v1) => v1.max_fee,
                 DeclareTx::V2(v2) => v2.max_fee,
-                DeclareTx::V3(_) => 0, // V3 doesn't have max_fee
+                DeclareTx::V3(v3) => max_fee_of_bounds(&v3.resource_bounds),
             },
             ExecutableTx::DeployAccount(tx) => match tx {
                 DeployAccountTx::V1(v1) => v1.max_fee,
-                DeployAccountTx::V3(_) => 0, // V3 doesn't have max_fee
+                DeployAccountTx::V3(v3) => max_fee_of_bounds(&v3.resource_bounds),
             },
         }
     }
@@ -191,3 +193,12 @@ impl PoolTransaction for ExecutableTxWithHash {
         }
     }
 }
+
+/// The max fee a v3 tx is willing to pay, ie the max amount of every resource at its max price.
+fn max_fee_of_bounds(bounds: &ResourceBoundsMapping) -> u128 {
+    let l1_gas =
+        u128::from(bounds.l1_gas.max_amount).saturating_mul(bounds.l1_gas.max_price_per_unit);
+    let l2_gas =
+        u128::from(bounds.l2_gas.max_amount).saturating_mul(bounds.l2_gas.max_price_per_unit);
+    l1_gas.saturating_add(l2_gas)
+}
@@ -68,4 +68,22 @@ pub enum InvalidTransactionError {
     /// declared.
     #[error("Class with hash {class_hash:#x} has already been declared.")]
     ClassAlreadyDeclared { class_hash: ClassHash },
+
+    /// Error when the calldata of the transaction is longer than the configured limit.
+    #[error("Calldata length ({length}) exceeds the maximum allowed length ({max}).")]
+    CalldataTooLong {
+        /// The length of the transaction calldata.
+        length: usize,
+        /// The maximum allowed calldata length.
+        max: usize,
+    },
+    /// Error when one of the calls of the transaction targets an entry point that doesn't exist in
+    /// the called contract.
+    #[error("Entry point selector {selector:#x} not found in contract {address}.")]
+    EntryPointNotFound {
+        /// The selector of the missing entry point.
+        selector: Felt,
+        /// The address of the called contract.
+        address: ContractAddress,
+    },
 }
@@ -2,7 +2,7 @@ pub mod error;
 pub mod stateful;
 
 use error::InvalidTransactionError;
-use katana_primitives::contract::Nonce;
+use katana_primitives::contract::{ContractAddress, Nonce};
 use katana_primitives::transaction::TxHash;
 
 use crate::tx::PoolTransaction;
@@ -43,6 +43,10 @@ pub trait Validator {
     ) -> Vec<ValidationResult<Self::Transaction>> {
         txs.into_iter().map(|tx| self.validate(tx)).collect()
     }
+
+    /// Resets the nonce expected from the next transaction of the sender, eg after its valid
+    /// transaction with this nonce has been evicted from the pool.
+    fn reset_nonce(&self, _address: ContractAddress, _nonce: Nonce) {}
 }
 
 // outcome of the validation phase. the variant of this enum determines on which pool
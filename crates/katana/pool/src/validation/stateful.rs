@@ -17,10 +17,12 @@ use katana_executor::implementation::blockifier::COMPILED_CLASS_CACHE;
 use katana_executor::ExecutionFlags;
 use katana_primitives::contract::{ContractAddress, Nonce};
 use katana_primitives::env::{BlockEnv, CfgEnv};
-use katana_primitives::transaction::{ExecutableTx, ExecutableTxWithHash};
+use katana_primitives::transaction::{ExecutableTx, ExecutableTxWithHash, InvokeTx};
+use katana_primitives::utils::transaction::decode_execute_calldata;
 use katana_primitives::Felt;
 use katana_provider::error::ProviderError;
 use katana_provider::traits::state::StateProvider;
+use katana_provider::ProviderResult;
 use parking_lot::Mutex;
 
 use super::{Error, InvalidTransactionError, ValidationOutcome, ValidationResult, Validator};
@@ -105,6 +107,13 @@ impl Validator for TxValidator {
         let tx_nonce = tx.nonce();
         let address = tx.sender();
 
+        let length = tx.calldata().len();
+        let max = this.cfg_env.tx_limits.max_calldata_length;
+        if length > max {
+            let error = InvalidTransactionError::CalldataTooLong { length, max };
+            return Ok(ValidationOutcome::Invalid { tx, error });
+        }
+
         // For declare transactions, perform a static check if there's already an existing class
         // with the same hash.
         if let ExecutableTx::Declare(ref declare_tx) = tx.transaction {
@@ -131,6 +140,16 @@ impl Validator for TxValidator {
             return Ok(ValidationOutcome::Dependent { current_nonce, tx_nonce, tx });
         }
 
+        // For invoke transactions sent by an account, make sure the entry points it calls exist
+        // before running the validation logic and charging any fee. V0 transactions call the
+        // target contract directly, so their calldata isn't an `__execute__` calldata.
+        if let ExecutableTx::Invoke(InvokeTx::V1(_) | InvokeTx::V3(_)) = tx.transaction {
+            let result = check_entry_points(this.state.as_ref().as_ref(), tx.calldata());
+            if let Some(error) = result.map_err(|e| Error::new(tx.hash, e.into()))? {
+                return Ok(ValidationOutcome::Invalid { tx, error });
+            }
+        }
+
         // Check if validation of an invoke transaction should be skipped due to deploy_account not
         // being proccessed yet. This feature is used to improve UX for users sending
         // deploy_account + invoke at once.
@@ -160,6 +179,34 @@ impl Validator for TxValidator {
             _ => result,
         }
     }
+
+    fn reset_nonce(&self, address: ContractAddress, nonce: Nonce) {
+        self.inner.lock().pool_nonces.insert(address, nonce);
+    }
+}
+
+/// Checks that the calls of an invoke transaction target existing entry points.
+///
+/// Only calldata following the Cairo 1 `__execute__` encoding can be checked, and calls to
+/// contracts that aren't deployed yet are skipped, so this is not an exhaustive check.
+fn check_entry_points(
+    state: &dyn StateProvider,
+    calldata: &[Felt],
+) -> ProviderResult<Option<InvalidTransactionError>> {
+    let Some(calls) = decode_execute_calldata(calldata) else { return Ok(None) };
+
+    for call in calls {
+        let address = ContractAddress::from(call.to);
+        let Some(class_hash) = state.class_hash_of_contract(address)? else { continue };
+        let Some(class) = state.class(class_hash)? else { continue };
+
+        if !class.has_external_entry_point(call.selector) {
+            let selector = call.selector;
+            return Ok(Some(InvalidTransactionError::EntryPointNotFound { selector, address }));
+        }
+    }
+
+    Ok(None)
 }
 
 // perform validation on the pool transaction using the provided stateful validator
@@ -82,6 +82,22 @@ impl Default for GasPrices {
     }
 }
 
+/// The algorithm used to compute the hash of a block.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, strum_macros::EnumString, strum_macros::Display,
+)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum BlockHashAlgorithm {
+    /// The Poseidon based block hash used since Starknet 0.13.2. See [`Header::compute_hash`].
+    #[default]
+    Poseidon,
+    /// The Pedersen based block hash used prior to Starknet 0.13.2. See
+    /// [`Header::compute_legacy_hash`].
+    Pedersen,
+}
+
 // uncommited header ->  header (what is stored in the database)
 
 /// Represents a block header.
@@ -166,6 +182,49 @@ impl Header {
         ])
     }
 
+    /// Computes the block hash using the given algorithm.
+    pub fn compute_hash_with(&self, algorithm: BlockHashAlgorithm) -> Felt {
+        match algorithm {
+            BlockHashAlgorithm::Poseidon => self.compute_hash(),
+            BlockHashAlgorithm::Pedersen => self.compute_legacy_hash(),
+        }
+    }
+
+    /// Computes the block hash of blocks prior to Starknet 0.13.2.
+    ///
+    /// A legacy block hash is defined as the Pedersen hash of the header’s fields, as follows:
+    ///
+    /// h(𝐵) = h(
+    ///     block_number,
+    ///     global_state_root,
+    ///     sequencer_address,
+    ///     block_timestamp,
+    ///     transaction_count,
+    ///     transactions_commitment,
+    ///     event_count,
+    ///     events_commitment,
+    ///     0,
+    ///     0,
+    ///     parent_block_hash
+    /// )
+    pub fn compute_legacy_hash(&self) -> Felt {
+        use starknet_types_core::hash::{Pedersen, StarkHash};
+
+        Pedersen::hash_array(&[
+            self.number.into(),
+            self.state_root,
+            self.sequencer_address.into(),
+            self.timestamp.into(),
+            self.transaction_count.into(),
+            self.transactions_commitment,
+            self.events_count.into(),
+            self.events_commitment,
+            Felt::ZERO,
+            Felt::ZERO,
+            self.parent_hash,
+        ])
+    }
+
     // Concantenate the transaction_count, event_count and state_diff_length, and l1_da_mode into a
     // single felt.
     //
@@ -283,6 +342,45 @@ impl SealedBlock {
     pub fn unseal(self) -> Block {
         Block { header: self.header, body: self.body }
     }
+
+    /// Checks that the block is self-consistent, ie. that its header matches its body and that
+    /// its hash is the hash of its header computed with the given algorithm.
+    ///
+    /// This doesn't check the block against the chain it extends, which is done by the backend
+    /// when the block is stored.
+    pub fn validate_with(&self, algorithm: BlockHashAlgorithm) -> Result<(), BlockValidationError> {
+        let tx_count = self.body.len();
+        if self.header.transaction_count as usize != tx_count {
+            return Err(BlockValidationError::TransactionCountMismatch {
+                header: self.header.transaction_count,
+                body: tx_count,
+            });
+        }
+
+        if self.header.timestamp == 0 {
+            return Err(BlockValidationError::ZeroTimestamp);
+        }
+
+        let computed = self.header.compute_hash_with(algorithm);
+        if self.hash != computed {
+            return Err(BlockValidationError::HashMismatch { expected: self.hash, computed });
+        }
+
+        Ok(())
+    }
+}
+
+/// Errors returned when validating a [`SealedBlock`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BlockValidationError {
+    #[error("transaction count mismatch: header has {header} but body has {body} transactions")]
+    TransactionCountMismatch { header: u32, body: usize },
+
+    #[error("block timestamp is zero")]
+    ZeroTimestamp,
+
+    #[error("block hash mismatch: expected {expected:#x} but computed {computed:#x}")]
+    HashMismatch { expected: BlockHash, computed: BlockHash },
 }
 
 /// A sealed block along with its status.
@@ -327,7 +425,7 @@ pub struct ExecutableBlock {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::felt;
+    use crate::{address, felt};
 
     #[test]
     fn header_concat_counts() {
@@ -343,4 +441,125 @@ mod tests {
         let actual = Header::concat_counts(0xFFFFFFFF, 0, 0, L1DataAvailabilityMode::Calldata);
         assert_eq!(actual, expected);
     }
+
+    // Sepolia integration block 35748 (v0.13.2)
+    #[test]
+    fn header_compute_hash() {
+        let header = Header {
+            number: 35748,
+            parent_hash: felt!("0x77140bef51bbb4d1932f17cc5081825ff18465a1df4440ca0429a4fa80f1dc5"),
+            state_root: felt!("0x38e01cbe2d5721780b2e1a478fd131f2ffcc099528dd2e1289f26b027127790"),
+            transactions_commitment: felt!(
+                "0x54f43cf29b80cc83aef36f3195b73cb165ad12553eae147b4cce62adbf0b180"
+            ),
+            events_commitment: felt!(
+                "0x12dfbe9dbbaba9c34b5a4c0ba622dcd8e2bb0264481c77f073008b59825a758"
+            ),
+            receipts_commitment: felt!(
+                "0x6f12628d21a8df7f158b631d801fc0dd20034b9e22eca255bddc0c1c1bc283f"
+            ),
+            state_diff_commitment: felt!(
+                "0x23587c54d590b57b8e25acbf1e1a422eb4cd104e95ee4a681021a6bb7456afa"
+            ),
+            transaction_count: 2,
+            events_count: 2,
+            state_diff_length: 6,
+            timestamp: 1720426817,
+            sequencer_address: address!(
+                "0x1176a1bd84444c89232ec27754698e5d2e7e1a7f1539f12027f28b23ec9f3d8"
+            ),
+            l1_gas_prices: GasPrices::new(0x7427e87c4, 0x9346cee0949c),
+            l1_data_gas_prices: GasPrices::new(0x3b095dc6, 0x4ada914d823),
+            l1_da_mode: L1DataAvailabilityMode::Blob,
+            protocol_version: ProtocolVersion::parse("0.13.2").unwrap(),
+        };
+
+        let expected = felt!("0x1ea2a9cfa3df5297d58c0a04d09d276bc68d40fe64701305bbe2ed8f417e869");
+        assert_eq!(header.compute_hash(), expected);
+        assert_eq!(header.compute_hash_with(BlockHashAlgorithm::Poseidon), expected);
+    }
+
+    #[rstest::rstest]
+    // Mainnet block 2800 (v0.9.0)
+    #[case::v0_9_0(
+        Header {
+            number: 2800,
+            parent_hash: felt!("0x479b4ae76a7c36639d1726a02721472eefc4c7e55e7cc0ff69412b12171a1c3"),
+            state_root: felt!("0xe3607c420b5a34c9b713edeb4f2f2b4d254120697e9e2fff589f0119529efe"),
+            transactions_commitment: felt!("0x5636f650506884eb066c444dbb877509d1a5ae63504f3bbd9d7ab469853f791"),
+            events_commitment: felt!("0x5c9502936ad20d5af7b3d591d2fc12514519bfaebf7599ea3bc7dcab485b40c"),
+            transaction_count: 184,
+            events_count: 196,
+            timestamp: 1655849631,
+            sequencer_address: address!("0x5dcd266a80b8a5f29f04d779c6b166b80150c24f2180a75e82427242dab20a9"),
+            ..Default::default()
+        },
+        felt!("0x10967f99fb3b6c92106a2124abff9c9204c666be2ecceeae456e51614185d2")
+    )]
+    // Mainnet block 65000 (v0.11.1)
+    #[case::v0_11_1(
+        Header {
+            number: 65000,
+            parent_hash: felt!("0x649de055f4d5ce6af195dc3704add880bca1958d87cf13e05245f9d83fb880d"),
+            state_root: felt!("0x7b67971649c24ca8999ac9b8a645133e6f37ada7f7d6696c58be6df263ac58a"),
+            transactions_commitment: felt!("0x23b7240581913f6f4fe4cbb1fd7fb86e37f03dd8b78beecec7e3b06db4c4a4"),
+            events_commitment: felt!("0x2188fa269174ef8455a54f24d80b42abac54935295d0d6129178ed9a0756fbe"),
+            transaction_count: 236,
+            events_count: 814,
+            timestamp: 1685168481,
+            sequencer_address: address!("0x1176a1bd84444c89232ec27754698e5d2e7e1a7f1539f12027f28b23ec9f3d8"),
+            ..Default::default()
+        },
+        felt!("0x2e2860fc70cfefaf6a2f1c8ca865a01edbd2caa3c79f0e1f310cd5799abfe6e")
+    )]
+    // Mainnet block 550000 (v0.13.0)
+    #[case::v0_13_0(
+        Header {
+            number: 550000,
+            parent_hash: felt!("0x4c4ea04513937764f872b2516ca74b192e9038abde340e2e76ca2d1c094e23b"),
+            state_root: felt!("0x518de912a27414c66194cf0906c2628cbae8c244b78e1c9aae2ae2e04932d90"),
+            transactions_commitment: felt!("0x68a641f49f7e2c8809de54edbd46381de0ac21e06c49de79aade0eddcd251aa"),
+            events_commitment: felt!("0x5068eca0a4b28c17279cebdcf445ef1e8e8f33a665e2452e19a165690992d21"),
+            transaction_count: 121,
+            events_count: 602,
+            timestamp: 1708087864,
+            sequencer_address: address!("0x1176a1bd84444c89232ec27754698e5d2e7e1a7f1539f12027f28b23ec9f3d8"),
+            ..Default::default()
+        },
+        felt!("0x60c26ab8ee1490faa130ad5fbb7afa753e3ced0a29413ccef618bbdd36451d")
+    )]
+    fn header_compute_legacy_hash(#[case] header: Header, #[case] expected: Felt) {
+        assert_eq!(header.compute_legacy_hash(), expected);
+        assert_eq!(header.compute_hash_with(BlockHashAlgorithm::Pedersen), expected);
+    }
+
+    #[test]
+    fn validate_sealed_block() {
+        let header = Header { number: 1, timestamp: 100, ..Default::default() };
+        let block = Block { header, body: vec![] }.seal();
+        assert_eq!(block.validate_with(BlockHashAlgorithm::Poseidon), Ok(()));
+        assert!(block.validate_with(BlockHashAlgorithm::Pedersen).is_err());
+
+        let mut invalid = block.clone();
+        invalid.header.transaction_count = 1;
+        assert_eq!(
+            invalid.validate_with(BlockHashAlgorithm::Poseidon),
+            Err(BlockValidationError::TransactionCountMismatch { header: 1, body: 0 })
+        );
+
+        let invalid =
+            Block { header: Header { timestamp: 0, ..block.header.clone() }, body: vec![] };
+        assert_eq!(
+            invalid.seal().validate_with(BlockHashAlgorithm::Poseidon),
+            Err(BlockValidationError::ZeroTimestamp)
+        );
+
+        let mut invalid = block.clone();
+        invalid.header.state_root = felt!("0x1");
+        let computed = invalid.header.compute_hash();
+        assert_eq!(
+            invalid.validate_with(BlockHashAlgorithm::Poseidon),
+            Err(BlockValidationError::HashMismatch { expected: block.hash, computed })
+        );
+    }
 }
@@ -8,6 +8,7 @@ use starknet::core::utils::{normalize_address, starknet_keccak};
 use starknet::macros::short_string;
 use starknet_crypto::poseidon_hash_many;
 
+use crate::event::{decode_event, DecodedEvent};
 use crate::Felt;
 
 /// The canonical hash of a contract class. This is the identifier of a class.
@@ -83,6 +84,38 @@ impl ContractClass {
             _ => None,
         }
     }
+
+    /// Returns the ABI entries (ie functions, events, structs, etc) of the class.
+    ///
+    /// Returns an empty list if the class doesn't have an ABI, or if it is a legacy class.
+    pub fn abi(&self) -> Vec<&abi::Item> {
+        match self.as_sierra().and_then(|class| class.abi.as_ref()) {
+            Some(abi) => abi.items.iter().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Decodes an event emitted by a contract of this class against the event ABI of the class.
+    ///
+    /// Returns `None` if the class has no ABI, or if the event isn't declared in it.
+    pub fn decode_event(&self, keys: &[Felt], data: &[Felt]) -> Option<DecodedEvent> {
+        decode_event(&self.abi(), keys, data)
+    }
+
+    /// Checks if the class has an external entry point with the given selector.
+    ///
+    /// Always returns `true` for legacy classes, as their `__default__` entry point may handle any
+    /// selector.
+    pub fn has_external_entry_point(&self, selector: Felt) -> bool {
+        match self {
+            Self::Legacy(_) => true,
+            Self::Class(class) => class
+                .entry_points_by_type
+                .external
+                .iter()
+                .any(|entry| Felt::from(entry.selector.clone()) == selector),
+        }
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -213,8 +246,10 @@ mod tests {
 
     use starknet::core::types::contract::legacy::LegacyContractClass as StarknetRsLegacyContractClass;
     use starknet::core::types::contract::SierraClass as StarknetRsSierraContractClass;
+    use starknet::macros::{felt, selector};
 
     use super::{ContractClass, LegacyContractClass, SierraContractClass};
+    use crate::event::{DecodedField, DecodedValue};
 
     #[test]
     fn compute_class_hash() {
@@ -245,4 +280,58 @@ mod tests {
 
         assert_eq!(actual_hash, expected_hash);
     }
+
+    #[test]
+    fn class_abi() {
+        let artifact = include_str!("../../contracts/build/default_account.json");
+        let mut class = serde_json::from_str::<SierraContractClass>(artifact).unwrap();
+        assert!(!ContractClass::Class(class.clone()).abi().is_empty());
+
+        // class without an abi
+        class.abi = None;
+        assert!(ContractClass::Class(class).abi().is_empty());
+
+        let artifact = include_str!("../../contracts/build/erc20.json");
+        let class = serde_json::from_str::<LegacyContractClass>(artifact).unwrap();
+        assert!(ContractClass::Legacy(class).abi().is_empty());
+    }
+
+    #[test]
+    fn class_external_entry_points() {
+        let artifact = include_str!("../../contracts/build/default_account.json");
+        let class = serde_json::from_str::<SierraContractClass>(artifact).unwrap();
+        let class = ContractClass::Class(class);
+        assert!(class.has_external_entry_point(selector!("__execute__")));
+        assert!(!class.has_external_entry_point(selector!("not_an_entry_point")));
+
+        let artifact = include_str!("../../contracts/build/erc20.json");
+        let class = serde_json::from_str::<LegacyContractClass>(artifact).unwrap();
+        assert!(ContractClass::Legacy(class).has_external_entry_point(selector!("foo")));
+    }
+
+    #[test]
+    fn class_decode_event() {
+        let artifact = include_str!("../../contracts/build/default_account.json");
+        let class = serde_json::from_str::<SierraContractClass>(artifact).unwrap();
+        let class = ContractClass::Class(class);
+
+        // `OwnerAdded` is a nested variant of the account component event, which is itself a flat
+        // variant of the contract event
+        let owner = felt!("0x1337");
+        let event = class.decode_event(&[selector!("OwnerAdded"), owner], &[]).unwrap();
+        assert_eq!(event.name, "OwnerAdded");
+        assert_eq!(
+            event.fields,
+            vec![DecodedField { name: "new_owner_guid".into(), value: DecodedValue::Felt(owner) }]
+        );
+
+        // an unknown event, or one with leftover data, can't be decoded
+        assert!(class.decode_event(&[selector!("Unknown"), owner], &[]).is_none());
+        assert!(class.decode_event(&[selector!("OwnerAdded"), owner], &[owner]).is_none());
+
+        let artifact = include_str!("../../contracts/build/erc20.json");
+        let class = serde_json::from_str::<LegacyContractClass>(artifact).unwrap();
+        let keys = [selector!("Transfer")];
+        assert!(ContractClass::Legacy(class).decode_event(&keys, &[owner, owner]).is_none());
+    }
 }
@@ -83,7 +83,7 @@ pub fn encode_state_updates(value: StateUpdates) -> Vec<BigUint> {
         entry.metadata.new_nonce = Some(*nonce);
     }
 
-    for (addr, class_hash) in &value.deployed_contracts {
+    for (addr, class_hash) in value.deployed_contracts.iter().chain(&value.replaced_classes) {
         let entry = contract_updates.entry(*addr).or_default();
         entry.metadata.class_information_flag = true;
         entry.class_hash = Some(*class_hash);
@@ -1,4 +1,4 @@
-use crate::block::{BlockNumber, GasPrices};
+use crate::block::{BlockHashAlgorithm, BlockNumber, GasPrices};
 use crate::chain::ChainId;
 use crate::contract::ContractAddress;
 
@@ -30,6 +30,43 @@ pub struct CfgEnv {
     pub validate_max_n_steps: u32,
     /// The maximum recursion depth allowed.
     pub max_recursion_depth: usize,
+    /// The algorithm used to compute the hash of the produced blocks.
+    pub block_hash_algorithm: BlockHashAlgorithm,
+    /// Limits on the size of the transactions and of the events they emit.
+    pub tx_limits: TxLimits,
+}
+
+/// The maximum length of the calldata of a transaction on Starknet mainnet.
+pub const DEFAULT_MAX_CALLDATA_LENGTH: usize = 4000;
+/// The maximum length of the data of an event on Starknet mainnet.
+pub const DEFAULT_MAX_EVENT_DATA_LENGTH: usize = 300;
+/// The maximum number of events a transaction can emit on Starknet mainnet.
+pub const DEFAULT_MAX_EMITTED_EVENTS: usize = 1000;
+
+/// Limits on the size of the transactions and of the events they emit.
+///
+/// Defaults to the limits of Starknet mainnet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxLimits {
+    /// The maximum length of the calldata of a transaction. Transactions with longer calldata are
+    /// rejected before being added to the pool.
+    pub max_calldata_length: usize,
+    /// The maximum length of the data of a single event. Transactions emitting an event with
+    /// longer data are reverted.
+    pub max_event_data_length: usize,
+    /// The maximum number of events a transaction can emit. Transactions emitting more events
+    /// are reverted.
+    pub max_emitted_events: usize,
+}
+
+impl Default for TxLimits {
+    fn default() -> Self {
+        Self {
+            max_calldata_length: DEFAULT_MAX_CALLDATA_LENGTH,
+            max_event_data_length: DEFAULT_MAX_EVENT_DATA_LENGTH,
+            max_emitted_events: DEFAULT_MAX_EMITTED_EVENTS,
+        }
+    }
 }
 
 /// The contract addresses of the tokens used for the fees.
@@ -1,7 +1,13 @@
 use core::fmt;
+use std::collections::{HashMap, HashSet};
 use std::num::ParseIntError;
+use std::slice;
 
-use crate::Felt;
+use katana_cairo::lang::starknet_classes::abi;
+use starknet::core::utils::starknet_keccak;
+
+use crate::utils::join_u256;
+use crate::{Felt, U256};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
@@ -110,6 +116,257 @@ impl std::fmt::Display for MaybeForkedContinuationToken {
     }
 }
 
+/// An event decoded against the event ABI of the contract that emitted it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecodedEvent {
+    /// The name of the event, eg `Transfer`.
+    pub name: String,
+    /// The members of the event, in the order they are declared in the ABI.
+    pub fields: Vec<DecodedField>,
+}
+
+/// A named member of a decoded event or struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecodedField {
+    pub name: String,
+    pub value: DecodedValue,
+}
+
+/// A value decoded from its serialized felts according to its Cairo type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum DecodedValue {
+    /// Any type that is serialized into a single felt, eg `felt252`, `ContractAddress`, `u64`.
+    Felt(Felt),
+    U256(U256),
+    /// A `ByteArray`.
+    String(String),
+    /// An `Array`, a `Span` or a tuple.
+    Array(Vec<DecodedValue>),
+    Struct(Vec<DecodedField>),
+    Enum {
+        variant: String,
+        value: Box<DecodedValue>,
+    },
+}
+
+/// Decodes the `keys` and `data` of an event against the `abi` of the contract that emitted it.
+///
+/// Returns `None` if the ABI doesn't declare an event matching the keys, or if the keys and data
+/// don't exactly match the layout of the declared event.
+pub fn decode_event(abi: &[&abi::Item], keys: &[Felt], data: &[Felt]) -> Option<DecodedEvent> {
+    let decoder = AbiDecoder::new(abi);
+
+    decoder.roots().find_map(|root| {
+        let (mut keys, mut data) = (keys.iter(), data.iter());
+        let event = decoder.event(root, &mut keys, &mut data)?;
+        (keys.len() == 0 && data.len() == 0).then_some(event)
+    })
+}
+
+type Felts<'a> = slice::Iter<'a, Felt>;
+
+const U256_TYPE: &str = "core::integer::u256";
+const BYTE_ARRAY_TYPE: &str = "core::byte_array::ByteArray";
+
+struct AbiDecoder<'a> {
+    events: HashMap<&'a str, &'a abi::EventKind>,
+    structs: HashMap<&'a str, &'a [abi::StructMember]>,
+    enums: HashMap<&'a str, &'a [abi::EnumVariant]>,
+}
+
+impl<'a> AbiDecoder<'a> {
+    fn new(abi: &[&'a abi::Item]) -> Self {
+        let mut events = HashMap::new();
+        let mut structs = HashMap::new();
+        let mut enums = HashMap::new();
+
+        for item in abi {
+            match item {
+                abi::Item::Event(e) => {
+                    events.insert(e.name.as_str(), &e.kind);
+                }
+                abi::Item::Struct(s) => {
+                    structs.insert(s.name.as_str(), s.members.as_slice());
+                }
+                abi::Item::Enum(e) => {
+                    enums.insert(e.name.as_str(), e.variants.as_slice());
+                }
+                _ => {}
+            }
+        }
+
+        Self { events, structs, enums }
+    }
+
+    /// Returns the top-level events, ie the event enums that are not a variant of another event.
+    /// This is normally only the `Event` enum of the contract.
+    fn roots(&self) -> impl Iterator<Item = &'a str> + '_ {
+        let nested = self
+            .events
+            .values()
+            .flat_map(|kind| match kind {
+                abi::EventKind::Struct { members } => members.iter(),
+                abi::EventKind::Enum { variants } => variants.iter(),
+            })
+            .map(|field| field.r#type.as_str())
+            .collect::<HashSet<_>>();
+
+        self.events.iter().filter_map(move |(name, kind)| {
+            let is_enum = matches!(kind, abi::EventKind::Enum { .. });
+            (is_enum && !nested.contains(name)).then_some(*name)
+        })
+    }
+
+    fn event(&self, ty: &str, keys: &mut Felts<'_>, data: &mut Felts<'_>) -> Option<DecodedEvent> {
+        match self.events.get(ty)? {
+            abi::EventKind::Struct { members } => {
+                let fields = members
+                    .iter()
+                    .map(|member| {
+                        let value = match member.kind {
+                            abi::EventFieldKind::KeySerde => self.value(&member.r#type, keys)?,
+                            abi::EventFieldKind::DataSerde => self.value(&member.r#type, data)?,
+                            abi::EventFieldKind::Nested | abi::EventFieldKind::Flat => {
+                                let event = self.event(&member.r#type, keys, data)?;
+                                DecodedValue::Struct(event.fields)
+                            }
+                        };
+                        Some(DecodedField { name: member.name.clone(), value })
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+
+                let name = ty.rsplit("::").next().unwrap_or(ty).to_string();
+                Some(DecodedEvent { name, fields })
+            }
+
+            abi::EventKind::Enum { variants } => variants.iter().find_map(|variant| {
+                let (mut k, mut d) = (keys.clone(), data.clone());
+
+                // nested variants are prefixed with the selector of the variant name, whereas flat
+                // variants are serialized as is
+                let nested = matches!(variant.kind, abi::EventFieldKind::Nested);
+                if nested && *k.next()? != starknet_keccak(variant.name.as_bytes()) {
+                    return None;
+                }
+
+                let mut event = self.event(&variant.r#type, &mut k, &mut d)?;
+                if nested {
+                    event.name = variant.name.clone();
+                }
+
+                (*keys, *data) = (k, d);
+                Some(event)
+            }),
+        }
+    }
+
+    fn value(&self, ty: &str, felts: &mut Felts<'_>) -> Option<DecodedValue> {
+        if ty == "()" {
+            return Some(DecodedValue::Array(Vec::new()));
+        }
+
+        if ty == U256_TYPE {
+            let (low, high) = (*felts.next()?, *felts.next()?);
+            return Some(DecodedValue::U256(join_u256(low, high)));
+        }
+
+        if ty == BYTE_ARRAY_TYPE {
+            return byte_array(felts).map(DecodedValue::String);
+        }
+
+        if let Some(elements) = ty.strip_prefix('(').and_then(|t| t.strip_suffix(')')) {
+            let values = split_types(elements).map(|t| self.value(t, felts));
+            return values.collect::<Option<Vec<_>>>().map(DecodedValue::Array);
+        }
+
+        if let Some(element) = array_element_type(ty) {
+            let len = length(felts)?;
+            let values = (0..len).map(|_| self.value(element, felts));
+            return values.collect::<Option<Vec<_>>>().map(DecodedValue::Array);
+        }
+
+        if let Some(members) = self.structs.get(ty) {
+            let fields = members.iter().map(|member| {
+                let value = self.value(&member.r#type, felts)?;
+                Some(DecodedField { name: member.name.clone(), value })
+            });
+            return fields.collect::<Option<Vec<_>>>().map(DecodedValue::Struct);
+        }
+
+        if let Some(variants) = self.enums.get(ty) {
+            let variant = variants.get(to_usize(felts.next()?)?)?;
+            let value = Box::new(self.value(&variant.r#type, felts)?);
+            return Some(DecodedValue::Enum { variant: variant.name.clone(), value });
+        }
+
+        felts.next().copied().map(DecodedValue::Felt)
+    }
+}
+
+fn to_usize(felt: &Felt) -> Option<usize> {
+    usize::try_from(u64::try_from(*felt).ok()?).ok()
+}
+
+/// Reads the length prefix of a serialized array, making sure there are enough felts left.
+fn length(felts: &mut Felts<'_>) -> Option<usize> {
+    let len = to_usize(felts.next()?)?;
+    (len <= felts.len()).then_some(len)
+}
+
+fn byte_array(felts: &mut Felts<'_>) -> Option<String> {
+    let mut bytes = Vec::new();
+
+    // full words are 31 bytes long
+    for _ in 0..length(felts)? {
+        bytes.extend_from_slice(&felts.next()?.to_bytes_be()[1..]);
+    }
+
+    let pending_word = felts.next()?.to_bytes_be();
+    let pending_len = to_usize(felts.next()?)?;
+    if pending_len > 31 {
+        return None;
+    }
+
+    bytes.extend_from_slice(&pending_word[32 - pending_len..]);
+
+    Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Returns the element type of an `Array` or `Span` type, eg `core::felt252` for
+/// `core::array::Array::<core::felt252>`.
+fn array_element_type(ty: &str) -> Option<&str> {
+    ["core::array::Array::<", "core::array::Span::<"]
+        .iter()
+        .find_map(|prefix| ty.strip_prefix(prefix))
+        .and_then(|ty| ty.strip_suffix('>'))
+}
+
+/// Splits a comma separated list of types, ignoring the commas of nested generic and tuple types.
+fn split_types(types: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0;
+    let mut start = 0;
+    let mut parts = Vec::new();
+
+    for (i, c) in types.char_indices() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&types[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    parts.push(&types[start..]);
+    parts.into_iter().map(str::trim).filter(|t| !t.is_empty())
+}
+
 #[cfg(test)]
 mod test {
     use assert_matches::assert_matches;
@@ -167,6 +424,102 @@ mod test {
         );
     }
 
+    const TOKEN_ABI: &str = r#"[
+        {
+            "type": "struct",
+            "name": "core::integer::u256",
+            "members": [
+                { "name": "low", "type": "core::integer::u128" },
+                { "name": "high", "type": "core::integer::u128" }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "token::Transfer",
+            "kind": "struct",
+            "members": [
+                { "name": "from", "type": "core::starknet::contract_address::ContractAddress", "kind": "key" },
+                { "name": "to", "type": "core::starknet::contract_address::ContractAddress", "kind": "key" },
+                { "name": "value", "type": "core::integer::u256", "kind": "data" }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "token::Metadata",
+            "kind": "struct",
+            "members": [
+                { "name": "name", "type": "core::byte_array::ByteArray", "kind": "data" },
+                { "name": "tags", "type": "core::array::Span::<core::felt252>", "kind": "data" }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "token::Event",
+            "kind": "enum",
+            "variants": [
+                { "name": "Transfer", "type": "token::Transfer", "kind": "nested" },
+                { "name": "Metadata", "type": "token::Metadata", "kind": "nested" }
+            ]
+        }
+    ]"#;
+
+    #[test]
+    fn decode_event_works() {
+        let items: Vec<abi::Item> = serde_json::from_str(TOKEN_ABI).unwrap();
+        let abi = items.iter().collect::<Vec<_>>();
+
+        let keys = [starknet_keccak(b"Transfer"), Felt::ONE, Felt::TWO];
+        let event = decode_event(&abi, &keys, &[Felt::from(100), Felt::ZERO]).unwrap();
+        assert_eq!(event.name, "Transfer");
+        assert_eq!(
+            event.fields,
+            vec![
+                DecodedField { name: "from".into(), value: DecodedValue::Felt(Felt::ONE) },
+                DecodedField { name: "to".into(), value: DecodedValue::Felt(Felt::TWO) },
+                DecodedField { name: "value".into(), value: DecodedValue::U256(U256::from(100)) },
+            ]
+        );
+
+        let keys = [starknet_keccak(b"Metadata")];
+        let data = [
+            // `ByteArray` "hello"
+            Felt::ZERO,
+            Felt::from_bytes_be_slice(b"hello"),
+            Felt::from(5),
+            // `Span<felt252>` [0xa, 0xb]
+            Felt::TWO,
+            Felt::from(0xa),
+            Felt::from(0xb),
+        ];
+        let event = decode_event(&abi, &keys, &data).unwrap();
+        assert_eq!(event.name, "Metadata");
+        assert_eq!(event.fields[0].value, DecodedValue::String("hello".into()));
+        assert_eq!(
+            event.fields[1].value,
+            DecodedValue::Array(vec![
+                DecodedValue::Felt(Felt::from(0xa)),
+                DecodedValue::Felt(Felt::from(0xb))
+            ])
+        );
+    }
+
+    #[test]
+    fn decode_unknown_event() {
+        let items: Vec<abi::Item> = serde_json::from_str(TOKEN_ABI).unwrap();
+        let abi = items.iter().collect::<Vec<_>>();
+
+        // no matching event in the abi
+        let keys = [starknet_keccak(b"Approval"), Felt::ONE, Felt::TWO];
+        assert!(decode_event(&abi, &keys, &[Felt::from(100), Felt::ZERO]).is_none());
+
+        // the data doesn't match the event layout
+        let keys = [starknet_keccak(b"Transfer"), Felt::ONE, Felt::TWO];
+        assert!(decode_event(&abi, &keys, &[Felt::from(100)]).is_none());
+
+        // no abi at all
+        assert!(decode_event(&[], &keys, &[Felt::from(100), Felt::ZERO]).is_none());
+    }
+
     #[test]
     fn parse_forked_token_works() {
         let forked_token = "FK_test_token";
@@ -43,3 +43,29 @@ pub struct TxFeeInfo {
     /// The type of fee used to pay for the transaction, depending on the transaction type.
     pub unit: PriceUnit,
 }
+
+impl TxFeeInfo {
+    /// Returns the fee breakdown of the transaction relative to the given max fee.
+    pub fn breakdown(&self, max_fee: u128) -> FeeBreakdown {
+        FeeBreakdown {
+            max_fee,
+            actual_fee: self.overall_fee,
+            refund: max_fee.saturating_sub(self.overall_fee),
+            unit: self.unit,
+        }
+    }
+}
+
+/// The fee actually charged for a transaction compared to the max fee set by the sender.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeBreakdown {
+    /// The max fee that the sender was willing to pay for the transaction.
+    pub max_fee: u128,
+    /// The fee charged for the transaction.
+    pub actual_fee: u128,
+    /// The unused portion of the max fee, ie `max_fee - actual_fee`.
+    pub refund: u128,
+    /// The unit of the fees.
+    pub unit: PriceUnit,
+}
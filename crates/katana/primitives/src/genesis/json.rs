@@ -144,6 +144,9 @@ impl<'de> Deserialize<'de> for ClassNameOrHash {
 #[serde(rename_all = "camelCase")]
 pub struct GenesisContractJson {
     pub class: Option<ClassNameOrHash>,
+    /// The fee token balance of the contract. See [`deserialize_balance`] for the accepted
+    /// formats.
+    #[serde(default, deserialize_with = "deserialize_balance")]
     pub balance: Option<U256>,
     pub nonce: Option<Felt>,
     pub storage: Option<BTreeMap<StorageKey, StorageValue>>,
@@ -154,6 +157,9 @@ pub struct GenesisContractJson {
 pub struct GenesisAccountJson {
     /// The public key of the account.
     pub public_key: Felt,
+    /// The fee token balance of the account. See [`deserialize_balance`] for the accepted
+    /// formats.
+    #[serde(default, deserialize_with = "deserialize_balance")]
     pub balance: Option<U256>,
     pub nonce: Option<Felt>,
     /// The class hash of the account contract. If not provided, the default account class is used.
@@ -622,6 +628,90 @@ fn class_artifact_at_path(
     Ok(content)
 }
 
+/// The number of decimals of a balance specified as an object, if not specified otherwise.
+pub const DEFAULT_BALANCE_DECIMALS: u8 = 18;
+
+/// Returns the number of decimals of the fee token with the given symbol, or `None` if it isn't
+/// one of the fee tokens.
+fn fee_token_decimals(symbol: &str) -> Option<u8> {
+    match symbol {
+        "ETH" | "STRK" => Some(18),
+        _ => None,
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BalanceJson {
+    Raw(U256),
+    Amount {
+        amount: String,
+        #[serde(default = "default_balance_decimals")]
+        decimals: u8,
+    },
+    WithUnit(String),
+}
+
+fn default_balance_decimals() -> u8 {
+    DEFAULT_BALANCE_DECIMALS
+}
+
+/// Deserializes a genesis balance, in the smallest unit of the token.
+///
+/// The balance can be specified either:
+/// - as a raw amount in the smallest unit of the token, eg `"0xD3C21BCECCEDA1000000"`,
+/// - as an amount of tokens followed by the symbol of a fee token, eg `"100 STRK"` or
+///   `"1.5 ETH"`, which is scaled using the decimals of the token. Other symbols are rejected,
+/// - as an object with the amount of tokens and the number of decimals of the token, eg
+///   `{ "amount": "100", "decimals": 6 }`.
+pub fn deserialize_balance<'de, D>(deserializer: D) -> Result<Option<U256>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    let Some(balance) = Option::<BalanceJson>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    let (amount, decimals) = match balance {
+        BalanceJson::Raw(value) => return Ok(Some(value)),
+        BalanceJson::Amount { amount, decimals } => (amount, decimals),
+        BalanceJson::WithUnit(value) => match value.split_whitespace().collect::<Vec<_>>()[..] {
+            [amount, symbol] => match fee_token_decimals(symbol) {
+                Some(decimals) => (amount.to_string(), decimals),
+                None => return Err(D::Error::custom(format!("unknown token symbol '{symbol}'"))),
+            },
+            _ => return Err(D::Error::custom(format!("invalid balance '{value}'"))),
+        },
+    };
+
+    parse_token_amount(&amount, decimals)
+        .map(Some)
+        .ok_or_else(|| D::Error::custom(format!("invalid balance amount '{amount}'")))
+}
+
+/// Converts a decimal amount of tokens, eg `1.5`, into the smallest unit of a token with the
+/// given number of decimals. Returns `None` if the amount is invalid, has more fractional digits
+/// than the token's decimals or overflows.
+fn parse_token_amount(amount: &str, decimals: u8) -> Option<U256> {
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if integer.is_empty() || !is_digits(integer) || !is_digits(fraction) {
+        return None;
+    }
+
+    let decimals = usize::from(decimals);
+    if fraction.len() > decimals {
+        return None;
+    }
+
+    // pad the fractional part with zeros up to the number of decimals
+    let digits = format!("{integer}{fraction:0<decimals$}");
+    U256::from_str_radix(&digits, 10).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use starknet::macros::felt;
@@ -1112,4 +1202,58 @@ mod tests {
             res.unwrap_err().to_string().contains(&format!("Class name '{name}' already exists"))
         )
     }
+
+    #[test]
+    fn balance_with_unit_and_decimals() {
+        let json = r#"
+        {
+            "publicKey": "0x1",
+            "balance": "1.5 STRK"
+        }
+        "#;
+        let account: GenesisAccountJson = serde_json::from_str(json).unwrap();
+        assert_eq!(account.balance, Some(U256::from(1_500_000_000_000_000_000_u128)));
+
+        let json = r#"
+        {
+            "balance": { "amount": "100", "decimals": 6 }
+        }
+        "#;
+        let contract: GenesisContractJson = serde_json::from_str(json).unwrap();
+        assert_eq!(contract.balance, Some(U256::from(100_000_000_u128)));
+
+        // raw balances are still in the smallest unit of the token
+        let json = r#"{ "balance": "0xD3C21BCECCEDA1000000" }"#;
+        let contract: GenesisContractJson = serde_json::from_str(json).unwrap();
+        assert_eq!(contract.balance, Some(U256::from_str("0xD3C21BCECCEDA1000000").unwrap()));
+
+        let json = r#"{ "balance": null }"#;
+        let contract: GenesisContractJson = serde_json::from_str(json).unwrap();
+        assert_eq!(contract.balance, None);
+
+        // more fractional digits than the token decimals
+        let json = r#"{ "balance": { "amount": "0.0000001", "decimals": 6 } }"#;
+        assert!(serde_json::from_str::<GenesisContractJson>(json).is_err());
+        let json = r#"{ "balance": "100 STRK ETH" }"#;
+        assert!(serde_json::from_str::<GenesisContractJson>(json).is_err());
+
+        // only the symbols of the fee tokens are known
+        let json = r#"{ "balance": "100 ETH" }"#;
+        let contract: GenesisContractJson = serde_json::from_str(json).unwrap();
+        assert_eq!(contract.balance, Some(U256::from(100_u128 * 10_u128.pow(18))));
+        let json = r#"{ "balance": "100 USDC" }"#;
+        assert!(serde_json::from_str::<GenesisContractJson>(json).is_err());
+    }
+
+    #[test]
+    fn parse_token_amounts() {
+        assert_eq!(parse_token_amount("100", 18), Some(U256::from(100_u128 * 10_u128.pow(18))));
+        assert_eq!(parse_token_amount("0.25", 2), Some(U256::from(25)));
+        assert_eq!(parse_token_amount("7", 0), Some(U256::from(7)));
+        assert_eq!(parse_token_amount("1.", 3), Some(U256::from(1000)));
+        assert_eq!(parse_token_amount("0.001", 2), None);
+        assert_eq!(parse_token_amount(".5", 2), None);
+        assert_eq!(parse_token_amount("-1", 2), None);
+        assert_eq!(parse_token_amount("1e3", 2), None);
+    }
 }
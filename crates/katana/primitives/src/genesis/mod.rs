@@ -18,11 +18,13 @@ use self::constant::{
     DEFAULT_ACCOUNT_CLASS_HASH, DEFAULT_LEGACY_ERC20_CLASS, DEFAULT_LEGACY_ERC20_CLASS_HASH,
     DEFAULT_LEGACY_UDC_CLASS, DEFAULT_LEGACY_UDC_CLASS_HASH,
 };
-use crate::block::{BlockHash, BlockNumber, GasPrices};
+use crate::block::{BlockHash, BlockNumber, GasPrices, Header};
 use crate::class::{ClassHash, ContractClass};
 use crate::contract::ContractAddress;
+use crate::da::L1DataAvailabilityMode;
 #[cfg(feature = "cartridge")]
 use crate::utils::class::parse_sierra_class;
+use crate::version::CURRENT_STARKNET_VERSION;
 use crate::Felt;
 
 /// Genesis block configuration.
@@ -66,6 +68,31 @@ impl Genesis {
         })
     }
 
+    /// Returns the header of the genesis block.
+    ///
+    /// The genesis block has no transactions, so the header only depends on the genesis block
+    /// configuration and not on the classes and allocations.
+    pub fn header(&self) -> Header {
+        Header {
+            state_diff_length: 0,
+            protocol_version: CURRENT_STARKNET_VERSION,
+            number: self.number,
+            timestamp: self.timestamp,
+            events_count: 0,
+            transaction_count: 0,
+            events_commitment: Felt::ZERO,
+            receipts_commitment: Felt::ZERO,
+            state_diff_commitment: Felt::ZERO,
+            transactions_commitment: Felt::ZERO,
+            state_root: self.state_root,
+            parent_hash: self.parent_hash,
+            l1_da_mode: L1DataAvailabilityMode::Calldata,
+            l1_gas_prices: self.gas_prices.clone(),
+            l1_data_gas_prices: self.gas_prices.clone(),
+            sequencer_address: self.sequencer_address,
+        }
+    }
+
     /// Returns an iterator over the genesis accounts. This will only return
     /// allocated account contracts.
     pub fn accounts(&self) -> impl Iterator<Item = (&ContractAddress, &GenesisAccountAlloc)> {
@@ -46,10 +46,36 @@ impl StateUpdates {
 
         len
     }
+
+    /// Merges the `other` state updates, which happened after `self`, into `self`. The values
+    /// updated in both take the value of `other`.
+    ///
+    /// A contract deployed in `self` whose class is replaced in `other` is only reported as
+    /// deployed, with the replacing class.
+    pub fn merge(&mut self, other: StateUpdates) {
+        self.nonce_updates.extend(other.nonce_updates);
+        self.deployed_contracts.extend(other.deployed_contracts);
+        self.declared_classes.extend(other.declared_classes);
+        self.deprecated_declared_classes.extend(other.deprecated_declared_classes);
+
+        for (address, class_hash) in other.replaced_classes {
+            match self.deployed_contracts.get_mut(&address) {
+                Some(deployed) => *deployed = class_hash,
+                None => {
+                    self.replaced_classes.insert(address, class_hash);
+                }
+            }
+        }
+
+        for (address, storage) in other.storage_updates {
+            self.storage_updates.entry(address).or_default().extend(storage);
+        }
+    }
 }
 
 /// State update with declared classes artifacts.
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StateUpdatesWithClasses {
     /// State updates.
     pub state_updates: StateUpdates,
@@ -103,3 +129,59 @@ pub fn compute_state_diff_hash(states: StateUpdates) -> Felt {
 
     hash::Poseidon::hash_array(&elements)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{address, felt};
+
+    #[test]
+    fn merge_state_updates() {
+        let contract = address!("0x1");
+        let other = address!("0x2");
+
+        let mut updates = StateUpdates {
+            nonce_updates: BTreeMap::from([(contract, felt!("0x1"))]),
+            storage_updates: BTreeMap::from([(
+                contract,
+                BTreeMap::from([(felt!("0x1"), felt!("0x1")), (felt!("0x2"), felt!("0x2"))]),
+            )]),
+            deployed_contracts: BTreeMap::from([(contract, felt!("0xc1"))]),
+            ..Default::default()
+        };
+
+        updates.merge(StateUpdates {
+            nonce_updates: BTreeMap::from([(contract, felt!("0x2"))]),
+            storage_updates: BTreeMap::from([
+                (contract, BTreeMap::from([(felt!("0x2"), felt!("0x3"))])),
+                (other, BTreeMap::from([(felt!("0x1"), felt!("0x1"))])),
+            ]),
+            deployed_contracts: BTreeMap::from([(other, felt!("0xc2"))]),
+            ..Default::default()
+        });
+
+        assert_eq!(updates.nonce_updates, BTreeMap::from([(contract, felt!("0x2"))]));
+        assert_eq!(
+            updates.storage_updates[&contract],
+            BTreeMap::from([(felt!("0x1"), felt!("0x1")), (felt!("0x2"), felt!("0x3"))])
+        );
+        assert_eq!(updates.storage_updates[&other], BTreeMap::from([(felt!("0x1"), felt!("0x1"))]));
+        assert_eq!(
+            updates.deployed_contracts,
+            BTreeMap::from([(contract, felt!("0xc1")), (other, felt!("0xc2"))])
+        );
+
+        // a contract deployed then upgraded is only reported as deployed, with its new class
+        let upgraded = address!("0x3");
+        updates.merge(StateUpdates {
+            replaced_classes: BTreeMap::from([(other, felt!("0xc3")), (upgraded, felt!("0xc4"))]),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            updates.deployed_contracts,
+            BTreeMap::from([(contract, felt!("0xc1")), (other, felt!("0xc3"))])
+        );
+        assert_eq!(updates.replaced_classes, BTreeMap::from([(upgraded, felt!("0xc4"))]));
+    }
+}
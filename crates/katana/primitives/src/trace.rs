@@ -18,6 +18,27 @@ pub struct ExecutionResources {
     pub builtin_instance_counter: BuiltinCounters,
 }
 
+impl ExecutionResources {
+    /// Returns the resources left after removing `other` from `self`, saturating at zero.
+    pub fn saturating_sub(&self, other: &ExecutionResources) -> ExecutionResources {
+        let builtins = self
+            .builtin_instance_counter
+            .0
+            .iter()
+            .map(|(builtin, count)| {
+                let used = other.builtin_instance_counter.0.get(builtin).copied().unwrap_or(0);
+                (*builtin, count.saturating_sub(used))
+            })
+            .collect::<HashMap<_, _>>();
+
+        ExecutionResources {
+            n_steps: self.n_steps.saturating_sub(other.n_steps),
+            n_memory_holes: self.n_memory_holes.saturating_sub(other.n_memory_holes),
+            builtin_instance_counter: BuiltinCounters::from(builtins),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -124,11 +145,26 @@ pub struct CallInfo {
     pub failed: bool,
 }
 
+impl CallInfo {
+    /// Returns the resources used by the call itself, excluding the ones used by its inner calls.
+    ///
+    /// The [`execution_resources`](CallInfo::execution_resources) of a call include the resources
+    /// of all of its inner calls. This is useful to find which call of a trace is the most
+    /// expensive one.
+    pub fn self_execution_resources(&self) -> ExecutionResources {
+        self.inner_calls.iter().fold(self.execution_resources.clone(), |resources, call| {
+            resources.saturating_sub(&call.execution_resources)
+        })
+    }
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[serde(transparent)]
-pub struct BuiltinCounters(HashMap<BuiltinName, usize>);
+pub struct BuiltinCounters(
+    #[serde(serialize_with = "serialize_sorted")] HashMap<BuiltinName, usize>,
+);
 
 impl BuiltinCounters {
     /// Returns the number of instances of the `output` builtin, if any.
@@ -196,6 +232,17 @@ impl BuiltinCounters {
     }
 }
 
+/// Serializes the counters sorted by builtin name, so that the serialized form doesn't depend on
+/// the iteration order of the underlying map.
+fn serialize_sorted<S>(map: &HashMap<BuiltinName, usize>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    let mut entries = map.iter().collect::<Vec<_>>();
+    entries.sort_by_key(|(builtin, _)| builtin.to_str());
+    serializer.collect_map(entries)
+}
+
 impl<T: Into<usize>> From<HashMap<BuiltinName, T>> for BuiltinCounters {
     fn from(map: HashMap<BuiltinName, T>) -> Self {
         // Filter out the builtins with 0 count.
@@ -247,4 +294,60 @@ mod tests {
         assert_eq!(counters.pedersen(), Some(2));
         assert_eq!(counters.ecdsa(), None);
     }
+
+    #[test]
+    fn test_call_self_execution_resources() {
+        let resources = |n_steps, pedersen: usize, poseidon: usize| ExecutionResources {
+            n_steps,
+            n_memory_holes: 0,
+            builtin_instance_counter: BuiltinCounters::from(HashMap::from([
+                (BuiltinName::pedersen, pedersen),
+                (BuiltinName::poseidon, poseidon),
+            ])),
+        };
+
+        let inner_a = CallInfo { execution_resources: resources(30, 2, 0), ..Default::default() };
+        let inner_b = CallInfo { execution_resources: resources(50, 0, 4), ..Default::default() };
+        let call = CallInfo {
+            execution_resources: resources(100, 3, 4),
+            inner_calls: vec![inner_a.clone(), inner_b],
+            ..Default::default()
+        };
+
+        assert_eq!(call.self_execution_resources(), resources(20, 1, 0));
+        assert_eq!(call.self_execution_resources().builtin_instance_counter.poseidon(), None);
+        // a call without inner calls uses all of its resources itself
+        assert_eq!(inner_a.self_execution_resources(), inner_a.execution_resources);
+    }
+
+    #[test]
+    fn test_builtin_counters_serialization_is_sorted() {
+        let builtins = [
+            BuiltinName::poseidon,
+            BuiltinName::output,
+            BuiltinName::range_check,
+            BuiltinName::pedersen,
+            BuiltinName::bitwise,
+            BuiltinName::ec_op,
+            BuiltinName::keccak,
+        ];
+
+        let forward = BuiltinCounters::from(
+            builtins.iter().enumerate().map(|(i, b)| (*b, i + 1)).collect::<HashMap<_, _>>(),
+        );
+        let reverse = BuiltinCounters::from(
+            builtins.iter().enumerate().rev().map(|(i, b)| (*b, i + 1)).collect::<HashMap<_, _>>(),
+        );
+
+        let serialized = serde_json::to_string(&forward).unwrap();
+        assert_eq!(serialized, serde_json::to_string(&reverse).unwrap());
+
+        let mut names = builtins.iter().map(|b| b.to_str()).collect::<Vec<_>>();
+        names.sort();
+        let positions = names.iter().map(|name| serialized.find(name).unwrap()).collect::<Vec<_>>();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+
+        let deserialized: BuiltinCounters = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, forward);
+    }
 }
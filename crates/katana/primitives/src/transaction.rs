@@ -83,6 +83,32 @@ impl Tx {
             Tx::Deploy(tx) => tx.version,
         }
     }
+
+    /// Returns the maximum fee that the sender is willing to pay for the transaction.
+    ///
+    /// For v3 transactions, this is the L1 gas max amount multiplied by its max price per unit.
+    /// Returns `None` for transactions that don't pay any fee.
+    pub fn max_fee(&self) -> Option<u128> {
+        let max_fee = match self {
+            Tx::Invoke(InvokeTx::V0(tx)) => tx.max_fee,
+            Tx::Invoke(InvokeTx::V1(tx)) => tx.max_fee,
+            Tx::Invoke(InvokeTx::V3(tx)) => max_fee_from_bounds(&tx.resource_bounds),
+            Tx::Declare(DeclareTx::V0(tx)) => tx.max_fee,
+            Tx::Declare(DeclareTx::V1(tx)) => tx.max_fee,
+            Tx::Declare(DeclareTx::V2(tx)) => tx.max_fee,
+            Tx::Declare(DeclareTx::V3(tx)) => max_fee_from_bounds(&tx.resource_bounds),
+            Tx::DeployAccount(DeployAccountTx::V1(tx)) => tx.max_fee,
+            Tx::DeployAccount(DeployAccountTx::V3(tx)) => max_fee_from_bounds(&tx.resource_bounds),
+            Tx::L1Handler(_) | Tx::Deploy(_) => return None,
+        };
+
+        Some(max_fee)
+    }
+}
+
+fn max_fee_from_bounds(bounds: &ResourceBoundsMapping) -> u128 {
+    let l1_gas = &bounds.l1_gas;
+    u128::from(l1_gas.max_amount).saturating_mul(l1_gas.max_price_per_unit)
 }
 
 #[derive(Debug)]
@@ -132,6 +158,20 @@ impl ExecutableTx {
         }
     }
 
+    /// Returns the calldata of the transaction. For a deploy account transaction, this is the
+    /// constructor calldata. Declare transactions don't have any calldata.
+    pub fn calldata(&self) -> &[Felt] {
+        match self {
+            ExecutableTx::Invoke(InvokeTx::V0(tx)) => &tx.calldata,
+            ExecutableTx::Invoke(InvokeTx::V1(tx)) => &tx.calldata,
+            ExecutableTx::Invoke(InvokeTx::V3(tx)) => &tx.calldata,
+            ExecutableTx::L1Handler(tx) => &tx.calldata,
+            ExecutableTx::Declare(_) => &[],
+            ExecutableTx::DeployAccount(DeployAccountTx::V1(tx)) => &tx.constructor_calldata,
+            ExecutableTx::DeployAccount(DeployAccountTx::V3(tx)) => &tx.constructor_calldata,
+        }
+    }
+
     pub fn r#type(&self) -> TxType {
         match self {
             ExecutableTx::Invoke(_) => TxType::Invoke,
@@ -510,6 +550,14 @@ pub struct L1HandlerTx {
 }
 
 impl L1HandlerTx {
+    /// Returns the payload of the L1 message that this transaction consumes.
+    ///
+    /// The first element of the calldata is the address of the L1 sender, and the rest is the
+    /// message payload.
+    pub fn message_payload(&self) -> &[Felt] {
+        self.calldata.get(1..).unwrap_or_default()
+    }
+
     /// Compute the hash of the transaction.
     pub fn calculate_hash(&self) -> TxHash {
         compute_l1_handler_tx_hash(
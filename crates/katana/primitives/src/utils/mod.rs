@@ -1,4 +1,5 @@
 use alloy_primitives::U256;
+use starknet::core::utils::starknet_keccak;
 
 use crate::Felt;
 
@@ -14,6 +15,25 @@ pub fn split_u256(value: U256) -> (Felt, Felt) {
     (Felt::from(low_u128), Felt::from(high_u128))
 }
 
+/// Joins the low and high 128-bit parts of a [U256], as returned by [split_u256], back into a
+/// [U256].
+pub fn join_u256(low: Felt, high: Felt) -> U256 {
+    let low = U256::from_be_bytes(low.to_bytes_be());
+    let high = U256::from_be_bytes(high.to_bytes_be());
+    (high << 128) | low
+}
+
+/// Computes the entry point selector of the given function name, ie the starknet keccak of the
+/// name masked to 250 bits.
+///
+/// The `__default__` and `__l1_default__` entry points have a selector of zero.
+pub fn get_selector_from_name(name: &str) -> Felt {
+    match name {
+        "__default__" | "__l1_default__" => Felt::ZERO,
+        _ => starknet_keccak(name.as_bytes()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,4 +50,26 @@ mod tests {
         assert_eq!(low, Felt::from(u128::MAX));
         assert_eq!(high, Felt::from(u128::MAX));
     }
+
+    #[test]
+    fn test_join_u256() {
+        let value = U256::from_be_slice(&[0xab; 24]);
+        let (low, high) = split_u256(value);
+        assert_eq!(join_u256(low, high), value);
+    }
+
+    #[test]
+    fn test_get_selector_from_name() {
+        use starknet::macros::felt;
+
+        assert_eq!(
+            get_selector_from_name("__execute__"),
+            felt!("0x15d40a3d6ca2ac30f4031e42be28da9b056fef9bb7357ac5e85627ee876e5ad")
+        );
+        assert_eq!(
+            get_selector_from_name("transfer"),
+            felt!("0x83afd3f4caedc6eebf44246fe54e38c95e3179a5ec9ea81740eca5b482d12e")
+        );
+        assert_eq!(get_selector_from_name("__default__"), Felt::ZERO);
+    }
 }
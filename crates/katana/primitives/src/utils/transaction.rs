@@ -1,6 +1,7 @@
 use alloy_primitives::B256;
+use num_traits::ToPrimitive;
 use starknet::core::crypto::compute_hash_on_elements;
-use starknet::core::types::{EthAddress, MsgToL1, MsgToL2};
+use starknet::core::types::{Call, EthAddress, MsgToL1, MsgToL2};
 use starknet_crypto::poseidon_hash_many;
 
 use crate::da::DataAvailabilityMode;
@@ -308,6 +309,68 @@ pub fn compute_l1_to_l2_message_hash(
     B256::from_slice(msg.hash().as_bytes())
 }
 
+/// Decodes the calldata of an account's `__execute__` entrypoint into its individual calls.
+///
+/// The calldata is expected to follow the Cairo 1 `Array<Call>` serialization, ie:
+/// `[calls_len, to, selector, calldata_len, ...calldata, to, selector, ...]`.
+///
+/// Returns `None` if the calldata is not a valid encoding of a list of calls.
+pub fn decode_execute_calldata(calldata: &[Felt]) -> Option<Vec<Call>> {
+    let (len, mut rest) = calldata.split_first()?;
+    let len = len.to_usize()?;
+
+    let mut calls = Vec::with_capacity(len.min(rest.len()));
+    for _ in 0..len {
+        let [to, selector, calldata_len, remaining @ ..] = rest else { return None };
+        let calldata_len = calldata_len.to_usize()?;
+
+        if remaining.len() < calldata_len {
+            return None;
+        }
+
+        let (calldata, remaining) = remaining.split_at(calldata_len);
+        calls.push(Call { to: *to, selector: *selector, calldata: calldata.to_vec() });
+        rest = remaining;
+    }
+
+    // the whole calldata must be consumed
+    rest.is_empty().then_some(calls)
+}
+
+/// Decodes the calldata of a Cairo 0 account's `__execute__` entrypoint into its individual calls.
+///
+/// The calldata is expected to follow the Cairo 0 serialization, where the calls are described by
+/// an array of `(to, selector, data_offset, data_len)` followed by their concatenated calldata, ie:
+/// `[call_array_len, to, selector, data_offset, data_len, ..., calldata_len, ...calldata]`.
+///
+/// Returns `None` if the calldata is not a valid encoding of a list of calls.
+pub fn decode_legacy_execute_calldata(calldata: &[Felt]) -> Option<Vec<Call>> {
+    let (len, rest) = calldata.split_first()?;
+    let call_array_len = len.to_usize()?.checked_mul(4)?;
+
+    if rest.len() < call_array_len {
+        return None;
+    }
+
+    let (call_array, rest) = rest.split_at(call_array_len);
+    let (data_len, data) = rest.split_first()?;
+
+    // the whole calldata must be consumed
+    if data_len.to_usize()? != data.len() {
+        return None;
+    }
+
+    call_array
+        .chunks_exact(4)
+        .map(|entry| {
+            let offset = entry[2].to_usize()?;
+            let end = offset.checked_add(entry[3].to_usize()?)?;
+            let calldata = data.get(offset..end)?.to_vec();
+            Some(Call { to: entry[0], selector: entry[1], calldata })
+        })
+        .collect()
+}
+
 fn encode_gas_bound(name: &[u8], bound: &ResourceBounds) -> Felt {
     let mut buffer = [0u8; 32];
     let (remainder, max_price) = buffer.split_at_mut(128 / 8);
@@ -344,7 +407,6 @@ fn encode_da_mode(
 
 #[cfg(test)]
 mod tests {
-    use num_traits::ToPrimitive;
     use starknet::macros::{felt, short_string};
 
     use super::*;
@@ -666,4 +728,83 @@ mod tests {
 
         assert_eq!(actual_hash, expected_hash);
     }
+
+    #[test]
+    fn test_decode_execute_calldata() {
+        let calldata = vec![
+            felt!("0x2"),
+            // first call
+            felt!("0x1"),
+            felt!("0xa"),
+            felt!("0x2"),
+            felt!("0x11"),
+            felt!("0x12"),
+            // second call
+            felt!("0x2"),
+            felt!("0xb"),
+            felt!("0x0"),
+        ];
+
+        let calls = decode_execute_calldata(&calldata).unwrap();
+
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].to, felt!("0x1"));
+        assert_eq!(calls[0].selector, felt!("0xa"));
+        assert_eq!(calls[0].calldata, vec![felt!("0x11"), felt!("0x12")]);
+        assert_eq!(calls[1].to, felt!("0x2"));
+        assert_eq!(calls[1].selector, felt!("0xb"));
+        assert!(calls[1].calldata.is_empty());
+
+        // calldata length exceeds the remaining data
+        assert!(decode_execute_calldata(&[felt!("0x1"), felt!("0x1"), felt!("0xa"), felt!("0x3")])
+            .is_none());
+        // trailing data that doesn't belong to any call
+        assert!(decode_execute_calldata(&[felt!("0x0"), felt!("0x1")]).is_none());
+        assert!(decode_execute_calldata(&[]).is_none());
+    }
+
+    #[test]
+    fn test_decode_legacy_execute_calldata() {
+        let calldata = vec![
+            felt!("0x2"),
+            // call array
+            felt!("0x1"),
+            felt!("0xa"),
+            felt!("0x0"),
+            felt!("0x2"),
+            felt!("0x2"),
+            felt!("0xb"),
+            felt!("0x2"),
+            felt!("0x0"),
+            // calldata
+            felt!("0x2"),
+            felt!("0x11"),
+            felt!("0x12"),
+        ];
+
+        let calls = decode_legacy_execute_calldata(&calldata).unwrap();
+
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].to, felt!("0x1"));
+        assert_eq!(calls[0].selector, felt!("0xa"));
+        assert_eq!(calls[0].calldata, vec![felt!("0x11"), felt!("0x12")]);
+        assert_eq!(calls[1].to, felt!("0x2"));
+        assert_eq!(calls[1].selector, felt!("0xb"));
+        assert!(calls[1].calldata.is_empty());
+
+        // call data range exceeds the calldata
+        let calldata = [
+            felt!("0x1"),
+            felt!("0x1"),
+            felt!("0xa"),
+            felt!("0x1"),
+            felt!("0x1"),
+            felt!("0x1"),
+            felt!("0x11"),
+        ];
+        assert!(decode_legacy_execute_calldata(&calldata).is_none());
+        // calldata length doesn't match the remaining data
+        assert!(decode_legacy_execute_calldata(&[felt!("0x0"), felt!("0x1")]).is_none());
+        assert!(decode_legacy_execute_calldata(&[]).is_none());
+    }
 }
@@ -1,7 +1,17 @@
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
-use katana_primitives::Felt;
+use katana_primitives::block::{BlockHash, BlockIdOrTag};
+use katana_primitives::contract::Nonce;
+use katana_primitives::transaction::TxHash;
+use katana_primitives::{ContractAddress, Felt};
 use katana_rpc_types::account::Account;
+use katana_rpc_types::message::MsgFromL1;
+use katana_rpc_types::receipt::FeeBreakdown;
+use katana_rpc_types::state_dump::StateDump;
+use katana_rpc_types::trace::{CallResources, TraceEvent};
+use katana_rpc_types::trie::ContractStorageKeys;
+use katana_rpc_types::world::WorldModel;
+use starknet::core::types::{Hash256, MsgToL1, StorageEntry};
 
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "dev"))]
 #[cfg_attr(feature = "client", rpc(client, server, namespace = "dev"))]
@@ -9,9 +19,20 @@ pub trait DevApi {
     #[method(name = "generateBlock")]
     async fn generate_block(&self) -> RpcResult<()>;
 
+    /// Mines `count` blocks (defaults to 1). The pending transactions, if any, are included in the
+    /// first block and the rest are empty blocks. If `interval` is specified, the timestamp of
+    /// each subsequent block is advanced by `interval` seconds.
+    ///
+    /// Returns the hashes of the mined blocks. At most 1000 blocks can be mined at once.
+    #[method(name = "mine")]
+    async fn mine(&self, count: Option<u64>, interval: Option<u64>) -> RpcResult<Vec<BlockHash>>;
+
     #[method(name = "nextBlockTimestamp")]
     async fn next_block_timestamp(&self) -> RpcResult<()>;
 
+    /// Sets the timestamp of the next block. The timestamp may be older than the one of the latest
+    /// block, which allows testing time-dependent logic against past times, so block timestamps
+    /// aren't guaranteed to be monotonic on chains where this method is used.
     #[method(name = "setNextBlockTimestamp")]
     async fn set_next_block_timestamp(&self, timestamp: u64) -> RpcResult<()>;
 
@@ -24,4 +45,97 @@ pub trait DevApi {
 
     #[method(name = "predeployedAccounts")]
     async fn predeployed_accounts(&self) -> RpcResult<Vec<Account>>;
+
+    /// Returns the L2 to L1 messages sent by the mined transactions that haven't been consumed
+    /// yet, in the order they were sent.
+    #[method(name = "getPendingL1Messages")]
+    async fn get_pending_l1_messages(&self) -> RpcResult<Vec<MsgToL1>>;
+
+    /// Consumes a pending L2 to L1 message, as the `consumeMessageFromL2` function of the core
+    /// contract on L1 would. `message_hash` is the hash of the message as computed on L1.
+    ///
+    /// If the same message was sent multiple times, only one of them is consumed.
+    #[method(name = "consumeL1Message")]
+    async fn consume_l1_message(&self, message_hash: Hash256) -> RpcResult<()>;
+
+    /// Sends a message from L1 to L2 by adding the corresponding L1 handler transaction to the
+    /// pool, as if the message was sent to the core contract on L1.
+    ///
+    /// Returns the hash of the L1 handler transaction.
+    #[method(name = "sendL1Message")]
+    async fn send_l1_message(&self, message: MsgFromL1) -> RpcResult<TxHash>;
+
+    /// Returns the events emitted during the execution of a transaction, including the
+    /// validation and fee transfer, in emission order. Each event is decoded against the event
+    /// ABI of the contract that emitted it, when available. Transactions of the pending block are
+    /// supported as well.
+    #[method(name = "getTransactionEvents")]
+    async fn get_transaction_events(&self, transaction_hash: TxHash) -> RpcResult<Vec<TraceEvent>>;
+
+    /// Returns the fee charged for a transaction along with the max fee set by its sender and the
+    /// refunded difference, which can be used to tune fee estimates. Transactions of the pending
+    /// block are supported as well.
+    ///
+    /// Fails for transactions that don't pay any fee, ie L1 handler and deploy transactions.
+    #[method(name = "getTransactionFee")]
+    async fn get_transaction_fee(&self, transaction_hash: TxHash) -> RpcResult<FeeBreakdown>;
+
+    /// Returns the call tree of a transaction, ie its validation, execution and fee transfer
+    /// calls, with the resources used by each call both with and without its inner calls. This
+    /// can be used to find the most expensive calls of a transaction. Transactions of the pending
+    /// block are supported as well.
+    #[method(name = "getTransactionResources")]
+    async fn get_transaction_resources(
+        &self,
+        transaction_hash: TxHash,
+    ) -> RpcResult<Vec<CallResources>>;
+
+    /// Returns the models registered in the Dojo world deployed at `world_address`, in
+    /// registration order, along with the schema of their currently deployed version.
+    #[method(name = "getWorldSchema")]
+    async fn get_world_schema(&self, world_address: ContractAddress) -> RpcResult<Vec<WorldModel>>;
+
+    /// Returns the non-zero storage entries of the contract deployed at `contract_address` in the
+    /// pending state, sorted by storage key.
+    ///
+    /// When forking, only the entries written since the fork are returned, as the storage of the
+    /// forked chain can't be enumerated.
+    #[method(name = "getContractStorage")]
+    async fn get_contract_storage(
+        &self,
+        contract_address: ContractAddress,
+    ) -> RpcResult<Vec<StorageEntry>>;
+
+    /// Returns the values of the storage at the given contracts and keys, in the same order as
+    /// they are requested. All the values are read from the state of the same block.
+    #[method(name = "getStoragesAt")]
+    async fn get_storages_at(
+        &self,
+        block_id: BlockIdOrTag,
+        contracts_storage_keys: Vec<ContractStorageKeys>,
+    ) -> RpcResult<Vec<Vec<Felt>>>;
+
+    /// Returns the full state of the latest block, ie the contracts, their storage and nonces,
+    /// and the declared classes, independently of the block history.
+    ///
+    /// The dump can be loaded into another node using `dev_loadState`.
+    #[method(name = "dumpState")]
+    async fn dump_state(&self) -> RpcResult<StateDump>;
+
+    /// Loads a state dumped with `dev_dumpState` on top of the latest state. The state of the
+    /// contracts that aren't part of the dump is left untouched.
+    ///
+    /// The state isn't applied to the pending block. Instead, a new block without transactions is
+    /// mined to hold it, so the latest block number is incremented by one. Fails if there are
+    /// pending transactions.
+    #[method(name = "loadState")]
+    async fn load_state(&self, state: StateDump) -> RpcResult<()>;
+
+    /// Sets the nonce of a deployed contract.
+    ///
+    /// The nonce isn't updated in the pending block. Instead, a new block without transactions is
+    /// mined that only updates it, so the latest block number is incremented by one and the change
+    /// shows up in the state update of that block. Fails if there are pending transactions.
+    #[method(name = "setNonce")]
+    async fn set_nonce(&self, contract_address: ContractAddress, nonce: Nonce) -> RpcResult<()>;
 }
@@ -3,7 +3,7 @@
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use katana_primitives::block::{BlockIdOrTag, BlockNumber};
-use katana_primitives::class::ClassHash;
+use katana_primitives::class::{CasmContractClass, ClassHash};
 use katana_primitives::transaction::TxHash;
 use katana_primitives::{ContractAddress, Felt};
 use katana_rpc_types::block::{
@@ -127,6 +127,10 @@ pub trait StarknetApi {
         contract_address: Felt,
     ) -> RpcResult<RpcContractClass>;
 
+    /// Get the compiled CASM code resulting from compiling the Sierra class with the given hash.
+    #[method(name = "getCompiledCasm")]
+    async fn get_compiled_casm(&self, class_hash: ClassHash) -> RpcResult<CasmContractClass>;
+
     /// Get the number of transactions in a block given a block id.
     #[method(name = "getBlockTransactionCount")]
     async fn get_block_transaction_count(&self, block_id: BlockIdOrTag) -> RpcResult<BlockTxCount>;
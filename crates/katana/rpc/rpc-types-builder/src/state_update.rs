@@ -40,11 +40,12 @@ where
             let block_num = BlockNumberProvider::block_number_by_hash(&self.provider, block_hash)?
                 .expect("should exist if block exists");
 
-            match block_num {
-                0 => Felt::ZERO,
-                _ => self
+            // the genesis block has no parent
+            match block_num.checked_sub(1) {
+                None => Felt::ZERO,
+                Some(parent_num) => self
                     .provider
-                    .historical((block_num - 1).into())?
+                    .historical(parent_num.into())?
                     .expect("should exist if block exists")
                     .state_root()?,
             }
@@ -7,6 +7,42 @@ use jsonrpsee::types::ErrorObject;
 pub enum DevApiError {
     #[error("Wait for pending transactions.")]
     PendingTransactions,
+    #[error("Failed to mine block.")]
+    BlockMiningFailed,
+    #[error("Failed to get L1 messages.")]
+    FailedToGetMessages,
+    #[error("Failed to send L1 message.")]
+    FailedToSendMessage,
+    #[error("Transaction not found.")]
+    TransactionNotFound,
+    #[error("Failed to get transaction events.")]
+    FailedToGetEvents,
+    #[error("L1 message not found.")]
+    MessageNotFound,
+    #[error("Contract not found.")]
+    ContractNotFound,
+    #[error("Failed to get world schema.")]
+    FailedToGetWorldSchema,
+    #[error("Failed to get contract storage.")]
+    FailedToGetStorage,
+    #[error("Failed to dump state.")]
+    FailedToDumpState,
+    #[error("Failed to load state.")]
+    FailedToLoadState,
+    #[error("Failed to set nonce.")]
+    FailedToSetNonce,
+    #[error("Too many blocks requested.")]
+    TooManyBlocks,
+    #[error("Block not found.")]
+    BlockNotFound,
+    #[error("Failed to get transaction fee.")]
+    FailedToGetFee,
+    #[error("Transaction doesn't pay any fee.")]
+    TransactionWithoutFee,
+    #[error("Failed to get transaction resources.")]
+    FailedToGetResources,
+    #[error("Block timestamp overflows.")]
+    TimestampOverflow,
 }
 
 impl From<DevApiError> for Error {
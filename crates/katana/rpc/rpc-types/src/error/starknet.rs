@@ -96,6 +96,11 @@ pub enum StarknetApiError {
         /// The total number of keys that is being requested.
         total: u64,
     },
+    #[error("Transaction pool is full")]
+    PoolFull {
+        /// The maximum number of transactions held by the pool.
+        max_size: u64,
+    },
 }
 
 impl StarknetApiError {
@@ -132,6 +137,7 @@ impl StarknetApiError {
             StarknetApiError::UnsupportedContractClassVersion => 62,
             StarknetApiError::UnexpectedError { .. } => 63,
             StarknetApiError::ProofLimitExceeded { .. } => 1000,
+            StarknetApiError::PoolFull { .. } => 1001,
         }
     }
 
@@ -145,6 +151,7 @@ impl StarknetApiError {
             | StarknetApiError::PageSizeTooBig { .. }
             | StarknetApiError::UnexpectedError { .. }
             | StarknetApiError::ProofLimitExceeded { .. }
+            | StarknetApiError::PoolFull { .. }
             | StarknetApiError::StorageProofNotSupported { .. }
             | StarknetApiError::TransactionExecutionError { .. } => Some(serde_json::json!(self)),
 
@@ -189,6 +196,14 @@ impl From<PoolError> for StarknetApiError {
     fn from(error: PoolError) -> Self {
         match error {
             PoolError::InvalidTransaction(err) => err.into(),
+            PoolError::DuplicateTransaction { .. } => StarknetApiError::DuplicateTransaction,
+            PoolError::DuplicateNonce { .. } => {
+                StarknetApiError::InvalidTransactionNonce { reason: error.to_string() }
+            }
+            PoolError::PoolFull { max_size } => {
+                StarknetApiError::PoolFull { max_size: max_size as u64 }
+            }
+            PoolError::QueueFull => StarknetApiError::UnexpectedError { reason: error.to_string() },
             PoolError::Internal(err) => {
                 StarknetApiError::UnexpectedError { reason: err.to_string() }
             }
@@ -209,6 +224,10 @@ impl From<Box<InvalidTransactionError>> for StarknetApiError {
             InvalidTransactionError::ValidationFailure { error, .. } => {
                 Self::ValidationFailure { reason: error.to_string() }
             }
+            InvalidTransactionError::CalldataTooLong { .. }
+            | InvalidTransactionError::EntryPointNotFound { .. } => {
+                Self::ValidationFailure { reason: error.to_string() }
+            }
         }
     }
 }
@@ -411,6 +430,16 @@ mod tests {
          	"total": 10
         }),
     )]
+    #[case(
+    	StarknetApiError::PoolFull {
+     		max_size: 10000
+     	},
+      	1001,
+       	"Transaction pool is full",
+        json!({
+        	"max_size": 10000
+        }),
+    )]
     fn test_starknet_api_error_to_error_conversion_data_some(
         #[case] starknet_error: StarknetApiError,
         #[case] expected_code: i32,
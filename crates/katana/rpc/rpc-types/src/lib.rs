@@ -10,10 +10,12 @@ pub mod error;
 pub mod event;
 pub mod message;
 pub mod receipt;
+pub mod state_dump;
 pub mod state_update;
 pub mod trace;
 pub mod transaction;
 pub mod trie;
+pub mod world;
 
 use std::ops::Deref;
 
@@ -7,6 +7,12 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MsgFromL1(starknet::core::types::MsgFromL1);
 
+impl From<starknet::core::types::MsgFromL1> for MsgFromL1 {
+    fn from(value: starknet::core::types::MsgFromL1) -> Self {
+        Self(value)
+    }
+}
+
 impl MsgFromL1 {
     pub fn into_tx_with_chain_id(self, chain_id: ChainId) -> L1HandlerTx {
         // Set the L1 to L2 message nonce to 0, because this is just used
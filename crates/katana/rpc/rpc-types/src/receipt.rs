@@ -2,6 +2,7 @@ use katana_primitives::block::FinalityStatus;
 use katana_primitives::fee::{PriceUnit, TxFeeInfo};
 use katana_primitives::receipt::{MessageToL1, Receipt};
 use katana_primitives::transaction::TxHash;
+use katana_primitives::Felt;
 use serde::{Deserialize, Serialize};
 pub use starknet::core::types::ReceiptBlock;
 use starknet::core::types::{
@@ -136,6 +137,36 @@ impl TxReceiptWithBlockInfo {
     }
 }
 
+/// The fee charged for a transaction compared to the max fee set by its sender, which isn't part
+/// of the receipts of the Starknet spec.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeBreakdown {
+    /// The max fee that the sender was willing to pay for the transaction.
+    pub max_fee: Felt,
+    /// The fee charged for the transaction, ie the `actual_fee` of its receipt.
+    pub actual_fee: Felt,
+    /// The unused portion of the max fee, ie `max_fee - actual_fee`.
+    pub refund: Felt,
+    /// The unit of the fees.
+    pub unit: starknet::core::types::PriceUnit,
+}
+
+impl From<katana_primitives::fee::FeeBreakdown> for FeeBreakdown {
+    fn from(value: katana_primitives::fee::FeeBreakdown) -> Self {
+        let unit = match value.unit {
+            PriceUnit::Wei => starknet::core::types::PriceUnit::Wei,
+            PriceUnit::Fri => starknet::core::types::PriceUnit::Fri,
+        };
+
+        Self {
+            max_fee: value.max_fee.into(),
+            actual_fee: value.actual_fee.into(),
+            refund: value.refund.into(),
+            unit,
+        }
+    }
+}
+
 struct MsgToL1(starknet::core::types::MsgToL1);
 
 impl From<MessageToL1> for MsgToL1 {
@@ -204,3 +235,149 @@ fn to_rpc_fee(fee: TxFeeInfo) -> FeePayment {
 
     FeePayment { amount: fee.overall_fee.into(), unit }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use alloy_primitives::B256;
+    use katana_primitives::receipt::{Event, InvokeTxReceipt, L1HandlerTxReceipt};
+    use katana_primitives::trace::{
+        BuiltinName, ExecutionResources as VmResources, L1Gas, TxResources,
+    };
+    use katana_primitives::{address, felt, ContractAddress};
+
+    use super::*;
+
+    fn fee() -> TxFeeInfo {
+        TxFeeInfo { gas_consumed: 10, gas_price: 2, overall_fee: 20, unit: PriceUnit::Fri }
+    }
+
+    #[test]
+    fn invoke_receipt_conversion() {
+        let receipt = Receipt::Invoke(InvokeTxReceipt {
+            fee: fee(),
+            events: vec![Event {
+                from_address: address!("0x1"),
+                keys: vec![felt!("0x2")],
+                data: vec![felt!("0x3")],
+            }],
+            messages_sent: vec![MessageToL1 {
+                from_address: address!("0x1"),
+                to_address: felt!("0x4"),
+                payload: vec![felt!("0x5"), felt!("0x6")],
+            }],
+            revert_error: None,
+            execution_resources: Default::default(),
+        });
+
+        let tx_hash = felt!("0x1337");
+        let TxReceipt(rpc_receipt) = TxReceipt::new(tx_hash, FinalityStatus::AcceptedOnL1, receipt);
+
+        let TransactionReceipt::Invoke(rpc_receipt) = rpc_receipt else {
+            panic!("expected an invoke receipt")
+        };
+
+        assert_eq!(rpc_receipt.transaction_hash, tx_hash);
+        assert_eq!(rpc_receipt.finality_status, TransactionFinalityStatus::AcceptedOnL1);
+        assert_eq!(rpc_receipt.execution_result, ExecutionResult::Succeeded);
+
+        assert_eq!(rpc_receipt.actual_fee.amount, felt!("20"));
+        assert_eq!(rpc_receipt.actual_fee.unit, starknet::core::types::PriceUnit::Fri);
+
+        assert_eq!(rpc_receipt.events.len(), 1);
+        assert_eq!(rpc_receipt.events[0].from_address, felt!("0x1"));
+        assert_eq!(rpc_receipt.events[0].keys, vec![felt!("0x2")]);
+        assert_eq!(rpc_receipt.events[0].data, vec![felt!("0x3")]);
+
+        assert_eq!(rpc_receipt.messages_sent.len(), 1);
+        assert_eq!(rpc_receipt.messages_sent[0].from_address, felt!("0x1"));
+        assert_eq!(rpc_receipt.messages_sent[0].to_address, felt!("0x4"));
+        assert_eq!(rpc_receipt.messages_sent[0].payload, vec![felt!("0x5"), felt!("0x6")]);
+    }
+
+    #[test]
+    fn reverted_l1_handler_receipt_conversion() {
+        let message_hash = B256::repeat_byte(0xab);
+        let receipt = Receipt::L1Handler(L1HandlerTxReceipt {
+            fee: fee(),
+            events: Vec::new(),
+            messages_sent: Vec::new(),
+            message_hash,
+            revert_error: Some("reverted".to_string()),
+            execution_resources: Default::default(),
+        });
+
+        let TxReceipt(rpc_receipt) =
+            TxReceipt::new(felt!("0x1"), FinalityStatus::AcceptedOnL2, receipt);
+
+        let TransactionReceipt::L1Handler(rpc_receipt) = rpc_receipt else {
+            panic!("expected an l1 handler receipt")
+        };
+
+        assert_eq!(rpc_receipt.finality_status, TransactionFinalityStatus::AcceptedOnL2);
+        assert_eq!(rpc_receipt.message_hash, Hash256::from_bytes(*message_hash));
+        assert_eq!(
+            rpc_receipt.execution_result,
+            ExecutionResult::Reverted { reason: "reverted".to_string() }
+        );
+    }
+
+    #[test]
+    fn receipt_execution_resources_breakdown() {
+        let builtins = HashMap::from([
+            (BuiltinName::pedersen, 4usize),
+            (BuiltinName::range_check, 21),
+            (BuiltinName::ecdsa, 1),
+            // builtins that weren't used are omitted
+            (BuiltinName::bitwise, 0),
+        ]);
+
+        let resources = TxResources {
+            n_reverted_steps: 0,
+            vm_resources: VmResources {
+                n_steps: 1234,
+                n_memory_holes: 56,
+                builtin_instance_counter: builtins.into(),
+            },
+            data_availability: L1Gas { l1_gas: 0, l1_data_gas: 128 },
+            total_gas_consumed: L1Gas { l1_gas: 7, l1_data_gas: 128 },
+        };
+
+        let receipt = Receipt::Invoke(InvokeTxReceipt {
+            fee: fee(),
+            events: Vec::new(),
+            messages_sent: Vec::new(),
+            revert_error: None,
+            execution_resources: resources,
+        });
+
+        let TxReceipt(rpc_receipt) =
+            TxReceipt::new(felt!("0x1"), FinalityStatus::AcceptedOnL2, receipt);
+
+        let TransactionReceipt::Invoke(rpc_receipt) = rpc_receipt else {
+            panic!("expected an invoke receipt")
+        };
+
+        let resources = rpc_receipt.execution_resources;
+        assert_eq!(
+            resources.computation_resources,
+            ComputationResources {
+                steps: 1234,
+                memory_holes: Some(56),
+                pedersen_builtin_applications: Some(4),
+                range_check_builtin_applications: Some(21),
+                ecdsa_builtin_applications: Some(1),
+                bitwise_builtin_applications: None,
+                ec_op_builtin_applications: None,
+                keccak_builtin_applications: None,
+                poseidon_builtin_applications: None,
+                segment_arena_builtin: None,
+            }
+        );
+        assert_eq!(
+            resources.data_resources.data_availability,
+            DataAvailabilityResources { l1_gas: 0, l1_data_gas: 128 }
+        );
+    }
+}
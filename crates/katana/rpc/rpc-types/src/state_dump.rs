@@ -0,0 +1,84 @@
+//! Portable representation of the state of a chain, used by the `dev_dumpState` and
+//! `dev_loadState` RPC methods.
+
+use std::io::{self, Read, Write};
+
+use katana_primitives::state::StateUpdatesWithClasses;
+use serde::{Deserialize, Serialize};
+use starknet::core::serde::byte_array::base64;
+
+#[derive(Debug, thiserror::Error)]
+pub enum StateDumpError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+/// The full state of a chain (contracts, storage, nonces and classes), independent of its block
+/// history.
+///
+/// The state is encoded as the gzip compressed JSON serialization of a
+/// [`StateUpdatesWithClasses`] that creates the whole state from an empty one, and is serialized
+/// as a base64 string.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StateDump(#[serde(with = "base64")] pub Vec<u8>);
+
+impl StateDump {
+    /// Encodes the given state.
+    pub fn encode(state: &StateUpdatesWithClasses) -> Result<Self, StateDumpError> {
+        let json = serde_json::to_vec(state)?;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(&json)?;
+        Ok(Self(encoder.finish()?))
+    }
+
+    /// Decodes the dumped state.
+    pub fn decode(&self) -> Result<StateUpdatesWithClasses, StateDumpError> {
+        let mut json = Vec::new();
+        flate2::read::GzDecoder::new(self.0.as_slice()).read_to_end(&mut json)?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use katana_primitives::genesis::constant::{
+        DEFAULT_LEGACY_ERC20_CLASS, DEFAULT_LEGACY_ERC20_CLASS_HASH,
+    };
+    use katana_primitives::{address, felt};
+
+    use super::*;
+
+    #[test]
+    fn encode_and_decode_state() {
+        let mut state = StateUpdatesWithClasses::default();
+        let contract = address!("0x1337");
+
+        state.state_updates.deployed_contracts.insert(contract, DEFAULT_LEGACY_ERC20_CLASS_HASH);
+        state.state_updates.nonce_updates.insert(contract, felt!("0x2"));
+        state
+            .state_updates
+            .storage_updates
+            .insert(contract, BTreeMap::from([(felt!("0x1"), felt!("0x10"))]));
+        state.state_updates.deprecated_declared_classes.insert(DEFAULT_LEGACY_ERC20_CLASS_HASH);
+        state.classes.insert(DEFAULT_LEGACY_ERC20_CLASS_HASH, DEFAULT_LEGACY_ERC20_CLASS.clone());
+
+        let dump = StateDump::encode(&state).unwrap();
+
+        // the dump is serialized as a string so it can be passed around as is
+        let json = serde_json::to_value(&dump).unwrap();
+        assert!(json.is_string());
+        let dump: StateDump = serde_json::from_value(json).unwrap();
+
+        let decoded = dump.decode().unwrap();
+        assert_eq!(decoded.state_updates, state.state_updates);
+        assert_eq!(decoded.classes, state.classes);
+
+        assert!(StateDump(vec![1, 2, 3]).decode().is_err());
+    }
+}
@@ -1,5 +1,7 @@
-use katana_primitives::trace::{CallInfo, TxExecInfo};
+use katana_primitives::event::DecodedEvent;
+use katana_primitives::trace::{CallInfo, ExecutionResources, TxExecInfo};
 use katana_primitives::transaction::TxHash;
+use katana_primitives::{ContractAddress, Felt};
 use serde::{Deserialize, Serialize};
 use starknet::core::types::{
     CallType, ComputationResources, EntryPointType, OrderedEvent, OrderedMessage,
@@ -83,3 +85,41 @@ pub struct TxExecutionInfo {
     /// The transaction execution trace.
     pub trace: TxExecInfo,
 }
+
+/// An event emitted during the execution of a transaction, as returned by the
+/// `dev_getTransactionEvents` RPC method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceEvent {
+    /// The contract that emitted the event.
+    pub from_address: ContractAddress,
+    pub keys: Vec<Felt>,
+    pub data: Vec<Felt>,
+    /// The event decoded against the event ABI of the emitting contract. `None` if the ABI is not
+    /// available (eg for legacy classes) or doesn't declare the event.
+    pub decoded: Option<DecodedEvent>,
+}
+
+/// The resources used by a call of a transaction and its inner calls, as returned by the
+/// `dev_getTransactionResources` RPC method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CallResources {
+    pub contract_address: ContractAddress,
+    pub entry_point_selector: Felt,
+    /// The resources used by the call, including the ones used by its inner calls.
+    pub execution_resources: ExecutionResources,
+    /// The resources used by the call itself, excluding the ones used by its inner calls.
+    pub self_execution_resources: ExecutionResources,
+    pub calls: Vec<CallResources>,
+}
+
+impl From<&CallInfo> for CallResources {
+    fn from(call: &CallInfo) -> Self {
+        Self {
+            contract_address: call.contract_address,
+            entry_point_selector: call.entry_point_selector,
+            execution_resources: call.execution_resources.clone(),
+            self_execution_resources: call.self_execution_resources(),
+            calls: call.inner_calls.iter().map(CallResources::from).collect(),
+        }
+    }
+}
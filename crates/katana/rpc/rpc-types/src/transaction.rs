@@ -620,3 +620,273 @@ fn to_rpc_resource_bounds(
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use katana_primitives::transaction::{DeclareTxV0, InvokeTxV0, L1HandlerTx, Tx as InternalTx};
+    use katana_primitives::{address, felt};
+    use starknet::core::types::{
+        DataAvailabilityMode as RpcDaMode, DeclareTransaction as RpcDeclareTx,
+        DeployAccountTransaction as RpcDeployAccountTx, InvokeTransaction as RpcInvokeTx,
+        ResourceBoundsMapping as RpcResourceBoundsMapping, Transaction as RpcTx,
+    };
+
+    use super::*;
+
+    fn resource_bounds() -> ResourceBoundsMapping {
+        ResourceBoundsMapping {
+            l1_gas: ResourceBounds { max_amount: 0x100, max_price_per_unit: 0x200 },
+            l2_gas: ResourceBounds { max_amount: 0x300, max_price_per_unit: 0x400 },
+        }
+    }
+
+    fn invoke_v3() -> InternalTx {
+        InternalTx::Invoke(InvokeTx::V3(InvokeTxV3 {
+            chain_id: ChainId::SEPOLIA,
+            sender_address: address!("0x1"),
+            nonce: felt!("0x2"),
+            calldata: vec![felt!("0x3")],
+            signature: vec![felt!("0x4")],
+            resource_bounds: resource_bounds(),
+            tip: 0x5,
+            paymaster_data: vec![felt!("0x6")],
+            account_deployment_data: vec![felt!("0x7")],
+            nonce_data_availability_mode: DataAvailabilityMode::L1,
+            fee_data_availability_mode: DataAvailabilityMode::L2,
+        }))
+    }
+
+    fn declare_v3() -> InternalTx {
+        InternalTx::Declare(DeclareTx::V3(DeclareTxV3 {
+            chain_id: ChainId::SEPOLIA,
+            sender_address: address!("0x1"),
+            nonce: felt!("0x2"),
+            signature: vec![felt!("0x4")],
+            class_hash: felt!("0x8"),
+            compiled_class_hash: felt!("0x9"),
+            resource_bounds: resource_bounds(),
+            tip: 0x5,
+            paymaster_data: vec![felt!("0x6")],
+            account_deployment_data: vec![felt!("0x7")],
+            nonce_data_availability_mode: DataAvailabilityMode::L1,
+            fee_data_availability_mode: DataAvailabilityMode::L2,
+        }))
+    }
+
+    fn deploy_account_v3() -> InternalTx {
+        InternalTx::DeployAccount(DeployAccountTx::V3(DeployAccountTxV3 {
+            chain_id: ChainId::SEPOLIA,
+            nonce: felt!("0x2"),
+            signature: vec![felt!("0x4")],
+            class_hash: felt!("0x8"),
+            contract_address: address!("0x1"),
+            contract_address_salt: felt!("0xa"),
+            constructor_calldata: vec![felt!("0x3")],
+            resource_bounds: resource_bounds(),
+            tip: 0x5,
+            paymaster_data: vec![felt!("0x6")],
+            nonce_data_availability_mode: DataAvailabilityMode::L1,
+            fee_data_availability_mode: DataAvailabilityMode::L2,
+        }))
+    }
+
+    fn assert_resource_bounds(bounds: &ResourceBoundsMapping, rpc: &RpcResourceBoundsMapping) {
+        assert_eq!(rpc.l1_gas.max_amount, bounds.l1_gas.max_amount);
+        assert_eq!(rpc.l1_gas.max_price_per_unit, bounds.l1_gas.max_price_per_unit);
+        assert_eq!(rpc.l2_gas.max_amount, bounds.l2_gas.max_amount);
+        assert_eq!(rpc.l2_gas.max_price_per_unit, bounds.l2_gas.max_price_per_unit);
+    }
+
+    fn assert_da_mode(mode: DataAvailabilityMode, rpc: RpcDaMode) {
+        match mode {
+            DataAvailabilityMode::L1 => assert_eq!(rpc, RpcDaMode::L1),
+            DataAvailabilityMode::L2 => assert_eq!(rpc, RpcDaMode::L2),
+        }
+    }
+
+    /// Asserts the fields shared by all v3 transactions.
+    macro_rules! assert_v3_fields {
+        ($tx:expr, $rpc:expr) => {
+            assert_resource_bounds(&$tx.resource_bounds, &$rpc.resource_bounds);
+            assert_eq!($rpc.tip, $tx.tip);
+            assert_eq!($rpc.paymaster_data, $tx.paymaster_data);
+            assert_da_mode($tx.nonce_data_availability_mode, $rpc.nonce_data_availability_mode);
+            assert_da_mode($tx.fee_data_availability_mode, $rpc.fee_data_availability_mode);
+        };
+    }
+
+    /// Asserts that the fields of the RPC transaction are those of the transaction it's converted
+    /// from.
+    fn assert_converted(tx: &InternalTx, rpc: &RpcTx) {
+        match (tx, rpc) {
+            (InternalTx::Invoke(InvokeTx::V0(tx)), RpcTx::Invoke(RpcInvokeTx::V0(rpc))) => {
+                assert_eq!(rpc.contract_address, Felt::from(tx.contract_address));
+                assert_eq!(rpc.entry_point_selector, tx.entry_point_selector);
+                assert_eq!(rpc.calldata, tx.calldata);
+                assert_eq!(rpc.signature, tx.signature);
+                assert_eq!(rpc.max_fee, Felt::from(tx.max_fee));
+            }
+            (InternalTx::Invoke(InvokeTx::V1(tx)), RpcTx::Invoke(RpcInvokeTx::V1(rpc))) => {
+                assert_eq!(rpc.sender_address, Felt::from(tx.sender_address));
+                assert_eq!(rpc.nonce, tx.nonce);
+                assert_eq!(rpc.calldata, tx.calldata);
+                assert_eq!(rpc.signature, tx.signature);
+                assert_eq!(rpc.max_fee, Felt::from(tx.max_fee));
+            }
+            (InternalTx::Invoke(InvokeTx::V3(tx)), RpcTx::Invoke(RpcInvokeTx::V3(rpc))) => {
+                assert_eq!(rpc.sender_address, Felt::from(tx.sender_address));
+                assert_eq!(rpc.nonce, tx.nonce);
+                assert_eq!(rpc.calldata, tx.calldata);
+                assert_eq!(rpc.signature, tx.signature);
+                assert_eq!(rpc.account_deployment_data, tx.account_deployment_data);
+                assert_v3_fields!(tx, rpc);
+            }
+            (InternalTx::Declare(DeclareTx::V0(tx)), RpcTx::Declare(RpcDeclareTx::V0(rpc))) => {
+                assert_eq!(rpc.sender_address, Felt::from(tx.sender_address));
+                assert_eq!(rpc.class_hash, tx.class_hash);
+                assert_eq!(rpc.signature, tx.signature);
+                assert_eq!(rpc.max_fee, Felt::from(tx.max_fee));
+            }
+            (InternalTx::Declare(DeclareTx::V1(tx)), RpcTx::Declare(RpcDeclareTx::V1(rpc))) => {
+                assert_eq!(rpc.sender_address, Felt::from(tx.sender_address));
+                assert_eq!(rpc.nonce, tx.nonce);
+                assert_eq!(rpc.class_hash, tx.class_hash);
+                assert_eq!(rpc.signature, tx.signature);
+                assert_eq!(rpc.max_fee, Felt::from(tx.max_fee));
+            }
+            (InternalTx::Declare(DeclareTx::V2(tx)), RpcTx::Declare(RpcDeclareTx::V2(rpc))) => {
+                assert_eq!(rpc.sender_address, Felt::from(tx.sender_address));
+                assert_eq!(rpc.nonce, tx.nonce);
+                assert_eq!(rpc.class_hash, tx.class_hash);
+                assert_eq!(rpc.compiled_class_hash, tx.compiled_class_hash);
+                assert_eq!(rpc.signature, tx.signature);
+                assert_eq!(rpc.max_fee, Felt::from(tx.max_fee));
+            }
+            (InternalTx::Declare(DeclareTx::V3(tx)), RpcTx::Declare(RpcDeclareTx::V3(rpc))) => {
+                assert_eq!(rpc.sender_address, Felt::from(tx.sender_address));
+                assert_eq!(rpc.nonce, tx.nonce);
+                assert_eq!(rpc.class_hash, tx.class_hash);
+                assert_eq!(rpc.compiled_class_hash, tx.compiled_class_hash);
+                assert_eq!(rpc.signature, tx.signature);
+                assert_eq!(rpc.account_deployment_data, tx.account_deployment_data);
+                assert_v3_fields!(tx, rpc);
+            }
+            (
+                InternalTx::DeployAccount(DeployAccountTx::V1(tx)),
+                RpcTx::DeployAccount(RpcDeployAccountTx::V1(rpc)),
+            ) => {
+                assert_eq!(rpc.nonce, tx.nonce);
+                assert_eq!(rpc.class_hash, tx.class_hash);
+                assert_eq!(rpc.contract_address_salt, tx.contract_address_salt);
+                assert_eq!(rpc.constructor_calldata, tx.constructor_calldata);
+                assert_eq!(rpc.signature, tx.signature);
+                assert_eq!(rpc.max_fee, Felt::from(tx.max_fee));
+            }
+            (
+                InternalTx::DeployAccount(DeployAccountTx::V3(tx)),
+                RpcTx::DeployAccount(RpcDeployAccountTx::V3(rpc)),
+            ) => {
+                assert_eq!(rpc.nonce, tx.nonce);
+                assert_eq!(rpc.class_hash, tx.class_hash);
+                assert_eq!(rpc.contract_address_salt, tx.contract_address_salt);
+                assert_eq!(rpc.constructor_calldata, tx.constructor_calldata);
+                assert_eq!(rpc.signature, tx.signature);
+                assert_v3_fields!(tx, rpc);
+            }
+            (InternalTx::L1Handler(tx), RpcTx::L1Handler(rpc)) => {
+                assert_eq!(Felt::from(rpc.nonce), tx.nonce);
+                assert_eq!(rpc.version, tx.version);
+                assert_eq!(rpc.contract_address, Felt::from(tx.contract_address));
+                assert_eq!(rpc.entry_point_selector, tx.entry_point_selector);
+                assert_eq!(rpc.calldata, tx.calldata);
+            }
+            _ => panic!("converted to the wrong variant: {rpc:?}"),
+        }
+    }
+
+    #[rstest::rstest]
+    #[case::invoke_v0(InternalTx::Invoke(InvokeTx::V0(InvokeTxV0 {
+        contract_address: address!("0x1"),
+        entry_point_selector: felt!("0x2"),
+        calldata: vec![felt!("0x3")],
+        signature: vec![felt!("0x4")],
+        max_fee: 0x5,
+    })))]
+    #[case::invoke_v1(InternalTx::Invoke(InvokeTx::V1(InvokeTxV1 {
+        sender_address: address!("0x1"),
+        nonce: felt!("0x2"),
+        calldata: vec![felt!("0x3")],
+        signature: vec![felt!("0x4")],
+        max_fee: 0x5,
+        ..Default::default()
+    })))]
+    #[case::invoke_v3(invoke_v3())]
+    #[case::declare_v0(InternalTx::Declare(DeclareTx::V0(DeclareTxV0 {
+        sender_address: address!("0x1"),
+        class_hash: felt!("0x8"),
+        max_fee: 0x5,
+        ..Default::default()
+    })))]
+    #[case::declare_v1(InternalTx::Declare(DeclareTx::V1(DeclareTxV1 {
+        sender_address: address!("0x1"),
+        nonce: felt!("0x2"),
+        class_hash: felt!("0x8"),
+        max_fee: 0x5,
+        ..Default::default()
+    })))]
+    #[case::declare_v2(InternalTx::Declare(DeclareTx::V2(DeclareTxV2 {
+        sender_address: address!("0x1"),
+        nonce: felt!("0x2"),
+        class_hash: felt!("0x8"),
+        compiled_class_hash: felt!("0x9"),
+        max_fee: 0x5,
+        ..Default::default()
+    })))]
+    #[case::declare_v3(declare_v3())]
+    #[case::deploy_account_v1(InternalTx::DeployAccount(DeployAccountTx::V1(DeployAccountTxV1 {
+        nonce: felt!("0x2"),
+        class_hash: felt!("0x8"),
+        contract_address: address!("0x1"),
+        contract_address_salt: felt!("0xa"),
+        constructor_calldata: vec![felt!("0x3")],
+        max_fee: 0x5,
+        ..Default::default()
+    })))]
+    #[case::deploy_account_v3(deploy_account_v3())]
+    #[case::l1_handler(InternalTx::L1Handler(L1HandlerTx {
+        nonce: felt!("0x2"),
+        contract_address: address!("0x1"),
+        entry_point_selector: felt!("0xb"),
+        calldata: vec![felt!("0x3")],
+        ..Default::default()
+    }))]
+    fn rpc_tx_roundtrip(#[case] tx: InternalTx) {
+        let hash = felt!("0x123");
+        let rpc_tx = Tx::from(TxWithHash { hash, transaction: tx.clone() });
+
+        let json = serde_json::to_value(&rpc_tx).unwrap();
+        let deserialized: starknet::core::types::Transaction =
+            serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(deserialized, rpc_tx.0);
+        assert_eq!(*deserialized.transaction_hash(), hash);
+        assert_converted(&tx, &deserialized);
+
+        // the fields specific to v3 transactions are all included
+        if matches!(
+            tx,
+            InternalTx::Invoke(InvokeTx::V3(_))
+                | InternalTx::Declare(DeclareTx::V3(_))
+                | InternalTx::DeployAccount(DeployAccountTx::V3(_))
+        ) {
+            assert_eq!(json["resource_bounds"]["l1_gas"]["max_amount"], "0x100");
+            assert_eq!(json["resource_bounds"]["l1_gas"]["max_price_per_unit"], "0x200");
+            assert_eq!(json["resource_bounds"]["l2_gas"]["max_amount"], "0x300");
+            assert_eq!(json["resource_bounds"]["l2_gas"]["max_price_per_unit"], "0x400");
+            assert_eq!(json["tip"], "0x5");
+            assert_eq!(json["paymaster_data"], serde_json::json!(["0x6"]));
+            assert_eq!(json["nonce_data_availability_mode"], "L1");
+            assert_eq!(json["fee_data_availability_mode"], "L2");
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! Types describing the models registered in a Dojo world contract.
+//!
+//! The schema types mirror the `dojo::meta::introspect` types of the Cairo core library, and can
+//! be deserialized from the output of the `schema` entrypoint of a model contract.
+
+use std::slice;
+
+use katana_primitives::class::ClassHash;
+use katana_primitives::{ContractAddress, Felt};
+use serde::{Deserialize, Serialize};
+use starknet::core::utils::parse_cairo_short_string;
+
+type Felts<'a> = slice::Iter<'a, Felt>;
+
+/// A model registered in a world contract.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorldModel {
+    pub namespace: String,
+    pub name: String,
+    /// The class hash of the currently deployed version of the model.
+    pub class_hash: ClassHash,
+    /// The address of the currently deployed version of the model.
+    pub address: ContractAddress,
+    pub schema: Struct,
+}
+
+/// The type of a model member.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type", content = "value")]
+pub enum Ty {
+    /// A primitive type, eg `u32` or `ContractAddress`.
+    Primitive(String),
+    Struct(Struct),
+    Enum(Enum),
+    Tuple(Vec<Ty>),
+    /// An `Array` or a `Span` of the given element type.
+    Array(Box<Ty>),
+    ByteArray,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Struct {
+    pub name: String,
+    /// The attributes of the struct, eg `key` for the keys of a model.
+    pub attrs: Vec<String>,
+    pub children: Vec<Member>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Member {
+    pub name: String,
+    pub attrs: Vec<String>,
+    pub ty: Ty,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Enum {
+    pub name: String,
+    pub attrs: Vec<String>,
+    pub options: Vec<EnumOption>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnumOption {
+    pub name: String,
+    pub ty: Ty,
+}
+
+impl Struct {
+    /// Deserializes a struct schema from its Cairo serialization.
+    ///
+    /// Returns `None` if the felts are not a valid serialization of the schema, or if there are
+    /// trailing felts.
+    pub fn from_felts(felts: &[Felt]) -> Option<Self> {
+        let mut felts = felts.iter();
+        let schema = Self::decode(&mut felts)?;
+        (felts.len() == 0).then_some(schema)
+    }
+
+    fn decode(felts: &mut Felts<'_>) -> Option<Self> {
+        let name = short_string(felts)?;
+        let attrs = short_strings(felts)?;
+        let children = (0..length(felts)?).map(|_| Member::decode(felts)).collect::<Option<_>>()?;
+        Some(Self { name, attrs, children })
+    }
+}
+
+impl Member {
+    fn decode(felts: &mut Felts<'_>) -> Option<Self> {
+        let name = short_string(felts)?;
+        let attrs = short_strings(felts)?;
+        let ty = Ty::decode(felts)?;
+        Some(Self { name, attrs, ty })
+    }
+}
+
+impl Enum {
+    fn decode(felts: &mut Felts<'_>) -> Option<Self> {
+        let name = short_string(felts)?;
+        let attrs = short_strings(felts)?;
+        let options = (0..length(felts)?)
+            .map(|_| Some(EnumOption { name: short_string(felts)?, ty: Ty::decode(felts)? }))
+            .collect::<Option<_>>()?;
+        Some(Self { name, attrs, options })
+    }
+}
+
+impl Ty {
+    fn decode(felts: &mut Felts<'_>) -> Option<Self> {
+        // the variant index of the `Ty` enum
+        let ty = match u8::try_from(*felts.next()?).ok()? {
+            0 => Ty::Primitive(short_string(felts)?),
+            1 => Ty::Struct(Struct::decode(felts)?),
+            2 => Ty::Enum(Enum::decode(felts)?),
+            3 => Ty::Tuple((0..length(felts)?).map(|_| Ty::decode(felts)).collect::<Option<_>>()?),
+            // the element type is wrapped in a span of exactly one element
+            4 => match length(felts)? {
+                1 => Ty::Array(Box::new(Ty::decode(felts)?)),
+                _ => return None,
+            },
+            5 => Ty::ByteArray,
+            _ => return None,
+        };
+
+        Some(ty)
+    }
+}
+
+fn short_string(felts: &mut Felts<'_>) -> Option<String> {
+    parse_cairo_short_string(felts.next()?).ok()
+}
+
+fn short_strings(felts: &mut Felts<'_>) -> Option<Vec<String>> {
+    (0..length(felts)?).map(|_| short_string(felts)).collect()
+}
+
+/// Reads the length prefix of a serialized span, making sure there are enough felts left.
+fn length(felts: &mut Felts<'_>) -> Option<usize> {
+    let len = usize::try_from(u64::try_from(*felts.next()?).ok()?).ok()?;
+    (len <= felts.len()).then_some(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet::core::utils::cairo_short_string_to_felt;
+
+    use super::*;
+
+    fn s(value: &str) -> Felt {
+        cairo_short_string_to_felt(value).unwrap()
+    }
+
+    #[test]
+    fn decode_struct_schema() {
+        // struct Position { #[key] player: ContractAddress, vec: Vec2, dir: Direction, name:
+        // ByteArray, path: Array<(u32, u32)> }
+        #[rustfmt::skip]
+        let felts = vec![
+            s("Position"), Felt::ZERO, Felt::from(5),
+            // player
+            s("player"), Felt::ONE, s("key"), Felt::ZERO, s("ContractAddress"),
+            // vec
+            s("vec"), Felt::ZERO, Felt::ONE, s("Vec2"), Felt::ZERO, Felt::from(2),
+                s("x"), Felt::ZERO, Felt::ZERO, s("u32"),
+                s("y"), Felt::ZERO, Felt::ZERO, s("u32"),
+            // dir
+            s("dir"), Felt::ZERO, Felt::from(2), s("Direction"), Felt::ZERO, Felt::from(2),
+                s("Left"), Felt::from(3), Felt::ZERO,
+                s("Right"), Felt::from(3), Felt::ZERO,
+            // name
+            s("name"), Felt::ZERO, Felt::from(5),
+            // path
+            s("path"), Felt::ZERO, Felt::from(4), Felt::ONE,
+                Felt::from(3), Felt::from(2), Felt::ZERO, s("u32"), Felt::ZERO, s("u32"),
+        ];
+
+        let schema = Struct::from_felts(&felts).unwrap();
+        assert_eq!(schema.name, "Position");
+        assert_eq!(schema.children.len(), 5);
+
+        let player = &schema.children[0];
+        assert_eq!(player.name, "player");
+        assert_eq!(player.attrs, vec!["key".to_string()]);
+        assert_eq!(player.ty, Ty::Primitive("ContractAddress".into()));
+
+        let u32 = Ty::Primitive("u32".into());
+        let vec = &schema.children[1];
+        assert_eq!(
+            vec.ty,
+            Ty::Struct(Struct {
+                name: "Vec2".into(),
+                attrs: vec![],
+                children: vec![
+                    Member { name: "x".into(), attrs: vec![], ty: u32.clone() },
+                    Member { name: "y".into(), attrs: vec![], ty: u32.clone() },
+                ],
+            })
+        );
+
+        let unit = Ty::Tuple(vec![]);
+        let dir = &schema.children[2];
+        assert_eq!(
+            dir.ty,
+            Ty::Enum(Enum {
+                name: "Direction".into(),
+                attrs: vec![],
+                options: vec![
+                    EnumOption { name: "Left".into(), ty: unit.clone() },
+                    EnumOption { name: "Right".into(), ty: unit },
+                ],
+            })
+        );
+
+        assert_eq!(schema.children[3].ty, Ty::ByteArray);
+        assert_eq!(schema.children[4].ty, Ty::Array(Box::new(Ty::Tuple(vec![u32.clone(), u32]))));
+    }
+
+    #[test]
+    fn decode_invalid_schema() {
+        // missing members
+        assert!(Struct::from_felts(&[s("Position"), Felt::ZERO, Felt::from(2)]).is_none());
+        // unknown `Ty` variant
+        let felts = [s("Position"), Felt::ZERO, Felt::ONE, s("x"), Felt::ZERO, Felt::from(9)];
+        assert!(Struct::from_felts(&felts).is_none());
+        // trailing felts
+        assert!(Struct::from_felts(&[s("Position"), Felt::ZERO, Felt::ZERO, Felt::ONE]).is_none());
+    }
+}
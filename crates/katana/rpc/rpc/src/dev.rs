@@ -1,23 +1,94 @@
-use std::sync::Arc;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 use jsonrpsee::core::{async_trait, Error};
 use katana_core::backend::Backend;
-use katana_core::service::block_producer::{BlockProducer, BlockProducerMode, PendingExecutor};
-use katana_executor::ExecutorFactory;
-use katana_primitives::Felt;
+use katana_core::service::block_producer::{
+    BlockProducer, BlockProducerMode, BlockProductionError, PendingExecutor,
+};
+use katana_executor::{EntryPointCall, ExecutorFactory};
+use katana_pool::{TransactionPool, TxPool};
+use katana_primitives::block::{BlockHash, BlockIdOrTag, BlockNumber, BlockTag};
+use katana_primitives::class::{ClassHash, ContractClass};
+use katana_primitives::contract::{Nonce, StorageValue};
+use katana_primitives::event::{DecodedEvent, DecodedValue};
+use katana_primitives::state::StateUpdatesWithClasses;
+use katana_primitives::trace::CallInfo;
+use katana_primitives::transaction::{ExecutableTxWithHash, TxHash};
+use katana_primitives::{ContractAddress, Felt};
+use katana_provider::error::ProviderError;
+use katana_provider::traits::block::{BlockHashProvider, BlockNumberProvider};
+use katana_provider::traits::contract::ContractClassProvider;
+use katana_provider::traits::env::BlockEnvProvider;
+use katana_provider::traits::state::{StateFactoryProvider, StateProvider};
+use katana_provider::traits::state_update::StateUpdateProvider;
+use katana_provider::traits::transaction::{
+    ReceiptProvider, TransactionProvider, TransactionTraceProvider,
+};
+use katana_provider::ProviderResult;
 use katana_rpc_api::dev::DevApiServer;
 use katana_rpc_types::account::Account;
 use katana_rpc_types::error::dev::DevApiError;
+use katana_rpc_types::message::MsgFromL1;
+use katana_rpc_types::receipt::FeeBreakdown;
+use katana_rpc_types::state_dump::StateDump;
+use katana_rpc_types::trace::{CallResources, TraceEvent};
+use katana_rpc_types::trie::ContractStorageKeys;
+use katana_rpc_types::world::{Struct, WorldModel};
+use starknet::core::types::{Hash256, MsgToL1, StorageEntry};
+use starknet::macros::selector;
+
+/// The maximum number of blocks that can be mined by a single `dev_mine` request.
+pub const MAX_MINED_BLOCKS: u64 = 1000;
 
 #[allow(missing_debug_implementations)]
 pub struct DevApi<EF: ExecutorFactory> {
     backend: Arc<Backend<EF>>,
+    pool: TxPool,
     block_producer: BlockProducer<EF>,
+    /// The L2 to L1 messages that haven't been consumed yet.
+    l1_messages: Mutex<L1MessageQueue>,
+    /// The nonce of the next L1 to L2 message sent through `dev_sendL1Message`.
+    l1_message_nonce: AtomicU64,
+    /// The models registered in the worlds queried through `dev_getWorldSchema`.
+    world_models: Mutex<HashMap<ContractAddress, WorldModels>>,
+}
+
+/// The models registered in a world, as found in the events of the blocks scanned so far.
+#[derive(Debug)]
+struct WorldModels {
+    /// The first block whose events haven't been scanned yet.
+    cursor: BlockNumber,
+    /// The registered models, in registration order.
+    models: Vec<RegisteredModel>,
+}
+
+/// Queue of the L2 to L1 messages waiting to be consumed on L1.
+#[derive(Debug)]
+struct L1MessageQueue {
+    /// The first block whose messages haven't been added to the queue yet.
+    cursor: BlockNumber,
+    /// The pending messages, in the order they were sent.
+    messages: Vec<MsgToL1>,
 }
 
 impl<EF: ExecutorFactory> DevApi<EF> {
-    pub fn new(backend: Arc<Backend<EF>>, block_producer: BlockProducer<EF>) -> Self {
-        Self { backend, block_producer }
+    pub fn new(backend: Arc<Backend<EF>>, pool: TxPool, block_producer: BlockProducer<EF>) -> Self {
+        let l1_messages = Mutex::new(L1MessageQueue {
+            cursor: backend.chain_spec.genesis().number,
+            messages: Vec::new(),
+        });
+
+        Self {
+            backend,
+            pool,
+            block_producer,
+            l1_messages,
+            l1_message_nonce: AtomicU64::new(0),
+            world_models: Mutex::new(HashMap::new()),
+        }
     }
 
     /// Returns the pending state if the sequencer is running in _interval_ mode. Otherwise `None`.
@@ -57,6 +128,480 @@ impl<EF: ExecutorFactory> DevApi<EF> {
 
         Ok(())
     }
+
+    pub fn mine(&self, count: u64, interval: Option<u64>) -> Result<Vec<BlockHash>, DevApiError> {
+        if count > MAX_MINED_BLOCKS {
+            return Err(DevApiError::TooManyBlocks);
+        }
+
+        let provider = self.backend.blockchain.provider();
+        let err = |_: ProviderError| DevApiError::BlockMiningFailed;
+        let mut hashes = Vec::new();
+
+        for i in 0..count {
+            if let Some(interval) = interval {
+                let timestamp = match self.pending_executor() {
+                    // The env of the pending block is already fixed, so the interval is applied to
+                    // the block that will be opened after it.
+                    Some(executor) => Some(executor.read().block_env().timestamp),
+                    // In instant mode, the env is only created when the block is mined.
+                    None if i > 0 => {
+                        let num = provider.latest_number().map_err(err)?;
+                        let env = provider.block_env_at(num.into()).map_err(err)?;
+                        env.map(|env| env.timestamp)
+                    }
+                    None => None,
+                };
+
+                if let Some(timestamp) = timestamp {
+                    let timestamp =
+                        timestamp.checked_add(interval).ok_or(DevApiError::TimestampOverflow)?;
+                    let mut block_context_generator = self.backend.block_context_generator.write();
+                    block_context_generator.next_block_start_time = timestamp;
+                }
+            }
+
+            let latest_num = provider.latest_number().map_err(err)?;
+            self.block_producer.force_mine();
+
+            // `force_mine` doesn't report failures, so we check that a new block was produced
+            if provider.latest_number().map_err(err)? == latest_num {
+                return Err(DevApiError::BlockMiningFailed);
+            }
+
+            hashes.push(provider.latest_hash().map_err(err)?);
+        }
+
+        Ok(hashes)
+    }
+
+    pub fn pending_l1_messages(&self) -> Result<Vec<MsgToL1>, DevApiError> {
+        let mut queue = self.l1_messages.lock().expect("poisoned lock");
+        self.sync_l1_messages(&mut queue)?;
+        Ok(queue.messages.clone())
+    }
+
+    pub fn consume_l1_message(&self, hash: Hash256) -> Result<(), DevApiError> {
+        let mut queue = self.l1_messages.lock().expect("poisoned lock");
+        self.sync_l1_messages(&mut queue)?;
+
+        let index = queue
+            .messages
+            .iter()
+            .position(|msg| msg.hash() == hash)
+            .ok_or(DevApiError::MessageNotFound)?;
+
+        queue.messages.remove(index);
+        Ok(())
+    }
+
+    /// Appends the messages sent by the blocks mined since the last sync to the queue.
+    fn sync_l1_messages(&self, queue: &mut L1MessageQueue) -> Result<(), DevApiError> {
+        let provider = self.backend.blockchain.provider();
+        let err = |_: ProviderError| DevApiError::FailedToGetMessages;
+
+        let latest_num = provider.latest_number().map_err(err)?;
+
+        for num in queue.cursor..=latest_num {
+            let receipts = provider.receipts_by_block(num.into()).map_err(err)?.unwrap_or_default();
+
+            for receipt in receipts {
+                queue.messages.extend(receipt.messages_sent().iter().map(|msg| MsgToL1 {
+                    from_address: msg.from_address.into(),
+                    to_address: msg.to_address,
+                    payload: msg.payload.clone(),
+                }));
+            }
+        }
+
+        queue.cursor = latest_num + 1;
+        Ok(())
+    }
+
+    pub fn send_l1_message(&self, message: MsgFromL1) -> Result<TxHash, DevApiError> {
+        let chain_id = self.backend.chain_spec.id();
+
+        // Each message gets a unique nonce so that sending the same message multiple times results
+        // in different transactions.
+        let mut tx = message.into_tx_with_chain_id(chain_id);
+        tx.nonce = self.l1_message_nonce.fetch_add(1, Ordering::Relaxed).into();
+
+        let hash = tx.calculate_hash();
+        let tx = ExecutableTxWithHash { hash, transaction: tx.into() };
+        self.pool.add_transaction(tx).map_err(|_| DevApiError::FailedToSendMessage)
+    }
+
+    pub fn transaction_events(&self, hash: TxHash) -> Result<Vec<TraceEvent>, DevApiError> {
+        let provider = self.backend.blockchain.provider();
+        let err = |_: ProviderError| DevApiError::FailedToGetEvents;
+
+        // the transactions of the pending block are not in the storage yet
+        let pending = self.pending_executor().and_then(|exec| {
+            let block = exec.read();
+            let (_, res) = block.transactions().iter().find(|(tx, _)| tx.hash == hash)?;
+            Some((res.trace()?.clone(), block.state()))
+        });
+
+        let (trace, state) = match pending {
+            Some(pending) => pending,
+            None => {
+                let trace = provider
+                    .transaction_execution(hash)
+                    .map_err(err)?
+                    .ok_or(DevApiError::TransactionNotFound)?;
+
+                // classes are never removed from the state, so the latest state has the classes
+                // of all the contracts called by the transaction.
+                (trace, provider.latest().map_err(err)?)
+            }
+        };
+
+        let mut classes = HashMap::new();
+        let mut events = Vec::new();
+
+        let calls =
+            [trace.validate_call_info, trace.execute_call_info, trace.fee_transfer_call_info];
+        for call in calls.iter().flatten() {
+            let mut call_events = Vec::new();
+            collect_events(call, &*state, &mut classes, &mut call_events).map_err(err)?;
+
+            // the events of the inner calls are interleaved with the events of their caller
+            call_events.sort_by_key(|(order, _)| *order);
+            events.extend(call_events.into_iter().map(|(_, event)| event));
+        }
+
+        Ok(events)
+    }
+
+    pub fn transaction_fee(&self, hash: TxHash) -> Result<FeeBreakdown, DevApiError> {
+        let provider = self.backend.blockchain.provider();
+        let err = |_: ProviderError| DevApiError::FailedToGetFee;
+
+        // the transactions of the pending block are not in the storage yet
+        let pending = self.pending_executor().and_then(|exec| {
+            let block = exec.read();
+            let (tx, res) = block.transactions().iter().find(|(tx, _)| tx.hash == hash)?;
+            Some((tx.transaction.clone(), res.receipt()?.clone()))
+        });
+
+        let (tx, receipt) = match pending {
+            Some(pending) => pending,
+            None => {
+                let tx = provider
+                    .transaction_by_hash(hash)
+                    .map_err(err)?
+                    .ok_or(DevApiError::TransactionNotFound)?;
+                let receipt = provider
+                    .receipt_by_hash(hash)
+                    .map_err(err)?
+                    .ok_or(DevApiError::TransactionNotFound)?;
+                (tx.transaction, receipt)
+            }
+        };
+
+        let max_fee = tx.max_fee().ok_or(DevApiError::TransactionWithoutFee)?;
+        Ok(receipt.fee().breakdown(max_fee).into())
+    }
+
+    pub fn transaction_resources(&self, hash: TxHash) -> Result<Vec<CallResources>, DevApiError> {
+        // the transactions of the pending block are not in the storage yet
+        let pending = self.pending_executor().and_then(|exec| {
+            let block = exec.read();
+            let (_, res) = block.transactions().iter().find(|(tx, _)| tx.hash == hash)?;
+            res.trace().cloned()
+        });
+
+        let trace = match pending {
+            Some(trace) => trace,
+            None => self
+                .backend
+                .blockchain
+                .provider()
+                .transaction_execution(hash)
+                .map_err(|_| DevApiError::FailedToGetResources)?
+                .ok_or(DevApiError::TransactionNotFound)?,
+        };
+
+        let calls =
+            [trace.validate_call_info, trace.execute_call_info, trace.fee_transfer_call_info];
+        Ok(calls.iter().flatten().map(CallResources::from).collect())
+    }
+
+    pub fn contract_storage(
+        &self,
+        address: ContractAddress,
+    ) -> Result<Vec<StorageEntry>, DevApiError> {
+        let err = |_: ProviderError| DevApiError::FailedToGetStorage;
+
+        let state = match self.pending_executor() {
+            Some(exec) => exec.read().state(),
+            None => self.backend.blockchain.provider().latest().map_err(err)?,
+        };
+
+        if state.class_hash_of_contract(address).map_err(err)?.is_none() {
+            return Err(DevApiError::ContractNotFound);
+        }
+
+        let entries = state.storage_entries(address).map_err(err)?;
+        Ok(entries.into_iter().map(|(key, value)| StorageEntry { key, value }).collect())
+    }
+
+    pub fn storages_at(
+        &self,
+        block_id: BlockIdOrTag,
+        contracts_storage_keys: Vec<ContractStorageKeys>,
+    ) -> Result<Vec<Vec<StorageValue>>, DevApiError> {
+        let err = |_: ProviderError| DevApiError::FailedToGetStorage;
+        let provider = self.backend.blockchain.provider();
+
+        // all the values are read from the same state so that they are consistent with each other
+        let state = match block_id {
+            BlockIdOrTag::Tag(BlockTag::Pending) => match self.pending_executor() {
+                Some(exec) => Some(exec.read().state()),
+                None => Some(provider.latest().map_err(err)?),
+            },
+            BlockIdOrTag::Tag(BlockTag::Latest) => Some(provider.latest().map_err(err)?),
+            BlockIdOrTag::Hash(hash) => provider.historical(hash.into()).map_err(err)?,
+            BlockIdOrTag::Number(num) => provider.historical(num.into()).map_err(err)?,
+        };
+
+        let state = state.ok_or(DevApiError::BlockNotFound)?;
+        let mut values = Vec::with_capacity(contracts_storage_keys.len());
+
+        for ContractStorageKeys { address, keys } in contracts_storage_keys {
+            // the system contract at 0x1 has storage but no class
+            if address.0 != Felt::ONE
+                && state.class_hash_of_contract(address).map_err(err)?.is_none()
+            {
+                return Err(DevApiError::ContractNotFound);
+            }
+
+            // forked states fetch all the slots from the remote chain at once
+            state.prefetch_storage(address, &keys).map_err(err)?;
+
+            let mut contract_values = Vec::with_capacity(keys.len());
+            for key in keys {
+                contract_values.push(state.storage(address, key).map_err(err)?.unwrap_or_default());
+            }
+
+            values.push(contract_values);
+        }
+
+        Ok(values)
+    }
+
+    pub fn dump_state(&self) -> Result<StateDump, DevApiError> {
+        let provider = self.backend.blockchain.provider();
+        let err = |_: ProviderError| DevApiError::FailedToDumpState;
+
+        // the latest state is the result of applying the state updates of every block in order
+        let mut states = StateUpdatesWithClasses::default();
+        let latest_num = provider.latest_number().map_err(err)?;
+        for num in self.backend.chain_spec.genesis().number..=latest_num {
+            let update = provider.state_update(num.into()).map_err(err)?.unwrap_or_default();
+            states.state_updates.merge(update);
+        }
+
+        let state = provider.latest().map_err(err)?;
+        let updates = &states.state_updates;
+        for hash in updates.declared_classes.keys().chain(&updates.deprecated_declared_classes) {
+            let class = state.class(*hash).map_err(err)?.ok_or(DevApiError::FailedToDumpState)?;
+            states.classes.insert(*hash, class);
+        }
+
+        StateDump::encode(&states).map_err(|_| DevApiError::FailedToDumpState)
+    }
+
+    pub fn load_state(&self, dump: StateDump) -> Result<(), DevApiError> {
+        let states = dump.decode().map_err(|_| DevApiError::FailedToLoadState)?;
+        self.block_producer.apply_state(states).map_err(|e| match e {
+            BlockProductionError::PendingTransactions => DevApiError::PendingTransactions,
+            _ => DevApiError::FailedToLoadState,
+        })?;
+        Ok(())
+    }
+
+    pub fn set_nonce(&self, address: ContractAddress, nonce: Nonce) -> Result<(), DevApiError> {
+        let err = |_: ProviderError| DevApiError::FailedToSetNonce;
+        let state = self.backend.blockchain.provider().latest().map_err(err)?;
+        if state.class_hash_of_contract(address).map_err(err)?.is_none() {
+            return Err(DevApiError::ContractNotFound);
+        }
+
+        let mut states = StateUpdatesWithClasses::default();
+        states.state_updates.nonce_updates.insert(address, nonce);
+        self.block_producer.apply_state(states).map_err(|e| match e {
+            BlockProductionError::PendingTransactions => DevApiError::PendingTransactions,
+            _ => DevApiError::FailedToSetNonce,
+        })?;
+        Ok(())
+    }
+
+    pub fn world_schema(&self, world: ContractAddress) -> Result<Vec<WorldModel>, DevApiError> {
+        let provider = self.backend.blockchain.provider();
+        let err = |_: ProviderError| DevApiError::FailedToGetWorldSchema;
+
+        let state = provider.latest().map_err(err)?;
+        let class_hash = state.class_hash_of_contract(world).map_err(err)?;
+        let class_hash = class_hash.ok_or(DevApiError::ContractNotFound)?;
+        let class = state.class(class_hash).map_err(err)?.ok_or(DevApiError::ContractNotFound)?;
+
+        let latest_num = provider.latest_number().map_err(err)?;
+
+        // The registered models are only discoverable through the events emitted by the world, so
+        // they are indexed as the chain grows instead of scanning the whole chain on every call.
+        let models = {
+            let mut worlds = self.world_models.lock().expect("poisoned lock");
+            let genesis = self.backend.chain_spec.genesis().number;
+            let world_models = worlds
+                .entry(world)
+                .or_insert_with(|| WorldModels { cursor: genesis, models: Vec::new() });
+
+            self.sync_world_models(world, &class, world_models, latest_num)?;
+            world_models.models.clone()
+        };
+
+        let block_env = provider
+            .block_env_at(latest_num.into())
+            .map_err(err)?
+            .ok_or(DevApiError::FailedToGetWorldSchema)?;
+        let executor = self.backend.executor_factory.with_state_and_block_env(state, block_env);
+
+        models
+            .into_iter()
+            .map(|model| {
+                let call = EntryPointCall {
+                    contract_address: model.address,
+                    entry_point_selector: selector!("schema"),
+                    calldata: Vec::new(),
+                };
+
+                let retdata =
+                    executor.call(call).map_err(|_| DevApiError::FailedToGetWorldSchema)?;
+                let schema =
+                    Struct::from_felts(&retdata).ok_or(DevApiError::FailedToGetWorldSchema)?;
+
+                Ok(WorldModel {
+                    namespace: model.namespace,
+                    name: model.name,
+                    class_hash: model.class_hash,
+                    address: model.address,
+                    schema,
+                })
+            })
+            .collect()
+    }
+
+    /// Adds the models registered or upgraded in the blocks mined since the last sync to the
+    /// models of `world`. The index is left untouched if any of the events can't be decoded.
+    fn sync_world_models(
+        &self,
+        world: ContractAddress,
+        class: &ContractClass,
+        world_models: &mut WorldModels,
+        latest_num: BlockNumber,
+    ) -> Result<(), DevApiError> {
+        let provider = self.backend.blockchain.provider();
+        let err = |_: ProviderError| DevApiError::FailedToGetWorldSchema;
+
+        let mut models = world_models.models.clone();
+
+        for num in world_models.cursor..=latest_num {
+            let receipts = provider.receipts_by_block(num.into()).map_err(err)?.unwrap_or_default();
+            let events =
+                receipts.iter().flat_map(|r| r.events()).filter(|e| e.from_address == world);
+
+            for event in events {
+                let Some(event) = class.decode_event(&event.keys, &event.data) else { continue };
+
+                match event.name.as_str() {
+                    "ModelRegistered" => models.push(RegisteredModel {
+                        namespace: string_field(&event, "namespace")?,
+                        name: string_field(&event, "name")?,
+                        class_hash: felt_field(&event, "class_hash")?,
+                        address: felt_field(&event, "address")?.into(),
+                    }),
+
+                    // upgrading a model deploys a new contract for it
+                    "ModelUpgraded" => {
+                        let prev_address =
+                            ContractAddress::from(felt_field(&event, "prev_address")?);
+                        if let Some(model) = models.iter_mut().find(|m| m.address == prev_address) {
+                            model.class_hash = felt_field(&event, "class_hash")?;
+                            model.address = felt_field(&event, "address")?.into();
+                        }
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+
+        world_models.models = models;
+        world_models.cursor = latest_num + 1;
+        Ok(())
+    }
+}
+
+/// A model registration found in the events of a world.
+#[derive(Debug, Clone)]
+struct RegisteredModel {
+    namespace: String,
+    name: String,
+    class_hash: ClassHash,
+    address: ContractAddress,
+}
+
+fn field<'a>(event: &'a DecodedEvent, name: &str) -> Result<&'a DecodedValue, DevApiError> {
+    let field = event.fields.iter().find(|f| f.name == name);
+    field.map(|f| &f.value).ok_or(DevApiError::FailedToGetWorldSchema)
+}
+
+fn felt_field(event: &DecodedEvent, name: &str) -> Result<Felt, DevApiError> {
+    match field(event, name)? {
+        DecodedValue::Felt(felt) => Ok(*felt),
+        _ => Err(DevApiError::FailedToGetWorldSchema),
+    }
+}
+
+fn string_field(event: &DecodedEvent, name: &str) -> Result<String, DevApiError> {
+    match field(event, name)? {
+        DecodedValue::String(value) => Ok(value.clone()),
+        _ => Err(DevApiError::FailedToGetWorldSchema),
+    }
+}
+
+/// Collects the events emitted by `call` and its inner calls, along with their emission order.
+fn collect_events(
+    call: &CallInfo,
+    state: &dyn StateProvider,
+    classes: &mut HashMap<ClassHash, Option<ContractClass>>,
+    events: &mut Vec<(u64, TraceEvent)>,
+) -> ProviderResult<()> {
+    let class = match call.class_hash {
+        Some(hash) => match classes.entry(hash) {
+            Entry::Occupied(entry) => entry.into_mut().as_ref(),
+            Entry::Vacant(entry) => entry.insert(state.class(hash)?).as_ref(),
+        },
+        None => None,
+    };
+
+    for event in &call.events {
+        let decoded = class.and_then(|class| class.decode_event(&event.keys, &event.data));
+        let trace_event = TraceEvent {
+            decoded,
+            from_address: call.contract_address,
+            keys: event.keys.clone(),
+            data: event.data.clone(),
+        };
+        events.push((event.order, trace_event));
+    }
+
+    for inner in &call.inner_calls {
+        collect_events(inner, state, classes, events)?;
+    }
+
+    Ok(())
 }
 
 #[async_trait]
@@ -66,6 +611,14 @@ impl<EF: ExecutorFactory> DevApiServer for DevApi<EF> {
         Ok(())
     }
 
+    async fn mine(
+        &self,
+        count: Option<u64>,
+        interval: Option<u64>,
+    ) -> Result<Vec<BlockHash>, Error> {
+        Ok(self.mine(count.unwrap_or(1), interval)?)
+    }
+
     async fn next_block_timestamp(&self) -> Result<(), Error> {
         // Ok(self.sequencer.backend().env.read().block.block_timestamp.0)
         Ok(())
@@ -95,4 +648,72 @@ impl<EF: ExecutorFactory> DevApiServer for DevApi<EF> {
     async fn predeployed_accounts(&self) -> Result<Vec<Account>, Error> {
         Ok(self.backend.chain_spec.genesis().accounts().map(|e| Account::new(*e.0, e.1)).collect())
     }
+
+    async fn get_pending_l1_messages(&self) -> Result<Vec<MsgToL1>, Error> {
+        Ok(self.pending_l1_messages()?)
+    }
+
+    async fn consume_l1_message(&self, message_hash: Hash256) -> Result<(), Error> {
+        Ok(self.consume_l1_message(message_hash)?)
+    }
+
+    async fn send_l1_message(&self, message: MsgFromL1) -> Result<TxHash, Error> {
+        Ok(self.send_l1_message(message)?)
+    }
+
+    async fn get_transaction_events(
+        &self,
+        transaction_hash: TxHash,
+    ) -> Result<Vec<TraceEvent>, Error> {
+        Ok(self.transaction_events(transaction_hash)?)
+    }
+
+    async fn get_transaction_fee(&self, transaction_hash: TxHash) -> Result<FeeBreakdown, Error> {
+        Ok(self.transaction_fee(transaction_hash)?)
+    }
+
+    async fn get_transaction_resources(
+        &self,
+        transaction_hash: TxHash,
+    ) -> Result<Vec<CallResources>, Error> {
+        Ok(self.transaction_resources(transaction_hash)?)
+    }
+
+    async fn get_world_schema(
+        &self,
+        world_address: ContractAddress,
+    ) -> Result<Vec<WorldModel>, Error> {
+        Ok(self.world_schema(world_address)?)
+    }
+
+    async fn get_contract_storage(
+        &self,
+        contract_address: ContractAddress,
+    ) -> Result<Vec<StorageEntry>, Error> {
+        Ok(self.contract_storage(contract_address)?)
+    }
+
+    async fn get_storages_at(
+        &self,
+        block_id: BlockIdOrTag,
+        contracts_storage_keys: Vec<ContractStorageKeys>,
+    ) -> Result<Vec<Vec<Felt>>, Error> {
+        Ok(self.storages_at(block_id, contracts_storage_keys)?)
+    }
+
+    async fn dump_state(&self) -> Result<StateDump, Error> {
+        Ok(self.dump_state()?)
+    }
+
+    async fn load_state(&self, state: StateDump) -> Result<(), Error> {
+        Ok(self.load_state(state)?)
+    }
+
+    async fn set_nonce(
+        &self,
+        contract_address: ContractAddress,
+        nonce: Nonce,
+    ) -> Result<(), Error> {
+        Ok(self.set_nonce(contract_address, nonce)?)
+    }
 }
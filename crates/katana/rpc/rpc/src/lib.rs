@@ -19,14 +19,19 @@ pub mod cors;
 pub mod dev;
 pub mod health;
 pub mod metrics;
+pub mod rate_limit;
 pub mod saya;
 pub mod starknet;
 pub mod torii;
+pub mod version;
+
 mod utils;
 
 use cors::Cors;
 use health::HealthCheck;
 use metrics::RpcServerMetrics;
+use rate_limit::RateLimit;
+use version::VersionedRoutes;
 
 /// The default maximum number of concurrent RPC connections.
 pub const DEFAULT_RPC_MAX_CONNECTIONS: u32 = 100;
@@ -34,6 +39,8 @@ pub const DEFAULT_RPC_MAX_CONNECTIONS: u32 = 100;
 pub const DEFAULT_MAX_REQUEST_BODY_SIZE: u32 = TEN_MB_SIZE_BYTES;
 /// The default maximum size in bytes for an RPC response body.
 pub const DEFAULT_MAX_RESPONSE_BODY_SIZE: u32 = TEN_MB_SIZE_BYTES;
+/// The default maximum duration of an RPC request.
+pub const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(20);
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -60,6 +67,10 @@ impl RpcServerHandle {
     }
 
     /// Wait until the server has stopped.
+    ///
+    /// Once stopped, the server no longer accepts new connections but the requests that are already
+    /// being processed are still served. This resolves only after all of them have completed, which
+    /// is bounded by the server's request timeout.
     pub async fn stopped(self) {
         self.handle.stopped().await
     }
@@ -75,10 +86,12 @@ pub struct RpcServer {
     metrics: bool,
     cors: Option<Cors>,
     health_check: bool,
+    rate_limit: Option<RateLimit>,
     module: RpcModule<()>,
     max_connections: u32,
     max_request_body_size: u32,
     max_response_body_size: u32,
+    timeout: Duration,
 }
 
 impl RpcServer {
@@ -87,10 +100,12 @@ impl RpcServer {
             cors: None,
             metrics: false,
             health_check: false,
+            rate_limit: None,
             module: RpcModule::new(()),
             max_connections: 100,
             max_request_body_size: TEN_MB_SIZE_BYTES,
             max_response_body_size: TEN_MB_SIZE_BYTES,
+            timeout: DEFAULT_RPC_TIMEOUT,
         }
     }
 
@@ -112,6 +127,13 @@ impl RpcServer {
         self
     }
 
+    /// Set the maximum duration of a request. Requests that take longer are aborted with an error.
+    /// Default is 20 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
     /// Collect metrics about the RPC server.
     ///
     /// See top level module of [`crate::metrics`] to see what metrics are collected.
@@ -131,6 +153,12 @@ impl RpcServer {
         self
     }
 
+    /// Limit the rate of requests that each connection can make. Disabled by default.
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = Some(limit);
+        self
+    }
+
     pub fn module(mut self, module: RpcModule<()>) -> Self {
         self.module = module;
         self
@@ -148,8 +176,10 @@ impl RpcServer {
 
         let middleware = ServiceBuilder::new()
             .option_layer(self.cors.clone())
+            .layer(VersionedRoutes)
             .option_layer(health_check_proxy)
-            .timeout(Duration::from_secs(20));
+            .option_layer(self.rate_limit)
+            .timeout(self.timeout);
 
         let builder = ServerBuilder::new()
             .set_middleware(middleware)
@@ -0,0 +1,189 @@
+//! Per-connection rate limiting for the RPC server.
+//!
+//! Every connection gets its own [token bucket]: it starts full with `burst` tokens, each request
+//! consumes one token, and tokens are refilled at a rate of `requests_per_second`. Requests that
+//! arrive when the bucket is empty are rejected with a JSON-RPC error instead of being forwarded
+//! to the RPC modules.
+//!
+//! [token bucket]: https://en.wikipedia.org/wiki/Token_bucket
+
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use futures::future::{self, Either, Ready};
+use http::{header, HeaderValue, Request, Response, StatusCode};
+use serde_json::json;
+use tower::{Layer, Service};
+
+/// The JSON-RPC error code returned when a request is rejected by the rate limiter.
+pub const RATE_LIMIT_EXCEEDED_CODE: i32 = -32005;
+/// The JSON-RPC error message returned when a request is rejected by the rate limiter.
+pub const RATE_LIMIT_EXCEEDED_MESSAGE: &str = "Rate limit exceeded";
+
+/// Layer that applies the [`RateLimitService`] middleware.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    requests_per_second: u32,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// Allow up to `requests_per_second` requests per second on every connection, with bursts of
+    /// the same size.
+    pub fn new(requests_per_second: u32) -> Self {
+        Self { requests_per_second, burst: requests_per_second }
+    }
+
+    /// Set the maximum number of requests that can be served at once before the rate limit kicks
+    /// in. Default is the same as the number of requests per second.
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst;
+        self
+    }
+}
+
+impl<S> Layer<S> for RateLimit {
+    type Service = RateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        // The layer is applied once for every new connection, so each connection gets its own
+        // bucket.
+        let bucket = TokenBucket::new(self.burst, self.requests_per_second);
+        RateLimitService { inner, bucket: Arc::new(Mutex::new(bucket)) }
+    }
+}
+
+/// Middleware that rejects requests once the connection has exhausted its rate limit.
+#[derive(Debug, Clone)]
+pub struct RateLimitService<S> {
+    inner: S,
+    bucket: Arc<Mutex<TokenBucket>>,
+}
+
+impl<S, B, ResBody> Service<Request<B>> for RateLimitService<S>
+where
+    S: Service<Request<B>, Response = Response<ResBody>>,
+    ResBody: From<String>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Either<S::Future, Ready<Result<Self::Response, Self::Error>>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let acquired = self.bucket.lock().expect("poisoned lock").try_acquire(Instant::now());
+
+        if acquired {
+            Either::Left(self.inner.call(req))
+        } else {
+            Either::Right(future::ok(rate_limited_response()))
+        }
+    }
+}
+
+fn rate_limited_response<B: From<String>>() -> Response<B> {
+    let body = json!({
+        "jsonrpc": "2.0",
+        "error": { "code": RATE_LIMIT_EXCEEDED_CODE, "message": RATE_LIMIT_EXCEEDED_MESSAGE },
+        "id": null
+    });
+
+    let mut response = Response::new(B::from(body.to_string()));
+    *response.status_mut() = StatusCode::TOO_MANY_REQUESTS;
+    response
+        .headers_mut()
+        .insert(header::CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    response
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    /// The maximum number of tokens the bucket can hold.
+    capacity: f64,
+    /// The number of tokens added to the bucket every second.
+    refill_rate: f64,
+    /// The number of tokens currently available.
+    tokens: f64,
+    /// The last time the bucket was refilled.
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: u32, refill_rate: u32) -> Self {
+        let capacity = capacity as f64;
+        Self {
+            capacity,
+            refill_rate: refill_rate as f64,
+            tokens: capacity,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Takes a token from the bucket if one is available, returning whether it was taken.
+    fn try_acquire(&mut self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_rate).min(self.capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn token_bucket_allows_burst_then_refills() {
+        let mut bucket = TokenBucket::new(3, 2);
+        let start = bucket.last_refill;
+
+        // the whole burst is available right away
+        assert!(bucket.try_acquire(start));
+        assert!(bucket.try_acquire(start));
+        assert!(bucket.try_acquire(start));
+        assert!(!bucket.try_acquire(start));
+
+        // at 2 requests per second, a token is refilled every 500ms
+        assert!(!bucket.try_acquire(start + Duration::from_millis(250)));
+        assert!(bucket.try_acquire(start + Duration::from_millis(500)));
+        assert!(!bucket.try_acquire(start + Duration::from_millis(500)));
+
+        // the bucket never holds more than its capacity
+        let later = start + Duration::from_secs(60);
+        assert!(bucket.try_acquire(later));
+        assert!(bucket.try_acquire(later));
+        assert!(bucket.try_acquire(later));
+        assert!(!bucket.try_acquire(later));
+    }
+
+    #[test]
+    fn zero_burst_rejects_everything() {
+        let mut bucket = TokenBucket::new(0, 10);
+        let start = bucket.last_refill;
+        assert!(!bucket.try_acquire(start));
+        assert!(!bucket.try_acquire(start + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn rate_limited_response_is_jsonrpc_error() {
+        let response: Response<String> = rate_limited_response();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        let body: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert_eq!(body["error"]["code"], RATE_LIMIT_EXCEEDED_CODE);
+        assert_eq!(body["error"]["message"], RATE_LIMIT_EXCEEDED_MESSAGE);
+        assert!(body["id"].is_null());
+    }
+}
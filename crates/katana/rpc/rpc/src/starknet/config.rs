@@ -1,3 +1,5 @@
+use std::num::NonZeroUsize;
+
 use url::Url;
 
 #[derive(Debug, Clone)]
@@ -12,6 +14,18 @@ pub struct StarknetApiConfig {
     /// If `None`, the maximum keys size is bounded by [`u64::MAX`].
     pub max_proof_keys: Option<u64>,
 
+    /// The multiplier applied to the estimated fee of V1 transactions sent with a zero max fee, in
+    /// basis points, eg `15_000` for 1.5.
+    ///
+    /// If `None`, the max fee of the transactions is left untouched.
+    pub auto_max_fee: Option<u64>,
+
+    /// The maximum number of compiled classes kept in the cache of the `getCompiledCasm` method.
+    ///
+    /// Set to the size of the compiled class cache of the executor, so that both caches are bound
+    /// by the same configuration.
+    pub class_cache_size: NonZeroUsize,
+
     #[cfg(feature = "cartridge")]
     pub paymaster: Option<PaymasterConfig>,
 }
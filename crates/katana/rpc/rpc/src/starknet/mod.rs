@@ -1,6 +1,6 @@
 //! Server implementation for the Starknet JSON-RPC API.
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use katana_core::backend::Backend;
 use katana_core::service::block_producer::{BlockProducer, BlockProducerMode, PendingExecutor};
@@ -10,7 +10,7 @@ use katana_primitives::block::{
     BlockHash, BlockHashOrNumber, BlockIdOrTag, BlockNumber, BlockTag, FinalityStatus,
     PartialHeader,
 };
-use katana_primitives::class::ClassHash;
+use katana_primitives::class::{CasmContractClass, ClassHash, CompiledClass, ContractClass};
 use katana_primitives::contract::{ContractAddress, Nonce, StorageKey, StorageValue};
 use katana_primitives::da::L1DataAvailabilityMode;
 use katana_primitives::env::BlockEnv;
@@ -34,7 +34,7 @@ use katana_rpc_types::class::RpcContractClass;
 use katana_rpc_types::error::starknet::StarknetApiError;
 use katana_rpc_types::event::{EventFilterWithPage, EventsPage};
 use katana_rpc_types::receipt::{ReceiptBlock, TxReceiptWithBlockInfo};
-use katana_rpc_types::state_update::MaybePendingStateUpdate;
+use katana_rpc_types::state_update::{MaybePendingStateUpdate, StateDiff};
 use katana_rpc_types::transaction::Tx;
 use katana_rpc_types::trie::{
     ClassesProof, ContractLeafData, ContractStorageKeys, ContractStorageProofs, ContractsProof,
@@ -43,8 +43,9 @@ use katana_rpc_types::trie::{
 use katana_rpc_types::FeeEstimate;
 use katana_rpc_types_builder::ReceiptBuilder;
 use katana_tasks::{BlockingTaskPool, TokioTaskSpawner};
+use lru::LruCache;
 use starknet::core::types::{
-    PriceUnit, ResultPageRequest, TransactionExecutionStatus, TransactionStatus,
+    PendingStateUpdate, PriceUnit, ResultPageRequest, TransactionExecutionStatus, TransactionStatus,
 };
 
 use crate::utils;
@@ -85,6 +86,9 @@ where
     blocking_task_pool: BlockingTaskPool,
     block_producer: Option<BlockProducer<EF>>,
     config: StarknetApiConfig,
+    /// Cache of the compiled CASM of the most recently requested Sierra classes, keyed by their
+    /// class hash.
+    compiled_casm: Mutex<LruCache<ClassHash, CasmContractClass>>,
 }
 
 impl<EF> StarknetApi<EF>
@@ -126,6 +130,7 @@ where
             block_producer,
             blocking_task_pool,
             forked_client,
+            compiled_casm: Mutex::new(LruCache::new(config.class_cache_size)),
             config,
         };
 
@@ -275,6 +280,37 @@ where
         .await
     }
 
+    async fn compiled_casm(&self, class_hash: ClassHash) -> StarknetApiResult<CasmContractClass> {
+        if let Some(casm) = self.inner.compiled_casm.lock().unwrap().get(&class_hash) {
+            return Ok(casm.clone());
+        }
+
+        self.on_cpu_blocking_task(move |this| {
+            let state = this.state(&BlockIdOrTag::Tag(BlockTag::Pending))?;
+
+            let class = match state.class(class_hash)? {
+                Some(ContractClass::Class(class)) => class,
+                // legacy classes are not compiled to CASM
+                Some(ContractClass::Legacy(..)) => {
+                    return Err(StarknetApiError::UnsupportedContractClassVersion);
+                }
+                None => return Err(StarknetApiError::ClassHashNotFound),
+            };
+
+            let compiled = ContractClass::Class(class)
+                .compile()
+                .map_err(|_| StarknetApiError::CompilationFailed)?;
+
+            let CompiledClass::Class(casm) = compiled else {
+                unreachable!("sierra class must be compiled to casm")
+            };
+
+            this.inner.compiled_casm.lock().unwrap().put(class_hash, casm.clone());
+            Ok(casm)
+        })
+        .await
+    }
+
     async fn class_hash_at_address(
         &self,
         block_id: BlockIdOrTag,
@@ -814,7 +850,15 @@ where
                     }
 
                     BlockIdOrTag::Tag(BlockTag::Pending) => {
-                        return Err(StarknetApiError::BlockNotFound);
+                        if let Some(exec) = this.pending_executor() {
+                            // the pending diff is applied on top of the latest state
+                            let old_root = provider.latest()?.state_root()?;
+                            let state_diff = StateDiff::from(exec.read().state_updates()).0;
+                            let update = PendingStateUpdate { old_root, state_diff };
+                            return Ok(Some(MaybePendingStateUpdate::Pending(update.into())));
+                        }
+
+                        provider.latest_number().map(BlockHashOrNumber::Num)?
                     }
                 };
 
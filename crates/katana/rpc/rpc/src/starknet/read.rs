@@ -6,7 +6,7 @@ use anyhow::anyhow;
 use jsonrpsee::core::{async_trait, Error, RpcResult};
 use katana_executor::{EntryPointCall, ExecutorFactory};
 use katana_primitives::block::BlockIdOrTag;
-use katana_primitives::class::ClassHash;
+use katana_primitives::class::{CasmContractClass, ClassHash};
 #[cfg(feature = "cartridge")]
 use katana_primitives::genesis::allocation::GenesisAccountAlloc;
 use katana_primitives::transaction::{ExecutableTx, ExecutableTxWithHash, TxHash};
@@ -131,6 +131,10 @@ impl<EF: ExecutorFactory> StarknetApiServer for StarknetApi<EF> {
         Ok(self.class_at_hash(block_id, class_hash).await?)
     }
 
+    async fn get_compiled_casm(&self, class_hash: ClassHash) -> RpcResult<CasmContractClass> {
+        Ok(self.compiled_casm(class_hash).await?)
+    }
+
     async fn get_events(&self, filter: EventFilterWithPage) -> RpcResult<EventsPage> {
         Ok(self.events(filter).await?)
     }
@@ -1,7 +1,11 @@
 use jsonrpsee::core::{async_trait, RpcResult};
-use katana_executor::ExecutorFactory;
+use katana_executor::{ExecutionFlags, ExecutorFactory};
 use katana_pool::TransactionPool;
-use katana_primitives::transaction::{ExecutableTx, ExecutableTxWithHash};
+use katana_primitives::block::{BlockIdOrTag, BlockTag};
+use katana_primitives::transaction::{
+    DeclareTx, DeployAccountTx, ExecutableTx, ExecutableTxWithHash, InvokeTx, TxHash,
+};
+use katana_provider::traits::transaction::TransactionProvider;
 use katana_rpc_api::starknet::StarknetWriteApiServer;
 use katana_rpc_types::error::starknet::StarknetApiError;
 use katana_rpc_types::transaction::{
@@ -11,7 +15,70 @@ use katana_rpc_types::transaction::{
 
 use super::StarknetApi;
 
+/// The number of basis points in a multiplier of 1.
+const BASIS_POINTS: u128 = 10_000;
+
 impl<EF: ExecutorFactory> StarknetApi<EF> {
+    /// Sets the max fee of a V1 transaction sent with a zero max fee to its estimated fee, padded
+    /// by the configured multiplier. Transactions are left untouched if the feature is disabled.
+    ///
+    /// The hash of the transaction isn't recomputed, so that the signature of the sender, which is
+    /// over the hash of the transaction as it was sent, remains valid. The hash is thus
+    /// inconsistent with the max fee the transaction is executed with.
+    fn apply_auto_max_fee(
+        &self,
+        mut tx: ExecutableTxWithHash,
+    ) -> Result<ExecutableTxWithHash, StarknetApiError> {
+        let Some(multiplier_bps) = self.inner.config.auto_max_fee else { return Ok(tx) };
+
+        if !matches!(legacy_max_fee_mut(&mut tx.transaction), Some(0)) {
+            return Ok(tx);
+        }
+
+        let account_validation =
+            self.inner.backend.executor_factory.execution_flags().account_validation();
+        let flags = ExecutionFlags::new()
+            .with_account_validation(account_validation)
+            .with_nonce_check(false);
+
+        let block_id = BlockIdOrTag::Tag(BlockTag::Pending);
+        let estimate = self.estimate_fee_with(vec![tx.clone()], block_id, flags)?;
+        let fee = u128::try_from(estimate[0].overall_fee).map_err(|_| {
+            StarknetApiError::UnexpectedError { reason: "Estimated fee overflows".to_string() }
+        })?;
+
+        let padded_fee = fee
+            .checked_mul(u128::from(multiplier_bps))
+            .map(|fee| fee / BASIS_POINTS)
+            .ok_or_else(|| StarknetApiError::UnexpectedError {
+                reason: "Padded max fee overflows".to_string(),
+            })?;
+
+        if let Some(max_fee) = legacy_max_fee_mut(&mut tx.transaction) {
+            *max_fee = padded_fee;
+        }
+
+        Ok(tx)
+    }
+
+    /// Returns an error if a transaction with the same hash has already been executed, so that the
+    /// same transaction can't be included twice. Transactions that are still in the pool are
+    /// rejected by the pool itself.
+    fn ensure_new_transaction(&self, hash: TxHash) -> Result<(), StarknetApiError> {
+        let provider = self.inner.backend.blockchain.provider();
+        let included = provider.transaction_by_hash(hash)?.is_some();
+
+        let pending = self.pending_executor().is_some_and(|executor| {
+            executor.read().transactions().iter().any(|(tx, _)| tx.hash == hash)
+        });
+
+        if included || pending {
+            Err(StarknetApiError::DuplicateTransaction)
+        } else {
+            Ok(())
+        }
+    }
+
     async fn add_invoke_transaction_impl(
         &self,
         tx: BroadcastedInvokeTx,
@@ -23,6 +90,8 @@ impl<EF: ExecutorFactory> StarknetApi<EF> {
 
             let tx = tx.into_tx_with_chain_id(this.inner.backend.chain_spec.id());
             let tx = ExecutableTxWithHash::new(ExecutableTx::Invoke(tx));
+            this.ensure_new_transaction(tx.hash)?;
+            let tx = this.apply_auto_max_fee(tx)?;
             let hash = this.inner.pool.add_transaction(tx)?;
 
             Ok(hash.into())
@@ -45,6 +114,8 @@ impl<EF: ExecutorFactory> StarknetApi<EF> {
 
             let class_hash = tx.class_hash();
             let tx = ExecutableTxWithHash::new(ExecutableTx::Declare(tx));
+            this.ensure_new_transaction(tx.hash)?;
+            let tx = this.apply_auto_max_fee(tx)?;
             let hash = this.inner.pool.add_transaction(tx)?;
 
             Ok((hash, class_hash).into())
@@ -65,6 +136,8 @@ impl<EF: ExecutorFactory> StarknetApi<EF> {
             let contract_address = tx.contract_address();
 
             let tx = ExecutableTxWithHash::new(ExecutableTx::DeployAccount(tx));
+            this.ensure_new_transaction(tx.hash)?;
+            let tx = this.apply_auto_max_fee(tx)?;
             let hash = this.inner.pool.add_transaction(tx)?;
 
             Ok((hash, contract_address).into())
@@ -73,6 +146,20 @@ impl<EF: ExecutorFactory> StarknetApi<EF> {
     }
 }
 
+/// Returns the max fee of a transaction, if it's a version that specifies its fee with a max fee.
+fn legacy_max_fee_mut(tx: &mut ExecutableTx) -> Option<&mut u128> {
+    match tx {
+        ExecutableTx::Invoke(InvokeTx::V1(tx)) => Some(&mut tx.max_fee),
+        ExecutableTx::Declare(tx) => match &mut tx.transaction {
+            DeclareTx::V1(tx) => Some(&mut tx.max_fee),
+            DeclareTx::V2(tx) => Some(&mut tx.max_fee),
+            _ => None,
+        },
+        ExecutableTx::DeployAccount(DeployAccountTx::V1(tx)) => Some(&mut tx.max_fee),
+        _ => None,
+    }
+}
+
 #[async_trait]
 impl<EF: ExecutorFactory> StarknetWriteApiServer for StarknetApi<EF> {
     async fn add_invoke_transaction(
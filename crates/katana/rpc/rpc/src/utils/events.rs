@@ -98,14 +98,15 @@ pub fn fetch_pending_events(
     let txs = pending_block.transactions();
     let cursor = cursor.unwrap_or(Cursor::new_block(block_env.number));
 
-    // process individual transactions in the block.
-    // the iterator will start with txn index == cursor.txn.idx
-    for (tx_idx, (tx_hash, events)) in txs
+    // only transactions that were successfully executed are included in the block
+    let txs = txs
         .iter()
         .filter_map(|(tx, res)| res.receipt().map(|receipt| (tx.hash, receipt.events())))
-        .enumerate()
-        .skip(cursor.txn.idx)
-    {
+        .collect::<Vec<_>>();
+
+    // process individual transactions in the block.
+    // the iterator will start with txn index == cursor.txn.idx
+    for (tx_idx, (tx_hash, events)) in txs.iter().copied().enumerate().skip(cursor.txn.idx) {
         if tx_idx == cursor.txn.idx {
             match events.len().cmp(&cursor.txn.event) {
                 Ordering::Equal | Ordering::Greater => {}
@@ -220,24 +221,25 @@ pub fn fetch_events_at_blocks(
     Ok(None)
 }
 
-/// An iterator that yields events that match the given filters.
+/// An iterator that yields events that match the given filters, along with their index in the
+/// transaction.
 #[derive(Debug)]
-struct FilteredEvents<'a, I: Iterator<Item = &'a Event>> {
+struct FilteredEvents<'a, I: Iterator<Item = (usize, &'a Event)>> {
     iter: I,
     filter: &'a Filter,
 }
 
-impl<'a, I: Iterator<Item = &'a Event>> FilteredEvents<'a, I> {
+impl<'a, I: Iterator<Item = (usize, &'a Event)>> FilteredEvents<'a, I> {
     fn new(iter: I, filter: &'a Filter) -> Self {
         Self { iter, filter }
     }
 }
 
-impl<'a, I: Iterator<Item = &'a Event>> Iterator for FilteredEvents<'a, I> {
-    type Item = &'a Event;
+impl<'a, I: Iterator<Item = (usize, &'a Event)>> Iterator for FilteredEvents<'a, I> {
+    type Item = (usize, &'a Event);
 
     fn next(&mut self) -> Option<Self::Item> {
-        for event in self.iter.by_ref() {
+        for (idx, event) in self.iter.by_ref() {
             // Check if the event matches the address filter
             if !self.filter.address.map_or(true, |addr| addr == event.from_address) {
                 continue;
@@ -268,7 +270,7 @@ impl<'a, I: Iterator<Item = &'a Event>> Iterator for FilteredEvents<'a, I> {
             };
 
             if is_matched {
-                return Some(event);
+                return Some((idx, event));
             }
         }
 
@@ -309,40 +311,35 @@ fn fetch_tx_events(
     // number of events we have taken.
     let total_can_take = chunk_size.saturating_sub(buffer.len());
 
-    // skip events according to the continuation token.
-    let filtered = FilteredEvents::new(events.iter(), filter)
-        .map(|e| EmittedEvent {
-            block_hash,
-            block_number,
-            keys: e.keys.clone(),
-            data: e.data.clone(),
-            transaction_hash: tx_hash,
-            from_address: e.from_address.into(),
-        })
-        // enumerate so that we can keep track of the event's index in the transaction
-        .enumerate()
-        .skip(next_event_idx)
+    // skip events according to the continuation token. the events are enumerated before being
+    // filtered so that the index always refers to the event's position in the transaction,
+    // regardless of the filter being used.
+    let filtered = FilteredEvents::new(events.iter().enumerate().skip(next_event_idx), filter)
         .take(total_can_take)
+        .map(|(idx, e)| {
+            let event = EmittedEvent {
+                block_hash,
+                block_number,
+                keys: e.keys.clone(),
+                data: e.data.clone(),
+                transaction_hash: tx_hash,
+                from_address: e.from_address.into(),
+            };
+            (idx, event)
+        })
         .collect::<Vec<_>>();
 
-    // remaining possible events that we haven't seen due to the chunk size limit.
-    let total_events_traversed = next_event_idx + total_can_take;
-
-    // get the index of the last matching event that we have reached. if there is not
-    // matching events (ie `filtered` is empty) we point to the end of the chunk
-    // we've covered thus far using the iterator..
-    let last_event_idx = filtered.last().map(|(idx, _)| *idx).unwrap_or(total_events_traversed);
+    // get the index of the last matching event that we have taken, if any.
+    let last_event_idx = filtered.last().map(|(idx, _)| *idx);
     buffer.extend(filtered.into_iter().map(|(_, event)| event));
 
     if buffer.len() >= chunk_size {
         // the next time we have to fetch the events, we will start from this index.
-        let new_last_event = if total_can_take == 0 {
-            // start from the same event pointed by the
-            // current cursor..
-            last_event_idx
-        } else {
-            // start at the next event of the last event we've filtered out.
-            last_event_idx + 1
+        let new_last_event = match last_event_idx {
+            // start at the next event of the last event we've taken.
+            Some(idx) => idx + 1,
+            // nothing was taken, so start from the same event pointed by the current cursor.
+            None => next_event_idx,
         };
 
         // if there are still more events that we haven't fetched yet for this tx.
@@ -369,3 +366,74 @@ impl From<ContinuationToken> for Cursor {
         Cursor::new(token.block_n, token.txn_n as usize, token.event_n as usize)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use katana_primitives::{address, felt};
+
+    use super::*;
+
+    fn events() -> Vec<Event> {
+        (0u64..5)
+            .map(|i| Event {
+                from_address: address!("0x1"),
+                // only the events with an even index has the key `0x2`
+                keys: vec![if i % 2 == 0 { felt!("0x2") } else { felt!("0x3") }],
+                data: vec![Felt::from(i)],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fetch_tx_events_cursor_points_to_event_index_in_tx() {
+        let events = events();
+        let filter = Filter { address: None, keys: Some(vec![vec![felt!("0x2")]]) };
+
+        let mut buffer = Vec::new();
+        let cursor =
+            fetch_tx_events(0, None, None, 0, felt!("0xa"), &events, &filter, 2, &mut buffer)
+                .unwrap()
+                .expect("should return a cursor");
+
+        // the cursor points to the event after the last event taken (ie index 2)
+        assert_eq!(cursor, PartialCursor { idx: 0, event: 3 });
+        let data = buffer.iter().map(|e| e.data[0]).collect::<Vec<_>>();
+        assert_eq!(data, vec![Felt::from(0u64), Felt::from(2u64)]);
+
+        // continue from the cursor
+        let mut buffer = Vec::new();
+        let cursor = fetch_tx_events(
+            cursor.event,
+            None,
+            None,
+            0,
+            felt!("0xa"),
+            &events,
+            &filter,
+            2,
+            &mut buffer,
+        )
+        .unwrap();
+
+        // all the remaining matching events fit in the buffer
+        assert!(cursor.is_none());
+        let data = buffer.iter().map(|e| e.data[0]).collect::<Vec<_>>();
+        assert_eq!(data, vec![Felt::from(4u64)]);
+    }
+
+    #[test]
+    fn fetch_tx_events_with_full_buffer() {
+        let events = events();
+        let filter = Filter::default();
+
+        let mut buffer = Vec::new();
+        fetch_tx_events(0, None, None, 0, felt!("0xa"), &events, &filter, 5, &mut buffer).unwrap();
+        assert_eq!(buffer.len(), 5);
+
+        // the buffer is already full, so the cursor should point to the same event
+        let cursor =
+            fetch_tx_events(1, None, None, 1, felt!("0xb"), &events, &filter, 5, &mut buffer)
+                .unwrap();
+        assert_eq!(cursor, Some(PartialCursor { idx: 1, event: 1 }));
+    }
+}
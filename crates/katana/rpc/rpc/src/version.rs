@@ -0,0 +1,334 @@
+//! Versioned routes for the RPC server.
+//!
+//! Clients can pin the version of the Starknet JSON-RPC specification they expect by sending their
+//! requests to `/rpc/v<major>_<minor>`, eg `/rpc/v0_7`. Requests to the version implemented by the
+//! node, [`RPC_SPEC_VERSION`], are served as if they were sent to the root path, which always
+//! serves that version. Requests to any other version are rejected with a JSON-RPC error, so that
+//! clients don't end up parsing responses of a different shape than the one they expect.
+//!
+//! Requests to `/rpc/v0_6` are served as well, with the responses of the methods whose result
+//! changed in v0.7 converted back to their v0.6 shape:
+//!
+//! - `starknet_specVersion` returns [`V0_6_SPEC_VERSION`].
+//! - `starknet_estimateFee` and `starknet_estimateMessageFee` return fee estimates without the
+//!   data gas fields.
+//!
+//! The responses of the other methods are served in their current shape.
+
+use std::collections::HashMap;
+use std::task::{Context, Poll};
+
+use futures::future::BoxFuture;
+use http::uri::PathAndQuery;
+use http::{header, HeaderValue, Request, Response, StatusCode, Uri};
+use hyper::Body;
+use katana_rpc_api::starknet::RPC_SPEC_VERSION;
+use serde_json::{json, Value};
+use tower::{Layer, Service};
+
+/// The JSON-RPC error code returned when a request targets an unsupported specification version.
+pub const UNSUPPORTED_VERSION_CODE: i32 = -32600;
+
+/// The specification version returned by `starknet_specVersion` on the v0.6 route.
+pub const V0_6_SPEC_VERSION: &str = "0.6.0";
+
+/// The path prefix of the versioned routes.
+const ROUTE_PREFIX: &str = "/rpc";
+
+/// The route version of the v0.6 specification.
+const V0_6_ROUTE: &str = "v0_6";
+
+/// Layer that applies the [`VersionedRoutesService`] middleware.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VersionedRoutes;
+
+impl<S> Layer<S> for VersionedRoutes {
+    type Service = VersionedRoutesService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        VersionedRoutesService { inner, supported: route_version(RPC_SPEC_VERSION) }
+    }
+}
+
+/// Middleware that maps the versioned routes to the root path, converting the responses of the
+/// v0.6 route to their v0.6 shape.
+#[derive(Debug, Clone)]
+pub struct VersionedRoutesService<S> {
+    inner: S,
+    /// The route version of the specification implemented by the node, eg `v0_7`.
+    supported: String,
+}
+
+impl<S> Service<Request<Body>> for VersionedRoutesService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Error: From<hyper::Error> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<Body>) -> Self::Future {
+        let Some(version) = requested_version(req.uri().path()) else {
+            return Box::pin(self.inner.call(req));
+        };
+
+        // an unversioned `/rpc` route is served by the latest version
+        if version.is_empty() || version == self.supported {
+            *req.uri_mut() = root_uri(req.uri());
+            Box::pin(self.inner.call(req))
+        } else if version == V0_6_ROUTE {
+            *req.uri_mut() = root_uri(req.uri());
+
+            // the service that was polled ready is the one that must be called
+            let clone = self.inner.clone();
+            let inner = std::mem::replace(&mut self.inner, clone);
+            Box::pin(serve_v0_6(inner, req))
+        } else {
+            let supported = format!("{V0_6_ROUTE}, {}", self.supported);
+            let response = unsupported_version_response(version, &supported);
+            Box::pin(futures::future::ok(response))
+        }
+    }
+}
+
+/// Serves a request with the current version and converts its response to the v0.6 shape.
+async fn serve_v0_6<S>(mut inner: S, req: Request<Body>) -> Result<Response<Body>, S::Error>
+where
+    S: Service<Request<Body>, Response = Response<Body>>,
+    S::Error: From<hyper::Error>,
+{
+    let (parts, body) = req.into_parts();
+    let body = hyper::body::to_bytes(body).await?;
+    let methods = request_methods(&body);
+
+    let response = inner.call(Request::from_parts(parts, Body::from(body))).await?;
+    if methods.is_empty() {
+        return Ok(response);
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let body = hyper::body::to_bytes(body).await?;
+
+    match response_to_v0_6(&methods, &body) {
+        Some(body) => {
+            // the length of the converted body differs from the original one
+            parts.headers.remove(header::CONTENT_LENGTH);
+            Ok(Response::from_parts(parts, Body::from(body)))
+        }
+        None => Ok(Response::from_parts(parts, Body::from(body))),
+    }
+}
+
+/// Returns the methods of a single or batch request, by their request id. Notifications, which
+/// don't have a response, are ignored.
+fn request_methods(body: &[u8]) -> HashMap<String, String> {
+    let requests = match serde_json::from_slice(body) {
+        Ok(Value::Array(requests)) => requests,
+        Ok(request) => vec![request],
+        Err(_) => return HashMap::new(),
+    };
+
+    requests
+        .iter()
+        .filter_map(|req| {
+            let id = req.get("id")?;
+            let method = req.get("method")?.as_str()?;
+            Some((id.to_string(), method.to_string()))
+        })
+        .collect()
+}
+
+/// Converts the results of a single or batch response to their v0.6 shape. Returns `None` if the
+/// body isn't a JSON-RPC response.
+fn response_to_v0_6(methods: &HashMap<String, String>, body: &[u8]) -> Option<Vec<u8>> {
+    let mut body: Value = serde_json::from_slice(body).ok()?;
+
+    let responses = match &mut body {
+        Value::Array(responses) => responses.iter_mut().collect::<Vec<_>>(),
+        response => vec![response],
+    };
+
+    for response in responses {
+        let Some(method) = response.get("id").and_then(|id| methods.get(&id.to_string())) else {
+            continue;
+        };
+
+        if let Some(result) = response.get_mut("result") {
+            result_to_v0_6(method, result);
+        }
+    }
+
+    serde_json::to_vec(&body).ok()
+}
+
+/// Converts the result of a method to its v0.6 shape, if it differs from the current one.
+fn result_to_v0_6(method: &str, result: &mut Value) {
+    match method {
+        "starknet_specVersion" => *result = Value::from(V0_6_SPEC_VERSION),
+        "starknet_estimateFee" => {
+            if let Value::Array(estimates) = result {
+                estimates.iter_mut().for_each(fee_estimate_to_v0_6);
+            }
+        }
+        "starknet_estimateMessageFee" => fee_estimate_to_v0_6(result),
+        _ => {}
+    }
+}
+
+/// The data gas fields of the fee estimates were added in v0.7.
+fn fee_estimate_to_v0_6(estimate: &mut Value) {
+    if let Value::Object(fields) = estimate {
+        fields.remove("data_gas_consumed");
+        fields.remove("data_gas_price");
+    }
+}
+
+/// Returns the version requested by a path of the versioned routes, or `None` if the path isn't
+/// one of them.
+fn requested_version(path: &str) -> Option<&str> {
+    let rest = path.strip_prefix(ROUTE_PREFIX)?;
+    if rest.is_empty() {
+        return Some(rest);
+    }
+
+    let version = rest.strip_prefix('/')?;
+    Some(version.strip_suffix('/').unwrap_or(version))
+}
+
+/// Converts a specification version to its route version, eg `0.7.1` to `v0_7`.
+fn route_version(spec_version: &str) -> String {
+    let mut parts = spec_version.split('.');
+    let major = parts.next().unwrap_or_default();
+    let minor = parts.next().unwrap_or_default();
+    format!("v{major}_{minor}")
+}
+
+/// Returns the same uri but with the root path, keeping the query if any.
+fn root_uri(uri: &Uri) -> Uri {
+    let path_and_query = match uri.query() {
+        Some(query) => format!("/?{query}").parse().expect("valid path"),
+        None => PathAndQuery::from_static("/"),
+    };
+
+    let mut parts = uri.clone().into_parts();
+    parts.path_and_query = Some(path_and_query);
+    Uri::from_parts(parts).expect("valid uri")
+}
+
+fn unsupported_version_response<B: From<String>>(requested: &str, supported: &str) -> Response<B> {
+    let message = format!(
+        "Unsupported JSON-RPC specification version '{requested}', supported versions: \
+         {supported}"
+    );
+
+    let body = json!({
+        "jsonrpc": "2.0",
+        "error": { "code": UNSUPPORTED_VERSION_CODE, "message": message },
+        "id": null
+    });
+
+    let mut response = Response::new(B::from(body.to_string()));
+    *response.status_mut() = StatusCode::NOT_FOUND;
+    response
+        .headers_mut()
+        .insert(header::CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_requested_version() {
+        assert_eq!(requested_version("/"), None);
+        assert_eq!(requested_version("/health"), None);
+        assert_eq!(requested_version("/rpcs"), None);
+        assert_eq!(requested_version("/rpc"), Some(""));
+        assert_eq!(requested_version("/rpc/"), Some(""));
+        assert_eq!(requested_version("/rpc/v0_7"), Some("v0_7"));
+        assert_eq!(requested_version("/rpc/v0_6/"), Some("v0_6"));
+    }
+
+    #[test]
+    fn spec_version_to_route_version() {
+        assert_eq!(route_version("0.7.1"), "v0_7");
+        assert_eq!(route_version("0.6.0"), "v0_6");
+    }
+
+    #[test]
+    fn versioned_route_is_mapped_to_root() {
+        let uri: Uri = "http://localhost:5050/rpc/v0_7?foo=bar".parse().unwrap();
+        assert_eq!(root_uri(&uri), "http://localhost:5050/?foo=bar");
+
+        let uri: Uri = "/rpc/v0_7".parse().unwrap();
+        assert_eq!(root_uri(&uri), "/");
+    }
+
+    #[test]
+    fn unsupported_version_response_is_jsonrpc_error() {
+        let response: Response<String> = unsupported_version_response("v0_5", "v0_6, v0_7");
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let body: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert_eq!(body["error"]["code"], UNSUPPORTED_VERSION_CODE);
+        assert!(body["error"]["message"].as_str().unwrap().contains("v0_5"));
+        assert!(body["id"].is_null());
+    }
+
+    #[test]
+    fn fee_estimates_are_converted_to_v0_6() {
+        let request = json!([
+            { "jsonrpc": "2.0", "id": 1, "method": "starknet_estimateFee", "params": [] },
+            { "jsonrpc": "2.0", "id": "2", "method": "starknet_specVersion", "params": [] },
+            { "jsonrpc": "2.0", "id": 3, "method": "starknet_blockNumber", "params": [] },
+            { "jsonrpc": "2.0", "method": "starknet_specVersion", "params": [] }
+        ]);
+
+        let methods = request_methods(request.to_string().as_bytes());
+        assert_eq!(methods.len(), 3);
+
+        let estimate = json!({
+            "gas_consumed": "0x1",
+            "gas_price": "0x2",
+            "data_gas_consumed": "0x3",
+            "data_gas_price": "0x4",
+            "overall_fee": "0xe",
+            "unit": "WEI"
+        });
+
+        let response = json!([
+            { "jsonrpc": "2.0", "id": 1, "result": [estimate] },
+            { "jsonrpc": "2.0", "id": "2", "result": RPC_SPEC_VERSION },
+            { "jsonrpc": "2.0", "id": 3, "result": 5 }
+        ]);
+
+        let body = response_to_v0_6(&methods, response.to_string().as_bytes()).unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+
+        let v0_6_estimate = json!({
+            "gas_consumed": "0x1",
+            "gas_price": "0x2",
+            "overall_fee": "0xe",
+            "unit": "WEI"
+        });
+        assert_eq!(body[0]["result"], json!([v0_6_estimate]));
+        assert_eq!(body[1]["result"], V0_6_SPEC_VERSION);
+        assert_eq!(body[2]["result"], 5);
+    }
+
+    #[test]
+    fn non_jsonrpc_responses_are_not_converted() {
+        let methods = request_methods(b"not json");
+        assert!(methods.is_empty());
+
+        let methods = HashMap::from([("1".to_string(), "starknet_specVersion".to_string())]);
+        assert_eq!(response_to_v0_6(&methods, b"not json"), None);
+    }
+}
@@ -1,8 +1,21 @@
+use dojo_test_utils::migration::copy_spawn_and_move_db;
 use dojo_test_utils::sequencer::{get_default_test_config, TestSequencer};
+use dojo_utils::TransactionWaiter;
 use katana_node::config::sequencing::SequencingConfig;
-use katana_provider::traits::block::{BlockNumberProvider, BlockProvider};
+use katana_primitives::felt;
+use katana_primitives::genesis::constant::DEFAULT_ETH_FEE_TOKEN_ADDRESS;
+use katana_provider::traits::block::{BlockHashProvider, BlockNumberProvider, BlockProvider};
 use katana_provider::traits::env::BlockEnvProvider;
 use katana_rpc_api::dev::DevApiClient;
+use katana_rpc_types::state_dump::StateDump;
+use katana_rpc_types::trie::ContractStorageKeys;
+use starknet::accounts::{Account, ConnectedAccount};
+use starknet::core::types::{
+    BlockId, BlockTag, Call, Felt, MaybePendingBlockWithTxHashes, MaybePendingStateUpdate,
+};
+use starknet::core::utils::get_storage_var_address;
+use starknet::macros::selector;
+use starknet::providers::Provider;
 
 async fn create_test_sequencer() -> TestSequencer {
     TestSequencer::start(get_default_test_config(SequencingConfig::default())).await
@@ -99,6 +112,47 @@ async fn test_increase_next_block_timestamp() {
     );
 }
 
+#[tokio::test]
+async fn test_mine_blocks_with_interval() {
+    let sequencer = create_test_sequencer().await;
+    let backend = sequencer.backend();
+    let provider = backend.blockchain.provider();
+
+    let client = HttpClientBuilder::default().build(sequencer.url()).unwrap();
+
+    let latest_num = provider.latest_number().unwrap();
+    let hashes = client.mine(Some(3), Some(100)).await.unwrap();
+
+    assert_eq!(hashes.len(), 3);
+    assert_eq!(provider.latest_number().unwrap(), latest_num + 3);
+
+    let blocks = (latest_num + 1..=latest_num + 3)
+        .map(|num| provider.block(num.into()).unwrap().unwrap())
+        .collect::<Vec<_>>();
+
+    for (block, hash) in blocks.iter().zip(&hashes) {
+        assert_eq!(provider.block_hash_by_num(block.header.number).unwrap(), Some(*hash));
+    }
+
+    assert_eq!(blocks[1].header.timestamp, blocks[0].header.timestamp + 100);
+    assert_eq!(blocks[2].header.timestamp, blocks[1].header.timestamp + 100);
+
+    // mines a single block by default
+    let hashes = client.mine(None, None).await.unwrap();
+    assert_eq!(hashes.len(), 1);
+    assert_eq!(provider.latest_number().unwrap(), latest_num + 4);
+
+    // the number of blocks is bounded
+    let err = client.mine(Some(u64::MAX), None).await.unwrap_err();
+    assert!(err.to_string().contains("Too many blocks requested"));
+    assert_eq!(provider.latest_number().unwrap(), latest_num + 4);
+
+    // the block after the first one would have a timestamp overflowing u64
+    let err = client.mine(Some(2), Some(u64::MAX)).await.unwrap_err();
+    assert!(err.to_string().contains("Block timestamp overflows"));
+    assert_eq!(provider.latest_number().unwrap(), latest_num + 5);
+}
+
 #[tokio::test]
 async fn test_dev_api_enabled() {
     let sequencer = create_test_sequencer().await;
@@ -109,6 +163,345 @@ async fn test_dev_api_enabled() {
     assert!(!accounts.is_empty(), "predeployed accounts should not be empty");
 }
 
+#[tokio::test]
+async fn test_get_transaction_events() {
+    let sequencer = create_test_sequencer().await;
+    let account = sequencer.account();
+
+    let client = HttpClientBuilder::default().build(sequencer.url()).unwrap();
+
+    let recipient = felt!("0x1337");
+    let amount = felt!("0x100");
+    let call = Call {
+        to: DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(),
+        selector: selector!("transfer"),
+        calldata: vec![recipient, amount, Felt::ZERO],
+    };
+
+    let res = account.execute_v1(vec![call]).send().await.unwrap();
+    TransactionWaiter::new(res.transaction_hash, account.provider()).await.unwrap();
+
+    let events = client.get_transaction_events(res.transaction_hash).await.unwrap();
+    let transfers = events
+        .iter()
+        .filter(|e| e.from_address == DEFAULT_ETH_FEE_TOKEN_ADDRESS)
+        .collect::<Vec<_>>();
+
+    // the transfer itself, followed by the fee transfer
+    assert_eq!(transfers.len(), 2);
+    assert_eq!(transfers[0].data, vec![account.address(), recipient, amount, Felt::ZERO]);
+    assert_eq!(transfers[1].data[0], account.address());
+
+    // the fee token is a legacy class, so its events can't be decoded
+    assert!(transfers.iter().all(|e| e.decoded.is_none()));
+
+    let err = client.get_transaction_events(felt!("0x1")).await.unwrap_err();
+    assert!(err.to_string().contains("Transaction not found"));
+}
+
+#[tokio::test]
+async fn test_get_pending_transaction_events() {
+    let config =
+        get_default_test_config(SequencingConfig { no_mining: true, ..Default::default() });
+    let sequencer = TestSequencer::start(config).await;
+    let account = sequencer.account();
+
+    let client = HttpClientBuilder::default().build(sequencer.url()).unwrap();
+
+    let recipient = felt!("0x1337");
+    let amount = felt!("0x100");
+    let call = Call {
+        to: DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(),
+        selector: selector!("transfer"),
+        calldata: vec![recipient, amount, Felt::ZERO],
+    };
+
+    let res = account.execute_v1(vec![call]).send().await.unwrap();
+    TransactionWaiter::new(res.transaction_hash, account.provider()).await.unwrap();
+
+    // the transaction is only in the pending block
+    let events = client.get_transaction_events(res.transaction_hash).await.unwrap();
+    let transfers = events
+        .iter()
+        .filter(|e| e.from_address == DEFAULT_ETH_FEE_TOKEN_ADDRESS)
+        .collect::<Vec<_>>();
+
+    assert_eq!(transfers.len(), 2);
+    assert_eq!(transfers[0].data, vec![account.address(), recipient, amount, Felt::ZERO]);
+
+    // the events are the same once the pending block is mined
+    client.generate_block().await.unwrap();
+    let mined = client.get_transaction_events(res.transaction_hash).await.unwrap();
+    assert_eq!(serde_json::to_value(mined).unwrap(), serde_json::to_value(events).unwrap());
+}
+
+#[tokio::test]
+async fn test_get_transaction_fee() {
+    let sequencer = create_test_sequencer().await;
+    let account = sequencer.account();
+
+    let client = HttpClientBuilder::default().build(sequencer.url()).unwrap();
+
+    let call = Call {
+        to: DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(),
+        selector: selector!("transfer"),
+        calldata: vec![felt!("0x1337"), felt!("0x100"), Felt::ZERO],
+    };
+
+    let max_fee = felt!("0x100000000000000");
+    let res = account.execute_v1(vec![call]).max_fee(max_fee).send().await.unwrap();
+    TransactionWaiter::new(res.transaction_hash, account.provider()).await.unwrap();
+
+    let receipt = account.provider().get_transaction_receipt(res.transaction_hash).await.unwrap();
+    let actual_fee = receipt.receipt.actual_fee().clone();
+
+    let fee = client.get_transaction_fee(res.transaction_hash).await.unwrap();
+    assert_eq!(fee.max_fee, max_fee);
+    assert_eq!(fee.actual_fee, actual_fee.amount);
+    assert_eq!(fee.refund, max_fee - actual_fee.amount);
+    assert_eq!(fee.unit, actual_fee.unit);
+
+    let err = client.get_transaction_fee(felt!("0x1")).await.unwrap_err();
+    assert!(err.to_string().contains("Transaction not found"));
+}
+
+#[tokio::test]
+async fn test_get_transaction_resources() {
+    let sequencer = create_test_sequencer().await;
+    let account = sequencer.account();
+
+    let client = HttpClientBuilder::default().build(sequencer.url()).unwrap();
+
+    let call = Call {
+        to: DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(),
+        selector: selector!("transfer"),
+        calldata: vec![felt!("0x1337"), felt!("0x100"), Felt::ZERO],
+    };
+
+    let res = account.execute_v1(vec![call]).send().await.unwrap();
+    TransactionWaiter::new(res.transaction_hash, account.provider()).await.unwrap();
+
+    // the validation, execution and fee transfer calls
+    let calls = client.get_transaction_resources(res.transaction_hash).await.unwrap();
+    assert_eq!(calls.len(), 3);
+
+    let execute = &calls[1];
+    assert_eq!(execute.entry_point_selector, selector!("__execute__"));
+    assert_eq!(execute.calls.len(), 1);
+    assert_eq!(execute.calls[0].contract_address, DEFAULT_ETH_FEE_TOKEN_ADDRESS);
+    assert_eq!(execute.calls[0].entry_point_selector, selector!("transfer"));
+
+    // the account call itself uses the resources not used by the token transfer
+    let steps = execute.execution_resources.n_steps;
+    let transfer_steps = execute.calls[0].execution_resources.n_steps;
+    assert_eq!(execute.self_execution_resources.n_steps, steps - transfer_steps);
+
+    let err = client.get_transaction_resources(felt!("0x1")).await.unwrap_err();
+    assert!(err.to_string().contains("Transaction not found"));
+}
+
+#[tokio::test]
+async fn test_get_contract_storage() {
+    let sequencer = create_test_sequencer().await;
+    let account = sequencer.account();
+
+    let client = HttpClientBuilder::default().build(sequencer.url()).unwrap();
+
+    let recipient = felt!("0x1337");
+    let amount = felt!("0x100");
+    let call = Call {
+        to: DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(),
+        selector: selector!("transfer"),
+        calldata: vec![recipient, amount, Felt::ZERO],
+    };
+
+    let res = account.execute_v1(vec![call]).send().await.unwrap();
+    TransactionWaiter::new(res.transaction_hash, account.provider()).await.unwrap();
+
+    let entries = client.get_contract_storage(DEFAULT_ETH_FEE_TOKEN_ADDRESS).await.unwrap();
+    assert!(entries.windows(2).all(|w| w[0].key < w[1].key), "entries must be sorted by key");
+    assert!(entries.iter().all(|e| e.value != Felt::ZERO));
+
+    let balance_key = get_storage_var_address("ERC20_balances", &[recipient]).unwrap();
+    let balance = entries.iter().find(|e| e.key == balance_key).expect("missing balance");
+    assert_eq!(balance.value, amount);
+
+    let err = client.get_contract_storage(felt!("0xdead").into()).await.unwrap_err();
+    assert!(err.to_string().contains("Contract not found"));
+}
+
+#[tokio::test]
+async fn test_get_storages_at() {
+    let sequencer = create_test_sequencer().await;
+    let provider = sequencer.provider();
+    let client = HttpClientBuilder::default().build(sequencer.url()).unwrap();
+
+    let fee_token = DEFAULT_ETH_FEE_TOKEN_ADDRESS;
+    let account = sequencer.account().address();
+    let balance_key = get_storage_var_address("ERC20_balances", &[account]).unwrap();
+
+    let keys = vec![
+        ContractStorageKeys { address: fee_token, keys: vec![balance_key, felt!("0x1337")] },
+        ContractStorageKeys { address: account.into(), keys: vec![felt!("0x1")] },
+    ];
+
+    let block_id = BlockId::Tag(BlockTag::Latest);
+    let values = client.get_storages_at(block_id, keys.clone()).await.unwrap();
+
+    // the values must be returned in the same order as the requested keys
+    assert_eq!(values.len(), keys.len());
+    for (contract, values) in keys.iter().zip(values) {
+        assert_eq!(contract.keys.len(), values.len());
+
+        for (key, value) in contract.keys.iter().zip(values) {
+            let address = Felt::from(contract.address);
+            let expected = provider.get_storage_at(address, key, block_id).await.unwrap();
+            assert_eq!(value, expected);
+        }
+    }
+
+    let keys = vec![ContractStorageKeys { address: felt!("0x1337").into(), keys: vec![Felt::ONE] }];
+    let err = client.get_storages_at(block_id, keys.clone()).await.unwrap_err();
+    assert!(err.to_string().contains("Contract not found"));
+
+    let err = client.get_storages_at(BlockId::Number(1337), keys).await.unwrap_err();
+    assert!(err.to_string().contains("Block not found"));
+}
+
+#[tokio::test]
+async fn test_get_world_schema() {
+    let mut config = get_default_test_config(SequencingConfig::default());
+    config.db.dir = Some(copy_spawn_and_move_db().into());
+    let sequencer = TestSequencer::start(config).await;
+
+    let client = HttpClientBuilder::default().build(sequencer.url()).unwrap();
+
+    let manifest = include_str!("../../../../../examples/spawn-and-move/manifest_dev.json");
+    let manifest: serde_json::Value = serde_json::from_str(manifest).unwrap();
+    let world = Felt::from_hex(manifest["world"]["address"].as_str().unwrap()).unwrap();
+
+    let mut expected = manifest["models"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|m| {
+            let tag = m["tag"].as_str().unwrap().to_string();
+            (tag, Felt::from_hex(m["class_hash"].as_str().unwrap()).unwrap())
+        })
+        .collect::<Vec<_>>();
+    expected.sort();
+
+    // the second call only has to scan the blocks mined since the first one
+    for _ in 0..2 {
+        let models = client.get_world_schema(world.into()).await.unwrap();
+
+        let mut actual = models
+            .iter()
+            .map(|m| (format!("{}-{}", m.namespace, m.name), m.class_hash))
+            .collect::<Vec<_>>();
+        actual.sort();
+
+        assert_eq!(actual, expected);
+        assert!(models.iter().all(|m| m.schema.name == m.name));
+
+        client.generate_block().await.unwrap();
+    }
+
+    let err = client.get_world_schema(felt!("0xdead").into()).await.unwrap_err();
+    assert!(err.to_string().contains("Contract not found"));
+}
+
+#[tokio::test]
+async fn test_dump_and_load_state() {
+    let sequencer = create_test_sequencer().await;
+    let account = sequencer.account();
+    let client = HttpClientBuilder::default().build(sequencer.url()).unwrap();
+
+    let recipient = felt!("0x1337");
+    let amount = felt!("0x100");
+    let call = Call {
+        to: DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(),
+        selector: selector!("transfer"),
+        calldata: vec![recipient, amount, Felt::ZERO],
+    };
+
+    let res = account.execute_v1(vec![call]).send().await.unwrap();
+    TransactionWaiter::new(res.transaction_hash, account.provider()).await.unwrap();
+
+    let dump = client.dump_state().await.unwrap();
+
+    // load the state into a fresh node
+    let other = create_test_sequencer().await;
+    let other_client = HttpClientBuilder::default().build(other.url()).unwrap();
+    let other_provider = other.provider();
+
+    let balance_key = get_storage_var_address("ERC20_balances", &[recipient]).unwrap();
+    let fee_token = Felt::from(DEFAULT_ETH_FEE_TOKEN_ADDRESS);
+    let latest = BlockId::Tag(BlockTag::Latest);
+
+    let balance = other_provider.get_storage_at(fee_token, balance_key, latest).await.unwrap();
+    assert_eq!(balance, Felt::ZERO);
+
+    let latest_num = other_provider.block_number().await.unwrap();
+    other_client.load_state(dump).await.unwrap();
+
+    // the state is loaded in a new block without transactions
+    assert_eq!(other_provider.block_number().await.unwrap(), latest_num + 1);
+    let MaybePendingBlockWithTxHashes::Block(block) =
+        other_provider.get_block_with_tx_hashes(latest).await.unwrap()
+    else {
+        panic!("expected a confirmed block");
+    };
+    assert!(block.transactions.is_empty());
+
+    let balance = other_provider.get_storage_at(fee_token, balance_key, latest).await.unwrap();
+    assert_eq!(balance, amount);
+
+    let nonce = other_provider.get_nonce(latest, account.address()).await.unwrap();
+    assert_eq!(nonce, account.get_nonce().await.unwrap());
+
+    let invalid = StateDump(vec![1, 2, 3]);
+    let err = other_client.load_state(invalid).await.unwrap_err();
+    assert!(err.to_string().contains("Failed to load state"));
+}
+
+#[tokio::test]
+async fn test_set_nonce() {
+    let sequencer = create_test_sequencer().await;
+    let account = sequencer.account();
+    let provider = sequencer.provider();
+    let client = HttpClientBuilder::default().build(sequencer.url()).unwrap();
+
+    let nonce = felt!("0x42");
+    let latest_num = provider.block_number().await.unwrap();
+    client.set_nonce(account.address().into(), nonce).await.unwrap();
+
+    assert_eq!(account.get_nonce().await.unwrap(), nonce);
+
+    // the nonce is updated in a new block without transactions
+    assert_eq!(provider.block_number().await.unwrap(), latest_num + 1);
+    let latest = BlockId::Tag(BlockTag::Latest);
+    let MaybePendingBlockWithTxHashes::Block(block) =
+        provider.get_block_with_tx_hashes(latest).await.unwrap()
+    else {
+        panic!("expected a confirmed block");
+    };
+    assert!(block.transactions.is_empty());
+
+    let MaybePendingStateUpdate::Update(update) = provider.get_state_update(latest).await.unwrap()
+    else {
+        panic!("expected a confirmed state update");
+    };
+
+    let nonces = update.state_diff.nonces;
+    assert_eq!(nonces.len(), 1);
+    assert_eq!(nonces[0].contract_address, account.address());
+    assert_eq!(nonces[0].nonce, nonce);
+
+    let err = client.set_nonce(felt!("0xdead").into(), nonce).await.unwrap_err();
+    assert!(err.to_string().contains("Contract not found"));
+}
+
 // #[tokio::test]
 // async fn test_set_storage_at_on_instant_mode() {
 //     let sequencer = create_test_sequencer().await;
@@ -27,6 +27,8 @@ fn forking_cfg() -> ForkingConfig {
     ForkingConfig {
         url: Url::parse(SEPOLIA_URL).unwrap(),
         block: Some(BlockHashOrNumber::Num(FORK_BLOCK_NUMBER)),
+        pinned: Vec::new(),
+        snapshot: None,
     }
 }
 
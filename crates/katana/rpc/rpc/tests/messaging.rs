@@ -3,24 +3,28 @@ use std::str::FromStr;
 use std::time::Duration;
 
 use alloy::primitives::{Uint, U256};
-use alloy::providers::ProviderBuilder;
+use alloy::providers::{ProviderBuilder, WalletProvider};
 use alloy::sol;
 use anyhow::Result;
+use assert_matches::assert_matches;
 use cainome::rs::abigen;
 use dojo_test_utils::sequencer::{get_default_test_config, TestSequencer};
 use dojo_utils::TransactionWaiter;
+use jsonrpsee::http_client::HttpClientBuilder;
 use katana_messaging::MessagingConfig;
 use katana_node::config::sequencing::SequencingConfig;
 use katana_primitives::felt;
 use katana_primitives::utils::transaction::{
-    compute_l1_handler_tx_hash, compute_l1_to_l2_message_hash,
+    compute_l1_handler_tx_hash, compute_l1_to_l2_message_hash, compute_l2_to_l1_message_hash,
 };
+use katana_rpc_api::dev::DevApiClient;
 use katana_rpc_types::receipt::ReceiptBlock;
 use rand::Rng;
 use starknet::accounts::{Account, ConnectedAccount};
 use starknet::contract::ContractFactory;
 use starknet::core::types::{
-    BlockId, BlockTag, ContractClass, Felt, Hash256, MsgFromL1, Transaction, TransactionReceipt,
+    BlockId, BlockTag, Call, ContractClass, Felt, Hash256, MsgFromL1, Transaction,
+    TransactionReceipt,
 };
 use starknet::core::utils::get_contract_address;
 use starknet::macros::selector;
@@ -68,6 +72,8 @@ async fn test_messaging() {
         contract_address: core_contract.address().to_string(),
         interval: 2,
         from_block: 0,
+        // the first prefunded account of anvil, which is unlocked
+        sender_address: Some(l1_provider.default_signer_address().to_string()),
     };
 
     let mut config = get_default_test_config(SequencingConfig::default());
@@ -229,8 +235,31 @@ async fn test_messaging() {
         }
     }
 
-    // Send message from L2 to L1 testing must be done using Saya or part of
-    // it to ensure the settlement contract is test on piltover and its `update_state` method.
+    // Send message from L2 to L1
+    {
+        let recipient = Felt::from_bytes_be_slice(l1_test_contract.address().as_slice());
+        let value = felt!("0x2");
+
+        let call = Call {
+            to: l2_test_contract,
+            selector: selector!("send_message_value"),
+            calldata: vec![recipient, value],
+        };
+
+        let res = katana_account.execute_v1(vec![call]).send().await.unwrap();
+        TransactionWaiter::new(res.transaction_hash, katana_account.provider()).await.unwrap();
+
+        // Wait for the messages of the block to be sent to L1
+        tokio::time::sleep(Duration::from_secs(5)).await;
+
+        // The message hash is registered on the core contract, ready to be consumed on L1
+        let msg_hash = compute_l2_to_l1_message_hash(l2_test_contract, recipient, &[value]);
+        let count = core_contract.l2ToL1Messages(msg_hash).call().await.unwrap()._0;
+        assert_eq!(count, U256::from(1));
+    }
+
+    // Settling the messages with a proof must be tested using Saya or part of it, to ensure the
+    // settlement contract is tested on piltover and its `update_state` method.
 }
 
 #[tokio::test]
@@ -311,3 +340,87 @@ async fn estimate_message_fee() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn dev_l1_messages() -> Result<()> {
+    let config = get_default_test_config(SequencingConfig::default());
+    let sequencer = TestSequencer::start(config).await;
+
+    let account = sequencer.account();
+    let client = HttpClientBuilder::default().build(sequencer.url())?;
+
+    // Declare and deploy a contract that can send/receive messages to/from L1
+    let path = PathBuf::from("tests/test_data/cairo_l1_msg_contract.json");
+    let (contract, compiled_hash) = common::prepare_contract_declaration_params(&path)?;
+    let class_hash = contract.class_hash();
+
+    let res = account.declare_v2(contract.into(), compiled_hash).send().await?;
+    TransactionWaiter::new(res.transaction_hash, account.provider()).await?;
+
+    let res = ContractFactory::new(class_hash, &account)
+        .deploy_v1(Vec::new(), Felt::ZERO, false)
+        .send()
+        .await?;
+    TransactionWaiter::new(res.transaction_hash, account.provider()).await?;
+
+    let address = get_contract_address(Felt::ZERO, class_hash, &[], Felt::ZERO);
+
+    // No messages have been sent to L1 yet
+    assert!(client.get_pending_l1_messages().await?.is_empty());
+
+    // Send a message from L2 to L1
+    let to_address = felt!("0x1337");
+    let value = felt!("0x123");
+    let call = Call {
+        to: address,
+        selector: selector!("send_message_value"),
+        calldata: vec![to_address, value],
+    };
+
+    let res = account.execute_v1(vec![call]).send().await?;
+    TransactionWaiter::new(res.transaction_hash, account.provider()).await?;
+
+    let messages = client.get_pending_l1_messages().await?;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].from_address, address);
+    assert_eq!(messages[0].to_address, to_address);
+    assert_eq!(messages[0].payload, vec![value]);
+
+    // The messages stay pending until they're consumed
+    assert_eq!(client.get_pending_l1_messages().await?, messages);
+
+    // The hash used to consume the message must match the one computed on L1
+    let msg_hash = compute_l2_to_l1_message_hash(address, to_address, &[value]);
+    assert_eq!(messages[0].hash().as_bytes(), msg_hash.as_slice());
+
+    client.consume_l1_message(messages[0].hash()).await?;
+    assert!(client.get_pending_l1_messages().await?.is_empty());
+
+    // A message can't be consumed twice
+    let err = client.consume_l1_message(messages[0].hash()).await.unwrap_err();
+    assert!(err.to_string().contains("L1 message not found"));
+
+    // Send a message from L1 to L2
+    let msg = MsgFromL1 {
+        to_address: address,
+        payload: vec![value],
+        from_address: to_address.try_into()?,
+        entry_point_selector: selector!("msg_handler_value"),
+    };
+
+    let hash = client.send_l1_message(msg.clone().into()).await?;
+    TransactionWaiter::new(hash, account.provider()).await?;
+
+    let tx = account.provider().get_transaction_by_hash(hash).await?;
+    assert_matches!(tx, Transaction::L1Handler(tx) => {
+        assert_eq!(tx.contract_address, address);
+        assert_eq!(tx.calldata, vec![to_address, value]);
+    });
+
+    // Sending the same message again results in a different transaction
+    let other_hash = client.send_l1_message(msg.into()).await?;
+    assert_ne!(hash, other_hash);
+    TransactionWaiter::new(other_hash, account.provider()).await?;
+
+    Ok(())
+}
@@ -9,28 +9,34 @@ use common::split_felt;
 use dojo_test_utils::sequencer::{get_default_test_config, TestSequencer};
 use indexmap::IndexSet;
 use jsonrpsee::http_client::HttpClientBuilder;
+use katana_chain_spec::ChainSpec;
 use katana_node::config::sequencing::SequencingConfig;
+use katana_primitives::chain::ChainId;
 use katana_primitives::event::ContinuationToken;
 use katana_primitives::genesis::constant::{
-    DEFAULT_ACCOUNT_CLASS_HASH, DEFAULT_ETH_FEE_TOKEN_ADDRESS, DEFAULT_PREFUNDED_ACCOUNT_BALANCE,
-    DEFAULT_STRK_FEE_TOKEN_ADDRESS, DEFAULT_UDC_ADDRESS,
+    DEFAULT_ACCOUNT_CLASS_HASH, DEFAULT_ETH_FEE_TOKEN_ADDRESS, DEFAULT_LEGACY_UDC_CLASS_HASH,
+    DEFAULT_PREFUNDED_ACCOUNT_BALANCE, DEFAULT_STRK_FEE_TOKEN_ADDRESS, DEFAULT_UDC_ADDRESS,
 };
+use katana_rpc::version::V0_6_SPEC_VERSION;
 use katana_rpc_api::dev::DevApiClient;
+use katana_rpc_api::starknet::RPC_SPEC_VERSION;
 use starknet::accounts::{
-    Account, AccountError, AccountFactory, ConnectedAccount, ExecutionEncoding,
+    Account, AccountError, AccountFactory, ConnectedAccount, ExecutionEncoder, ExecutionEncoding,
     OpenZeppelinAccountFactory, SingleOwnerAccount,
 };
 use starknet::core::types::contract::legacy::LegacyContractClass;
 use starknet::core::types::{
-    BlockId, BlockTag, Call, DeclareTransactionReceipt, DeployAccountTransactionReceipt,
-    EventFilter, EventsPage, ExecutionResult, Felt, MaybePendingBlockWithReceipts,
-    MaybePendingBlockWithTxHashes, MaybePendingBlockWithTxs, MaybePendingStateUpdate,
-    StarknetError, TransactionExecutionStatus, TransactionFinalityStatus, TransactionReceipt,
-    TransactionTrace,
+    BlockId, BlockStatus, BlockTag, BroadcastedInvokeTransaction, BroadcastedInvokeTransactionV1,
+    BroadcastedTransaction, Call, DeclareTransactionReceipt, DeployAccountTransactionReceipt,
+    EventFilter, EventsPage, ExecutionResult, Felt, InvokeTransaction,
+    MaybePendingBlockWithReceipts, MaybePendingBlockWithTxHashes, MaybePendingBlockWithTxs,
+    MaybePendingStateUpdate, SimulationFlagForEstimateFee, StarknetError, Transaction,
+    TransactionExecutionStatus, TransactionFinalityStatus, TransactionReceipt, TransactionTrace,
 };
-use starknet::core::utils::get_contract_address;
+use starknet::core::utils::{get_contract_address, get_storage_var_address};
 use starknet::macros::{felt, selector};
-use starknet::providers::{Provider, ProviderError};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider, ProviderError};
 use starknet::signers::{LocalWallet, Signer, SigningKey};
 use tokio::sync::Mutex;
 
@@ -103,6 +109,50 @@ async fn declare_and_deploy_contract() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn get_compiled_casm() -> Result<()> {
+    use jsonrpsee::core::Error;
+    use jsonrpsee::types::error::CallError;
+    use katana_rpc_api::starknet::StarknetApiClient;
+
+    let sequencer =
+        TestSequencer::start(get_default_test_config(SequencingConfig::default())).await;
+
+    let account = sequencer.account();
+    let provider = sequencer.provider();
+    // `starknet-rs` doesn't yet support `starknet_getCompiledCasm`
+    let client = HttpClientBuilder::default().build(sequencer.url())?;
+
+    let path: PathBuf = PathBuf::from("tests/test_data/cairo1_contract.json");
+    let (contract, compiled_class_hash) = common::prepare_contract_declaration_params(&path)?;
+
+    let class_hash = contract.class_hash();
+    let res = account.declare_v2(contract.into(), compiled_class_hash).send().await?;
+    dojo_utils::TransactionWaiter::new(res.transaction_hash, &provider).await?;
+
+    let casm = client.get_compiled_casm(class_hash).await?;
+    assert_eq!(casm.compiled_class_hash(), compiled_class_hash);
+
+    // subsequent requests are served from the cache
+    let cached = client.get_compiled_casm(class_hash).await?;
+    assert_eq!(cached, casm);
+
+    let err = client.get_compiled_casm(felt!("0x1337")).await.expect_err("unknown class");
+    assert_matches!(err, Error::Call(CallError::Custom(e)) => {
+        assert_eq!(e.code(), 28);
+        assert_eq!(&e.message(), &"Class hash not found");
+    });
+
+    // legacy classes are not compiled to casm
+    let err = client.get_compiled_casm(DEFAULT_LEGACY_UDC_CLASS_HASH).await.expect_err("legacy");
+    assert_matches!(err, Error::Call(CallError::Custom(e)) => {
+        assert_eq!(e.code(), 62);
+        assert_eq!(&e.message(), &"The contract class version is not supported");
+    });
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn declare_and_deploy_legacy_contract() -> Result<()> {
     let sequencer =
@@ -333,6 +383,94 @@ async fn estimate_fee() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn estimate_fee_of_dependent_transactions() -> Result<()> {
+    let sequencer =
+        TestSequencer::start(get_default_test_config(SequencingConfig::default())).await;
+
+    let provider = sequencer.provider();
+    let account = sequencer.account();
+    let block_id = BlockId::Tag(BlockTag::Pending);
+
+    // spending the allowance only succeeds if the approval has been executed first
+    let amount = Uint256 { low: felt!("0x1"), high: Felt::ZERO };
+    let approve = Call {
+        to: DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(),
+        selector: selector!("approve"),
+        calldata: vec![account.address(), amount.low, amount.high],
+    };
+    let transfer_from = Call {
+        to: DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(),
+        selector: selector!("transferFrom"),
+        calldata: vec![account.address(), felt!("0x1"), amount.low, amount.high],
+    };
+
+    let invoke = |call: Call, nonce: Felt| {
+        BroadcastedTransaction::Invoke(BroadcastedInvokeTransaction::V1(
+            BroadcastedInvokeTransactionV1 {
+                nonce,
+                max_fee: Felt::ZERO,
+                signature: Vec::new(),
+                is_query: true,
+                sender_address: account.address(),
+                calldata: account.encode_calls(&[call]),
+            },
+        ))
+    };
+
+    let nonce = provider.get_nonce(block_id, account.address()).await?;
+    let txs = [invoke(approve, nonce), invoke(transfer_from, nonce + Felt::ONE)];
+    let flags = [SimulationFlagForEstimateFee::SkipValidate];
+
+    // the transactions are executed one after the other on the same state
+    let estimates = provider.estimate_fee(&txs, flags, block_id).await?;
+    assert_eq!(estimates.len(), 2);
+
+    // on its own, the second transaction fails because there is no allowance
+    let result = provider.estimate_fee(&txs[1..], flags, block_id).await;
+    assert!(result.is_err(), "estimate should fail without the approval");
+
+    // the estimation doesn't commit any state
+    assert_eq!(provider.get_nonce(block_id, account.address()).await?, nonce);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn reject_query_transactions() -> Result<()> {
+    let sequencer =
+        TestSequencer::start(get_default_test_config(SequencingConfig::default())).await;
+
+    let provider = sequencer.provider();
+    let account = sequencer.account();
+
+    let call = Call {
+        to: DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(),
+        selector: selector!("transfer"),
+        calldata: vec![felt!("0x1"), Felt::ONE, Felt::ZERO],
+    };
+
+    // query-only transactions can be estimated or simulated, but never be included in a block
+    let tx = BroadcastedInvokeTransaction::V1(BroadcastedInvokeTransactionV1 {
+        nonce: Felt::ZERO,
+        max_fee: Felt::ZERO,
+        signature: Vec::new(),
+        is_query: true,
+        sender_address: account.address(),
+        calldata: account.encode_calls(&[call]),
+    });
+
+    let flags = [SimulationFlagForEstimateFee::SkipValidate];
+    let block_id = BlockId::Tag(BlockTag::Pending);
+    let txs = [BroadcastedTransaction::Invoke(tx.clone())];
+    provider.estimate_fee(&txs, flags, block_id).await?;
+
+    let err = provider.add_invoke_transaction(tx).await.unwrap_err();
+    assert_provider_starknet_err!(err, StarknetError::UnsupportedTxVersion);
+
+    Ok(())
+}
+
 #[rstest::rstest]
 #[tokio::test(flavor = "multi_thread")]
 async fn concurrent_transactions_submissions(
@@ -556,6 +694,95 @@ async fn send_txs_with_invalid_signature(
     Ok(())
 }
 
+#[tokio::test]
+async fn spec_version() -> Result<()> {
+    let sequencer =
+        TestSequencer::start(get_default_test_config(SequencingConfig::default())).await;
+
+    let provider = sequencer.provider();
+    assert_eq!(provider.spec_version().await?, RPC_SPEC_VERSION);
+
+    // the versioned route of the supported version reports the same version
+    let url = sequencer.url().join("rpc/v0_7")?;
+    let provider = JsonRpcClient::new(HttpTransport::new(url));
+    assert_eq!(provider.spec_version().await?, RPC_SPEC_VERSION);
+
+    let url = sequencer.url().join("rpc/v0_6")?;
+    let provider = JsonRpcClient::new(HttpTransport::new(url));
+    assert_eq!(provider.spec_version().await?, V0_6_SPEC_VERSION);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn chain_id_is_used_for_transaction_hashes() -> Result<()> {
+    let chain_id = ChainId::parse("KATANA_TEST")?;
+
+    let mut config = get_default_test_config(SequencingConfig::default());
+    let ChainSpec::Dev(mut chain) = config.chain.as_ref().clone() else { unreachable!() };
+    chain.id = chain_id;
+    config.chain = Arc::new(ChainSpec::Dev(chain));
+
+    let sequencer = TestSequencer::start(config).await;
+    let provider = sequencer.provider();
+    assert_eq!(provider.chain_id().await?, chain_id.id());
+
+    let recipient = Felt::ONE;
+    let amount = Uint256 { low: Felt::ONE, high: Felt::ZERO };
+
+    // an account signing for the configured chain id can send transactions
+    let raw_account = sequencer.raw_account();
+    let mut account = SingleOwnerAccount::new(
+        sequencer.provider(),
+        LocalWallet::from(SigningKey::from_secret_scalar(raw_account.private_key)),
+        raw_account.account_address,
+        chain_id.id(),
+        ExecutionEncoding::New,
+    );
+    account.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+    let contract = Erc20Contract::new(DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(), &account);
+    let res = contract.transfer(&recipient, &amount).send().await?;
+    dojo_utils::TransactionWaiter::new(res.transaction_hash, &provider).await?;
+
+    // but not one signing for another chain
+    let account = sequencer.account();
+    let contract = Erc20Contract::new(DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(), &account);
+    let fee = felt!("0x11111111111");
+    let res = contract.transfer(&recipient, &amount).max_fee(fee).send().await;
+    assert_account_starknet_err!(res.unwrap_err(), StarknetError::ValidationFailure(_));
+
+    Ok(())
+}
+
+#[rstest::rstest]
+#[tokio::test]
+async fn reject_duplicate_transactions(
+    #[values(None, Some(1000))] block_time: Option<u64>,
+) -> Result<()> {
+    let config = get_default_test_config(SequencingConfig { block_time, ..Default::default() });
+    let sequencer = TestSequencer::start(config).await;
+
+    let provider = sequencer.provider();
+    let account = sequencer.account();
+    let contract = Erc20Contract::new(DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(), &account);
+
+    let recipient = Felt::ONE;
+    let amount = Uint256 { low: Felt::ONE, high: Felt::ZERO };
+    let fee = felt!("0x11111111111");
+    let nonce = account.get_nonce().await?;
+
+    let res = contract.transfer(&recipient, &amount).nonce(nonce).max_fee(fee).send().await?;
+    dojo_utils::TransactionWaiter::new(res.transaction_hash, &provider).await?;
+
+    // resubmitting the exact same transaction, whether it's in the pending block or already
+    // mined, is rejected.
+    let res = contract.transfer(&recipient, &amount).nonce(nonce).max_fee(fee).send().await;
+    assert_account_starknet_err!(res.unwrap_err(), StarknetError::DuplicateTx);
+
+    Ok(())
+}
+
 #[rstest::rstest]
 #[tokio::test]
 async fn send_txs_with_invalid_nonces(
@@ -625,6 +852,160 @@ async fn send_txs_with_invalid_nonces(
     Ok(())
 }
 
+#[tokio::test]
+async fn send_tx_with_calldata_over_limit() -> Result<()> {
+    let mut config = get_default_test_config(SequencingConfig::default());
+    config.execution.tx_limits.max_calldata_length = 4;
+
+    let sequencer = TestSequencer::start(config).await;
+    let account = sequencer.account();
+    let contract = Erc20Contract::new(DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(), &account);
+
+    // the encoded `transfer` call is longer than the calldata limit
+    let amount = Uint256 { low: Felt::ONE, high: Felt::ZERO };
+    let fee = felt!("0x11111111111");
+    let res = contract.transfer(&Felt::ONE, &amount).max_fee(fee).send().await;
+    assert_account_starknet_err!(res.unwrap_err(), StarknetError::ValidationFailure(_));
+
+    // the rejected transaction doesn't increment the nonce
+    assert_eq!(account.get_nonce().await?, Felt::ZERO);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn send_tx_with_unknown_entry_point() -> Result<()> {
+    let sequencer =
+        TestSequencer::start(get_default_test_config(SequencingConfig::default())).await;
+    let account = sequencer.account();
+
+    // the account contract doesn't have an entry point with this selector
+    let call = Call {
+        to: account.address(),
+        selector: selector!("not_an_entry_point"),
+        calldata: Vec::new(),
+    };
+
+    let fee = felt!("0x11111111111");
+    let res = account.execute_v1(vec![call]).max_fee(fee).send().await;
+    assert_matches!(
+        res.unwrap_err(),
+        AccountError::Provider(ProviderError::StarknetError(
+            StarknetError::ValidationFailure(reason)
+        )) if reason.contains("not found in contract")
+    );
+
+    // the rejected transaction doesn't increment the nonce
+    assert_eq!(account.get_nonce().await?, Felt::ZERO);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn multicall_revert_reports_failing_call_index() -> Result<()> {
+    let sequencer =
+        TestSequencer::start(get_default_test_config(SequencingConfig::default())).await;
+    let provider = sequencer.provider();
+    let account = sequencer.account();
+
+    let transfer = |amount_high: Felt| Call {
+        to: DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(),
+        selector: selector!("transfer"),
+        calldata: vec![Felt::ONE, Felt::ONE, amount_high],
+    };
+
+    // the second transfer exceeds the account balance
+    let calls = vec![transfer(Felt::ZERO), transfer(Felt::ONE), transfer(Felt::ZERO)];
+
+    let fee = felt!("0x11111111111");
+    let res = account.execute_v1(calls).max_fee(fee).send().await?;
+    let res = dojo_utils::TransactionWaiter::new(res.transaction_hash, &provider).await;
+    assert_matches!(
+        res.unwrap_err(),
+        dojo_utils::TransactionWaitingError::TransactionReverted(reason)
+            if reason.ends_with("\nFailed at call index 1 of the multicall.")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn send_tx_with_auto_max_fee() -> Result<()> {
+    let mut config = get_default_test_config(SequencingConfig::default());
+    config.dev.fee = true;
+    config.dev.auto_max_fee = Some(20_000);
+
+    let sequencer = TestSequencer::start(config).await;
+    let provider = sequencer.provider();
+    let account = sequencer.account();
+    let contract = Erc20Contract::new(DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(), &account);
+
+    let amount = Uint256 { low: Felt::ONE, high: Felt::ZERO };
+    let estimate = contract.transfer(&Felt::ONE, &amount).estimate_fee().await?;
+
+    // send the transaction without a max fee
+    let res = contract.transfer(&Felt::ONE, &amount).max_fee(Felt::ZERO).send().await?;
+    dojo_utils::TransactionWaiter::new(res.transaction_hash, &provider).await?;
+
+    // the transaction is still retrievable using the hash of the transaction as it was sent
+    let tx = provider.get_transaction_by_hash(res.transaction_hash).await?;
+    let Transaction::Invoke(InvokeTransaction::V1(tx)) = tx else {
+        panic!("expected an invoke v1 transaction")
+    };
+
+    // the max fee is the estimated fee padded by the multiplier
+    assert_eq!(tx.max_fee, estimate.overall_fee * Felt::TWO);
+
+    let receipt = provider.get_transaction_receipt(res.transaction_hash).await?;
+    let actual_fee = receipt.receipt.actual_fee().amount;
+    assert!(actual_fee > Felt::ZERO);
+    assert!(tx.max_fee >= actual_fee);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_events_same_order_after_restart() -> Result<()> {
+    let db_dir = tempfile::tempdir()?;
+    let mut config = get_default_test_config(SequencingConfig::default());
+    config.db.dir = Some(db_dir.path().to_path_buf());
+
+    let sequencer = TestSequencer::start(config.clone()).await;
+    let provider = sequencer.provider();
+    let account = sequencer.account();
+    let contract = Erc20Contract::new(DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(), &account);
+
+    // every transaction emits a single event and is mined in its own block
+    let mut tx_hashes = Vec::new();
+    for _ in 0..5 {
+        let amount = Uint256 { low: Felt::ONE, high: Felt::ZERO };
+        let res = contract.transfer(&Felt::ONE, &amount).send().await?;
+        dojo_utils::TransactionWaiter::new(res.transaction_hash, &provider).await?;
+        tx_hashes.push(res.transaction_hash);
+    }
+
+    let filter = EventFilter {
+        from_block: Some(BlockId::Number(1)),
+        to_block: Some(BlockId::Tag(BlockTag::Latest)),
+        address: Some(DEFAULT_ETH_FEE_TOKEN_ADDRESS.into()),
+        keys: None,
+    };
+
+    let events = provider.get_events(filter.clone(), None, 100).await?;
+    let emitted_by = events.events.iter().map(|e| e.transaction_hash).collect::<Vec<_>>();
+    assert_eq!(emitted_by, tx_hashes);
+    assert!(events.events.windows(2).all(|w| w[0].block_number < w[1].block_number));
+
+    sequencer.shutdown().await?;
+
+    // the events are returned in the same order once the node is restarted on the same database
+    let sequencer = TestSequencer::start(config).await;
+    let restarted = sequencer.provider().get_events(filter, None, 100).await?;
+    assert_eq!(restarted, events);
+
+    Ok(())
+}
+
 // TODO: write more elaborate tests for get events.
 #[tokio::test]
 async fn get_events_no_pending() -> Result<()> {
@@ -1075,6 +1456,261 @@ async fn fetch_pending_blocks() {
     }
 }
 
+#[tokio::test]
+async fn transaction_by_block_id_and_index() {
+    let config =
+        get_default_test_config(SequencingConfig { no_mining: true, ..Default::default() });
+    let sequencer = TestSequencer::start(config).await;
+
+    // create a json rpc client to interact with the dev api.
+    let dev_client = HttpClientBuilder::default().build(sequencer.url()).unwrap();
+    let provider = sequencer.provider();
+    let account = sequencer.account();
+
+    let contract = Erc20Contract::new(DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(), &account);
+    let recipient = felt!("0x1");
+    let amount = Uint256 { low: felt!("0x1"), high: Felt::ZERO };
+
+    let mut txs = Vec::new();
+    for _ in 0..2 {
+        let res = contract.transfer(&recipient, &amount).send().await.unwrap();
+        dojo_utils::TransactionWaiter::new(res.transaction_hash, &provider).await.unwrap();
+        txs.push(res.transaction_hash);
+    }
+
+    // the transactions are still in the pending block
+    let pending = BlockId::Tag(BlockTag::Pending);
+    for (index, hash) in txs.iter().enumerate() {
+        let tx = provider.get_transaction_by_block_id_and_index(pending, index as u64).await;
+        assert_eq!(tx.unwrap().transaction_hash(), hash);
+
+        let tx = provider.get_transaction_by_hash(hash).await.unwrap();
+        assert_eq!(tx.transaction_hash(), hash);
+    }
+
+    let err = provider.get_transaction_by_block_id_and_index(pending, 2).await.unwrap_err();
+    assert_provider_starknet_err!(err, StarknetError::InvalidTransactionIndex);
+
+    // close the pending block, the transactions should now be accessible from the mined block
+    dev_client.generate_block().await.unwrap();
+    let block = provider.block_hash_and_number().await.unwrap();
+
+    for block_id in [
+        BlockId::Tag(BlockTag::Latest),
+        BlockId::Hash(block.block_hash),
+        BlockId::Number(block.block_number),
+    ] {
+        for (index, hash) in txs.iter().enumerate() {
+            let tx = provider.get_transaction_by_block_id_and_index(block_id, index as u64).await;
+            assert_eq!(tx.unwrap().transaction_hash(), hash);
+        }
+
+        let err = provider.get_transaction_by_block_id_and_index(block_id, 2).await.unwrap_err();
+        assert_provider_starknet_err!(err, StarknetError::InvalidTransactionIndex);
+    }
+
+    // every transaction of the mined block must also be locatable by its hash
+    for hash in &txs {
+        let tx = provider.get_transaction_by_hash(hash).await.unwrap();
+        assert_eq!(tx.transaction_hash(), hash);
+
+        let receipt = provider.get_transaction_receipt(hash).await.unwrap();
+        assert_eq!(receipt.block.block_number(), Some(block.block_number));
+    }
+
+    // a hash that is neither in a mined block, the pending block nor the pool
+    let err = provider.get_transaction_by_hash(felt!("0x1337")).await.unwrap_err();
+    assert_provider_starknet_err!(err, StarknetError::TransactionHashNotFound);
+}
+
+#[tokio::test]
+async fn block_with_tx_hashes() {
+    let sequencer =
+        TestSequencer::start(get_default_test_config(SequencingConfig::default())).await;
+
+    let provider = sequencer.provider();
+    let account = sequencer.account();
+
+    let contract = Erc20Contract::new(DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(), &account);
+    let amount = Uint256 { low: felt!("0x1"), high: Felt::ZERO };
+
+    let res = contract.transfer(&felt!("0x1"), &amount).send().await.unwrap();
+    dojo_utils::TransactionWaiter::new(res.transaction_hash, &provider).await.unwrap();
+
+    let latest = provider.block_hash_and_number().await.unwrap();
+    let block_id = BlockId::Number(latest.block_number);
+
+    let MaybePendingBlockWithTxHashes::Block(block) =
+        provider.get_block_with_tx_hashes(block_id).await.unwrap()
+    else {
+        panic!("expected a mined block")
+    };
+
+    assert_eq!(block.status, BlockStatus::AcceptedOnL2);
+    assert_eq!(block.block_hash, latest.block_hash);
+    assert_eq!(block.block_number, latest.block_number);
+    assert_eq!(block.transactions, vec![res.transaction_hash]);
+
+    let parent_id = BlockId::Number(latest.block_number - 1);
+    let MaybePendingBlockWithTxHashes::Block(parent) =
+        provider.get_block_with_tx_hashes(parent_id).await.unwrap()
+    else {
+        panic!("expected a mined block")
+    };
+    assert_eq!(block.parent_hash, parent.block_hash);
+
+    // the header must be the same as the one returned along with the full transactions
+    let MaybePendingBlockWithTxs::Block(full) =
+        provider.get_block_with_txs(block_id).await.unwrap()
+    else {
+        panic!("expected a mined block")
+    };
+    assert_eq!(block.block_hash, full.block_hash);
+    assert_eq!(block.parent_hash, full.parent_hash);
+    assert_eq!(block.new_root, full.new_root);
+    assert_eq!(block.timestamp, full.timestamp);
+    assert_eq!(block.sequencer_address, full.sequencer_address);
+
+    // the state root must be the one committed by the block's state update
+    let MaybePendingStateUpdate::Update(update) =
+        provider.get_state_update(block_id).await.unwrap()
+    else {
+        panic!("expected a state update of a mined block")
+    };
+    assert_eq!(block.new_root, update.new_root);
+    assert_eq!(block.block_hash, update.block_hash);
+}
+
+// Calls at the pending block must see the state changes of the transactions in the pending block,
+// while calls at the latest block only see the committed state.
+#[tokio::test]
+async fn call_at_pending_block() {
+    let config =
+        get_default_test_config(SequencingConfig { no_mining: true, ..Default::default() });
+    let sequencer = TestSequencer::start(config).await;
+
+    let provider = sequencer.provider();
+    let account = sequencer.account();
+
+    let contract = Erc20Contract::new(DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(), &account);
+    let reader = Erc20ContractReader::new(DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(), &provider);
+
+    let recipient = felt!("0x1337");
+    let amount = Uint256 { low: felt!("0x100"), high: Felt::ZERO };
+
+    let pending = BlockId::Tag(BlockTag::Pending);
+    let latest = BlockId::Tag(BlockTag::Latest);
+
+    let initial = reader.balanceOf(&recipient).block_id(latest).call().await.unwrap();
+    assert_eq!(initial.low, Felt::ZERO);
+
+    let res = contract.transfer(&recipient, &amount).send().await.unwrap();
+    dojo_utils::TransactionWaiter::new(res.transaction_hash, &provider).await.unwrap();
+
+    let balance = reader.balanceOf(&recipient).block_id(pending).call().await.unwrap();
+    assert_eq!(balance.low, amount.low);
+
+    let balance = reader.balanceOf(&recipient).block_id(latest).call().await.unwrap();
+    assert_eq!(balance.low, Felt::ZERO);
+}
+
+#[tokio::test]
+async fn nonce_and_class_hash_at_pending_block() {
+    let config =
+        get_default_test_config(SequencingConfig { no_mining: true, ..Default::default() });
+    let sequencer = TestSequencer::start(config).await;
+
+    let provider = sequencer.provider();
+    let account = sequencer.account();
+
+    let pending = BlockId::Tag(BlockTag::Pending);
+    let latest = BlockId::Tag(BlockTag::Latest);
+
+    let initial_nonce = provider.get_nonce(latest, account.address()).await.unwrap();
+
+    // deploy a new instance of the default account class through the UDC
+    let ctor_args = vec![felt!("0x1337")];
+    let calldata = [
+        vec![DEFAULT_ACCOUNT_CLASS_HASH, Felt::ZERO, Felt::ZERO, Felt::from(ctor_args.len())],
+        ctor_args.clone(),
+    ]
+    .concat();
+    let address =
+        get_contract_address(Felt::ZERO, DEFAULT_ACCOUNT_CLASS_HASH, &ctor_args, Felt::ZERO);
+
+    let call =
+        Call { calldata, to: DEFAULT_UDC_ADDRESS.into(), selector: selector!("deployContract") };
+    let res = account.execute_v3(vec![call]).send().await.unwrap();
+    dojo_utils::TransactionWaiter::new(res.transaction_hash, &provider).await.unwrap();
+
+    // the deployment is only visible in the pending block
+    let class_hash = provider.get_class_hash_at(pending, address).await.unwrap();
+    assert_eq!(class_hash, DEFAULT_ACCOUNT_CLASS_HASH);
+
+    let err = provider.get_class_hash_at(latest, address).await.unwrap_err();
+    assert_provider_starknet_err!(err, StarknetError::ContractNotFound);
+
+    let nonce = provider.get_nonce(pending, account.address()).await.unwrap();
+    assert_eq!(nonce, initial_nonce + Felt::ONE);
+
+    let nonce = provider.get_nonce(latest, account.address()).await.unwrap();
+    assert_eq!(nonce, initial_nonce);
+}
+
+#[tokio::test]
+async fn state_update_of_pending_block() {
+    let config =
+        get_default_test_config(SequencingConfig { no_mining: true, ..Default::default() });
+    let sequencer = TestSequencer::start(config).await;
+
+    let provider = sequencer.provider();
+    let account = sequencer.account();
+
+    let pending = BlockId::Tag(BlockTag::Pending);
+    let latest = BlockId::Tag(BlockTag::Latest);
+
+    let MaybePendingStateUpdate::Update(latest_update) =
+        provider.get_state_update(latest).await.unwrap()
+    else {
+        panic!("expected a state update for the latest block")
+    };
+
+    let recipient = felt!("0x1337");
+    let amount = felt!("0x100");
+    let call = Call {
+        to: DEFAULT_ETH_FEE_TOKEN_ADDRESS.into(),
+        selector: selector!("transfer"),
+        calldata: vec![recipient, amount, Felt::ZERO],
+    };
+
+    let res = account.execute_v1(vec![call]).send().await.unwrap();
+    dojo_utils::TransactionWaiter::new(res.transaction_hash, &provider).await.unwrap();
+
+    let MaybePendingStateUpdate::PendingUpdate(update) =
+        provider.get_state_update(pending).await.unwrap()
+    else {
+        panic!("expected a pending state update")
+    };
+
+    // the pending diff is applied on top of the latest block
+    assert_eq!(update.old_root, latest_update.new_root);
+
+    let nonce = provider.get_nonce(pending, account.address()).await.unwrap();
+    let nonce_update =
+        update.state_diff.nonces.iter().find(|n| n.contract_address == account.address());
+    assert_eq!(nonce_update.map(|n| n.nonce), Some(nonce));
+
+    let balance_key = get_storage_var_address("ERC20_balances", &[recipient]).unwrap();
+    let fee_token_diff = update
+        .state_diff
+        .storage_diffs
+        .iter()
+        .find(|d| d.address == Felt::from(DEFAULT_ETH_FEE_TOKEN_ADDRESS))
+        .expect("missing fee token storage diff");
+    let balance = fee_token_diff.storage_entries.iter().find(|e| e.key == balance_key);
+    assert_eq!(balance.map(|e| e.value), Some(amount));
+}
+
 // Querying for pending blocks in instant mining mode will always return the last accepted block.
 #[tokio::test]
 async fn fetch_pending_blocks_in_instant_mode() {
@@ -1128,6 +1764,12 @@ async fn fetch_pending_blocks_in_instant_mode() {
         assert_eq!(block.transactions.len(), 1);
         assert_eq!(block.parent_hash, latest_block_hash);
         assert_eq!(*block.transactions[0].transaction.transaction_hash(), res.transaction_hash);
+
+        // the receipts must have the same finality status as the block they're in
+        let receipt = &block.transactions[0].receipt;
+        assert_eq!(block.status, BlockStatus::AcceptedOnL2);
+        assert_eq!(receipt.transaction_hash(), &res.transaction_hash);
+        assert_eq!(receipt.finality_status(), &TransactionFinalityStatus::AcceptedOnL2);
     } else {
         panic!("expected pending block with transaction receipts")
     }
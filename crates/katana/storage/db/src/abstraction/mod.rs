@@ -54,6 +54,27 @@ pub trait Database: Send + Sync {
         Ok(res)
     }
 
+    /// Takes a fallible function and passes a write-read transaction into it. The transaction is
+    /// only committed if the function succeeds, otherwise it's aborted and none of the writes made
+    /// by the function are persisted.
+    fn try_update<T, E, F>(&self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&Self::TxMut) -> Result<T, E>,
+        E: From<DatabaseError>,
+    {
+        let tx = self.tx_mut()?;
+        match f(&tx) {
+            Ok(res) => {
+                tx.commit()?;
+                Ok(res)
+            }
+            Err(err) => {
+                tx.abort();
+                Err(err)
+            }
+        }
+    }
+
     /// Retrieve the database statistics.
     fn stats(&self) -> Result<Self::Stats, DatabaseError>;
 }
@@ -274,6 +274,7 @@ mod tests {
     use super::*;
     use crate::abstraction::{DbCursor, DbCursorMut, DbDupSortCursor, DbTx, DbTxMut, Walker};
     use crate::codecs::Encode;
+    use crate::error::CodecError;
     use crate::mdbx::test_utils::create_test_db;
     use crate::models::storage::StorageEntry;
     use crate::tables::{BlockHashes, ContractInfo, ContractStorage, Headers, Table};
@@ -347,6 +348,26 @@ mod tests {
         assert!(result.expect(ERROR_RETURN_VALUE) == value);
     }
 
+    #[test]
+    fn db_try_update_aborts_on_error() {
+        let env = create_test_db();
+
+        // the writes made before the error are not persisted
+        let result = env.try_update(|tx| {
+            tx.put::<Headers>(1u64, Header::default())?;
+            Err::<(), _>(DatabaseError::Codec(CodecError::Decode("failed".to_string())))
+        });
+        assert!(result.is_err());
+
+        let entries = env.tx().expect(ERROR_INIT_TX).entries::<Headers>().expect(ERROR_GET);
+        assert!(entries == 0);
+
+        env.try_update(|tx| tx.put::<Headers>(1u64, Header::default())).expect(ERROR_PUT);
+
+        let entries = env.tx().expect(ERROR_INIT_TX).entries::<Headers>().expect(ERROR_GET);
+        assert!(entries == 1);
+    }
+
     #[test]
     fn db_delete() {
         let env = create_test_db();
@@ -291,16 +291,32 @@ impl<Db: Database> StateUpdateProvider for DbProvider<Db> {
                 Ok(Some((contract_address, nonce)))
             })?;
 
-            let deployed_contracts = dup_entries::<
+            let class_changes = dup_entries::<
                 Db,
                 tables::ClassChangeHistory,
-                BTreeMap<ContractAddress, ClassHash>,
+                Vec<(ContractAddress, ClassHash)>,
                 _,
             >(&db_tx, block_num, |entry| {
                 let (_, ContractClassChange { contract_address, class_hash }) = entry?;
                 Ok(Some((contract_address, class_hash)))
             })?;
 
+            let mut deployed_contracts = BTreeMap::new();
+            let mut replaced_classes = BTreeMap::new();
+
+            for (address, class_hash) in class_changes {
+                // the contract already had a class before this block, so it has been replaced
+                let change_set = db_tx.get::<tables::ContractInfoChangeSet>(address)?;
+                let is_replaced = block_num > 0
+                    && change_set.is_some_and(|c| c.class_change_list.rank(block_num - 1) > 0);
+
+                if is_replaced {
+                    replaced_classes.insert(address, class_hash);
+                } else {
+                    deployed_contracts.insert(address, class_hash);
+                }
+            }
+
             let mut declared_classes = BTreeMap::new();
             let mut deprecated_declared_classes = BTreeSet::new();
 
@@ -348,7 +364,7 @@ impl<Db: Database> StateUpdateProvider for DbProvider<Db> {
                 deployed_contracts,
                 declared_classes,
                 deprecated_declared_classes,
-                replaced_classes: BTreeMap::default(),
+                replaced_classes,
             }))
         } else {
             Ok(None)
@@ -658,7 +674,7 @@ impl<Db: Database> BlockWriter for DbProvider<Db> {
         receipts: Vec<Receipt>,
         executions: Vec<TxExecInfo>,
     ) -> ProviderResult<()> {
-        self.0.update(move |db_tx| -> ProviderResult<()> {
+        self.0.try_update(move |db_tx| -> ProviderResult<()> {
             let block_hash = block.block.hash;
             let block_number = block.block.header.number;
 
@@ -767,7 +783,13 @@ impl<Db: Database> BlockWriter for DbProvider<Db> {
 
             // update contract info
 
-            for (addr, class_hash) in states.state_updates.deployed_contracts {
+            let class_changes = states
+                .state_updates
+                .deployed_contracts
+                .into_iter()
+                .chain(states.state_updates.replaced_classes);
+
+            for (addr, class_hash) in class_changes {
                 let value = if let Some(info) = db_tx.get::<tables::ContractInfo>(addr)? {
                     GenericContractInfo { class_hash, ..info }
                 } else {
@@ -820,7 +842,7 @@ impl<Db: Database> BlockWriter for DbProvider<Db> {
             }
 
             Ok(())
-        })?
+        })
     }
 }
 
@@ -865,6 +887,7 @@ mod tests {
         BlockHashProvider, BlockNumberProvider, BlockProvider, BlockStatusProvider, BlockWriter,
     };
     use crate::traits::state::StateFactoryProvider;
+    use crate::traits::state_update::StateUpdateProvider;
     use crate::traits::transaction::TransactionProvider;
 
     fn create_dummy_block() -> SealedBlockWithStatus {
@@ -1087,4 +1110,64 @@ mod tests {
         assert_eq!(storage1, felt!("100"));
         assert_eq!(storage2, felt!("200"));
     }
+
+    #[test]
+    fn replaced_class_history() {
+        let provider = create_db_provider();
+
+        let block0 = create_dummy_block();
+        BlockWriter::insert_block_with_states_and_receipts(
+            &provider,
+            block0.clone(),
+            create_dummy_state_updates(),
+            vec![Receipt::Invoke(InvokeTxReceipt {
+                revert_error: None,
+                events: Vec::new(),
+                messages_sent: Vec::new(),
+                execution_resources: Default::default(),
+                fee: TxFeeInfo {
+                    gas_consumed: 0,
+                    gas_price: 0,
+                    overall_fee: 0,
+                    unit: PriceUnit::Wei,
+                },
+            })],
+            vec![TxExecInfo::default()],
+        )
+        .expect("failed to insert block");
+
+        let header = Header { parent_hash: block0.block.hash, number: 1, ..Default::default() };
+        let block1 = Block { header, body: Vec::new() }.seal();
+        let block1 = SealedBlockWithStatus { block: block1, status: FinalityStatus::AcceptedOnL2 };
+
+        let state_updates = StateUpdatesWithClasses {
+            state_updates: StateUpdates {
+                replaced_classes: BTreeMap::from([(address!("1"), felt!("4"))]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        BlockWriter::insert_block_with_states_and_receipts(
+            &provider,
+            block1,
+            state_updates,
+            Vec::new(),
+            Vec::new(),
+        )
+        .expect("failed to insert block");
+
+        // the class of the contract is replaced, not deployed, in block 1
+        let update0 = provider.state_update(BlockHashOrNumber::Num(0)).unwrap().unwrap();
+        let update1 = provider.state_update(BlockHashOrNumber::Num(1)).unwrap().unwrap();
+        assert_eq!(update0.deployed_contracts.get(&address!("1")), Some(&felt!("3")));
+        assert!(update0.replaced_classes.is_empty());
+        assert!(update1.deployed_contracts.is_empty());
+        assert_eq!(update1.replaced_classes, BTreeMap::from([(address!("1"), felt!("4"))]));
+
+        let latest = StateFactoryProvider::latest(&provider).unwrap();
+        let historical = provider.historical(BlockHashOrNumber::Num(0)).unwrap().unwrap();
+        assert_eq!(latest.class_hash_of_contract(address!("1")).unwrap(), Some(felt!("4")));
+        assert_eq!(historical.class_hash_of_contract(address!("1")).unwrap(), Some(felt!("3")));
+    }
 }
@@ -1,6 +1,6 @@
 use core::fmt;
 
-use katana_db::abstraction::{Database, DbCursorMut, DbDupSortCursor, DbTx, DbTxMut};
+use katana_db::abstraction::{Database, DbCursor, DbCursorMut, DbDupSortCursor, DbTx, DbTxMut};
 use katana_db::models::contract::ContractInfoChangeList;
 use katana_db::models::list::BlockList;
 use katana_db::models::storage::{ContractStorageKey, StorageEntry};
@@ -21,7 +21,7 @@ use crate::ProviderResult;
 
 impl<Db: Database> StateWriter for DbProvider<Db> {
     fn set_nonce(&self, address: ContractAddress, nonce: Nonce) -> ProviderResult<()> {
-        self.0.update(move |db_tx| -> ProviderResult<()> {
+        self.0.try_update(move |db_tx| -> ProviderResult<()> {
             let value = if let Some(info) = db_tx.get::<tables::ContractInfo>(address)? {
                 GenericContractInfo { nonce, ..info }
             } else {
@@ -29,7 +29,7 @@ impl<Db: Database> StateWriter for DbProvider<Db> {
             };
             db_tx.put::<tables::ContractInfo>(address, value)?;
             Ok(())
-        })?
+        })
     }
 
     fn set_storage(
@@ -38,7 +38,7 @@ impl<Db: Database> StateWriter for DbProvider<Db> {
         storage_key: StorageKey,
         storage_value: StorageValue,
     ) -> ProviderResult<()> {
-        self.0.update(move |db_tx| -> ProviderResult<()> {
+        self.0.try_update(move |db_tx| -> ProviderResult<()> {
             let mut cursor = db_tx.cursor_dup_mut::<tables::ContractStorage>()?;
             let entry = cursor.seek_by_key_subkey(address, storage_key)?;
 
@@ -51,7 +51,7 @@ impl<Db: Database> StateWriter for DbProvider<Db> {
 
             cursor.upsert(address, StorageEntry { key: storage_key, value: storage_value })?;
             Ok(())
-        })?
+        })
     }
 
     fn set_class_hash_of_contract(
@@ -59,7 +59,7 @@ impl<Db: Database> StateWriter for DbProvider<Db> {
         address: ContractAddress,
         class_hash: ClassHash,
     ) -> ProviderResult<()> {
-        self.0.update(move |db_tx| -> ProviderResult<()> {
+        self.0.try_update(move |db_tx| -> ProviderResult<()> {
             let value = if let Some(info) = db_tx.get::<tables::ContractInfo>(address)? {
                 GenericContractInfo { class_hash, ..info }
             } else {
@@ -67,16 +67,16 @@ impl<Db: Database> StateWriter for DbProvider<Db> {
             };
             db_tx.put::<tables::ContractInfo>(address, value)?;
             Ok(())
-        })?
+        })
     }
 }
 
 impl ContractClassWriter for DbProvider {
     fn set_class(&self, hash: ClassHash, class: ContractClass) -> ProviderResult<()> {
-        self.0.update(move |db_tx| -> ProviderResult<()> {
+        self.0.try_update(move |db_tx| -> ProviderResult<()> {
             db_tx.put::<tables::Classes>(hash, class)?;
             Ok(())
-        })?
+        })
     }
 
     fn set_compiled_class_hash_of_class_hash(
@@ -84,19 +84,19 @@ impl ContractClassWriter for DbProvider {
         hash: ClassHash,
         compiled_hash: CompiledClassHash,
     ) -> ProviderResult<()> {
-        self.0.update(move |db_tx| -> ProviderResult<()> {
+        self.0.try_update(move |db_tx| -> ProviderResult<()> {
             db_tx.put::<tables::CompiledClassHashes>(hash, compiled_hash)?;
             Ok(())
-        })?
+        })
     }
 }
 
 impl ContractClassWriterExt for DbProvider {
     fn set_compiled_class(&self, hash: ClassHash, class: CompiledClass) -> ProviderResult<()> {
-        self.0.update(move |db_tx| -> ProviderResult<()> {
+        self.0.try_update(move |db_tx| -> ProviderResult<()> {
             db_tx.put::<tables::CompiledClasses>(hash, class)?;
             Ok(())
-        })?
+        })
     }
 }
 
@@ -156,6 +156,24 @@ where
             _ => Ok(None),
         }
     }
+
+    fn storage_entries(
+        &self,
+        address: ContractAddress,
+    ) -> ProviderResult<Vec<(StorageKey, StorageValue)>> {
+        let mut cursor = self.0.cursor_dup::<tables::ContractStorage>()?;
+        let Some(walker) = cursor.walk_dup(Some(address), None)? else { return Ok(Vec::new()) };
+
+        let mut entries = Vec::new();
+        for entry in walker {
+            let (_, StorageEntry { key, value }) = entry?;
+            if value != Felt::ZERO {
+                entries.push((key, value));
+            }
+        }
+
+        Ok(entries)
+    }
 }
 
 impl<Tx> StateProofProvider for LatestStateProvider<Tx>
@@ -333,6 +351,31 @@ where
 
         Ok(None)
     }
+
+    fn storage_entries(
+        &self,
+        address: ContractAddress,
+    ) -> ProviderResult<Vec<(StorageKey, StorageValue)>> {
+        // the change sets are keyed by (contract address, storage key), so all the storage keys
+        // ever written to by the contract are adjacent and sorted.
+        let start = ContractStorageKey { contract_address: address, key: Felt::ZERO };
+        let mut cursor = self.tx.cursor::<tables::StorageChangeSet>()?;
+
+        let mut entries = Vec::new();
+        for entry in cursor.walk(Some(start))? {
+            let (ContractStorageKey { contract_address, key }, _) = entry?;
+            if contract_address != address {
+                break;
+            }
+
+            match self.storage(address, key)? {
+                Some(value) if value != Felt::ZERO => entries.push((key, value)),
+                _ => {}
+            }
+        }
+
+        Ok(entries)
+    }
 }
 
 impl<Tx> StateProofProvider for HistoricalStateProvider<Tx>
@@ -17,13 +17,17 @@ use crate::traits::state::{StateFactoryProvider, StateProvider};
 use crate::traits::trie::TrieWriter;
 use crate::ProviderResult;
 
+// NOTE: The trie updates of a block are committed in their own database transactions, separately
+// from each other and from the block itself, which is inserted by
+// `BlockWriter::insert_block_with_states_and_receipts`. A failure in between leaves the tries and
+// the stored blocks out of sync.
 impl<Db: Database> TrieWriter for DbProvider<Db> {
     fn trie_insert_declared_classes(
         &self,
         block_number: BlockNumber,
         updates: &BTreeMap<ClassHash, CompiledClassHash>,
     ) -> ProviderResult<Felt> {
-        self.0.update(|tx| {
+        self.0.try_update(|tx| -> ProviderResult<Felt> {
             let mut trie = ClassesTrie::new(TrieDbMut::<tables::ClassesTrie, _>::new(tx));
 
             for (class_hash, compiled_hash) in updates {
@@ -32,7 +36,7 @@ impl<Db: Database> TrieWriter for DbProvider<Db> {
 
             trie.commit(block_number);
             Ok(trie.root())
-        })?
+        })
     }
 
     fn trie_insert_contract_updates(
@@ -40,7 +44,7 @@ impl<Db: Database> TrieWriter for DbProvider<Db> {
         block_number: BlockNumber,
         state_updates: &StateUpdates,
     ) -> ProviderResult<Felt> {
-        self.0.update(|tx| {
+        self.0.try_update(|tx| -> ProviderResult<Felt> {
             let mut contract_trie_db =
                 ContractsTrie::new(TrieDbMut::<tables::ContractsTrie, _>::new(tx));
 
@@ -99,7 +103,7 @@ impl<Db: Database> TrieWriter for DbProvider<Db> {
 
             contract_trie_db.commit(block_number);
             Ok(contract_trie_db.root())
-        })?
+        })
     }
 }
 
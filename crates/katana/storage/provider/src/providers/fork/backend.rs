@@ -25,6 +25,7 @@ use starknet::core::types::{BlockId, ContractClass as RpcContractClass, Starknet
 use starknet::providers::{Provider, ProviderError as StarknetProviderError};
 use tracing::{error, trace};
 
+use super::state::{local_storage_entries, remote_storage_keys};
 use crate::error::ProviderError;
 use crate::providers::in_memory::cache::CacheStateDb;
 use crate::traits::contract::ContractClassProvider;
@@ -407,6 +408,41 @@ impl BackendHandle {
         }
     }
 
+    /// Fetches the storage values of multiple keys of a contract at once.
+    ///
+    /// All the requests are sent to the backend before waiting on any of the results, so that the
+    /// values are fetched concurrently instead of one round-trip at a time. The results are
+    /// returned in the same order as the given keys.
+    pub fn get_storages(
+        &self,
+        address: ContractAddress,
+        keys: &[StorageKey],
+    ) -> Result<Vec<BackendResult<StorageValue>>, BackendError> {
+        trace!(target: LOG_TARGET, %address, total = %keys.len(), "Requesting contract storages.");
+
+        let mut receivers = Vec::with_capacity(keys.len());
+        for key in keys {
+            let (req, rx) = BackendRequest::storage(address, *key);
+            self.request(req)?;
+            receivers.push(rx);
+        }
+
+        let mut results = Vec::with_capacity(receivers.len());
+        for rx in receivers {
+            match rx.recv()? {
+                BackendResponse::Storage(res) => results.push(res),
+                response => {
+                    return Err(BackendError::UnexpectedReceiveResult(Arc::new(anyhow!(
+                        "{:?}",
+                        response
+                    ))));
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
     pub fn get_class_hash_at(&self, address: ContractAddress) -> Result<ClassHash, BackendError> {
         trace!(target: LOG_TARGET, %address, "Requesting contract class hash.");
         let (req, rx) = BackendRequest::class_hash(address);
@@ -571,6 +607,45 @@ impl StateProvider for SharedStateProvider {
             Ok(None)
         }
     }
+
+    // The storage of the remote chain can't be enumerated, so only the cached entries are returned.
+    fn storage_entries(
+        &self,
+        address: ContractAddress,
+    ) -> ProviderResult<Vec<(StorageKey, StorageValue)>> {
+        Ok(local_storage_entries(self.0.storage.read().get(&address)))
+    }
+
+    // Only the keys that are not yet in the cache are fetched, in a single batch.
+    fn prefetch_storage(
+        &self,
+        address: ContractAddress,
+        keys: &[StorageKey],
+    ) -> ProviderResult<()> {
+        let keys = remote_storage_keys(self.0.storage.read().get(&address), keys);
+
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let results = self.0.get_storages(address, &keys).map_err(|error| {
+            error!(target: LOG_TARGET, %address, %error, "Prefetching storage values.");
+            error
+        })?;
+
+        let mut values = Vec::with_capacity(keys.len());
+        for (key, result) in keys.into_iter().zip(results) {
+            let value = handle_not_found_err(result).map_err(|error| {
+                error!(target: LOG_TARGET, %address, storage_key = %format!("{key:#x}"), %error, "Prefetching storage value.");
+                error
+            })?;
+            values.push((key, value.unwrap_or_default()));
+        }
+
+        self.0.storage.write().entry(address).or_default().extend(values);
+
+        Ok(())
+    }
 }
 
 impl ContractClassProvider for SharedStateProvider {
@@ -1145,6 +1220,32 @@ mod tests {
         assert_eq!(stats, 3, "Backend should only have 3 ongoing requests.")
     }
 
+    #[test]
+    fn get_storages_requests_are_sent_concurrently() {
+        // start a mock remote network
+        start_tcp_server("127.0.0.1:8088".to_string());
+
+        let handle = create_forked_backend("http://127.0.0.1:8088", 1);
+
+        // check no pending requests
+        let stats = handle.stats().expect(ERROR_STATS);
+        assert_eq!(stats, 0, "Backend should not have any ongoing requests.");
+
+        // send a batch of requests to the backend, including a duplicate key
+        let h1 = handle.clone();
+        thread::spawn(move || {
+            let keys = [felt!("0x1"), felt!("0x2"), felt!("0x3"), felt!("0x1")];
+            h1.get_storages(felt!("0x1").into(), &keys).expect(ERROR_SEND_REQUEST);
+        });
+
+        // wait for the requests to be handled
+        thread::sleep(Duration::from_secs(1));
+
+        // all the unique keys should be fetched at the same time
+        let stats = handle.stats().expect(ERROR_STATS);
+        assert_eq!(stats, 3, "Backend should have 3 ongoing requests.")
+    }
+
     #[test]
     fn get_from_cache_if_exist() {
         // setup
@@ -26,6 +26,7 @@ use self::backend::{Backend, BackendError, SharedStateProvider};
 use self::state::ForkedStateDb;
 use super::in_memory::cache::{CacheDb, CacheStateDb};
 use super::in_memory::state::HistoricalStates;
+use crate::error::ProviderError;
 use crate::traits::block::{
     BlockHashProvider, BlockNumberProvider, BlockProvider, BlockStatusProvider, BlockWriter,
     HeaderProvider,
@@ -68,7 +69,12 @@ impl ForkedProvider {
 
 impl BlockHashProvider for ForkedProvider {
     fn latest_hash(&self) -> ProviderResult<BlockHash> {
-        Ok(self.storage.read().latest_block_hash)
+        let storage = self.storage.read();
+        // avoid returning the default hash if no block (ie genesis) has been inserted yet
+        if storage.block_hashes.is_empty() {
+            return Err(ProviderError::MissingLatestBlockHash);
+        }
+        Ok(storage.latest_block_hash)
     }
 
     fn block_hash_by_num(&self, num: BlockNumber) -> ProviderResult<Option<BlockHash>> {
@@ -78,7 +84,12 @@ impl BlockHashProvider for ForkedProvider {
 
 impl BlockNumberProvider for ForkedProvider {
     fn latest_number(&self) -> ProviderResult<BlockNumber> {
-        Ok(self.storage.read().latest_block_number)
+        let storage = self.storage.read();
+        // avoid returning the default number if no block (ie genesis) has been inserted yet
+        if storage.block_hashes.is_empty() {
+            return Err(ProviderError::MissingLatestBlockNumber);
+        }
+        Ok(storage.latest_block_number)
     }
 
     fn block_number_by_hash(&self, hash: BlockHash) -> ProviderResult<Option<BlockNumber>> {
@@ -633,3 +644,28 @@ impl StageCheckpointProvider for ForkedProvider {
         unimplemented!("syncing is not supported for forked provider")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use katana_primitives::block::BlockHashOrNumber;
+    use starknet::providers::jsonrpc::HttpTransport;
+    use starknet::providers::JsonRpcClient;
+    use url::Url;
+
+    use super::ForkedProvider;
+    use crate::error::ProviderError;
+    use crate::traits::block::{BlockHashProvider, BlockNumberProvider};
+
+    #[test]
+    fn latest_block_of_empty_provider() {
+        let url = Url::parse("http://localhost:5050").expect("valid url");
+        let client = Arc::new(JsonRpcClient::new(HttpTransport::new(url)));
+        let provider = ForkedProvider::new(client, BlockHashOrNumber::Num(0)).unwrap();
+
+        // no block (not even the genesis) has been inserted yet
+        assert!(matches!(provider.latest_hash(), Err(ProviderError::MissingLatestBlockHash)));
+        assert!(matches!(provider.latest_number(), Err(ProviderError::MissingLatestBlockNumber)));
+    }
+}
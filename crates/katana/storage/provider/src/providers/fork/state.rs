@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use katana_primitives::class::{ClassHash, CompiledClassHash, ContractClass};
@@ -74,6 +75,23 @@ impl StateProvider for ForkedStateDb {
         }
         StateProvider::storage(&self.db, address, storage_key)
     }
+
+    // The storage of the remote chain can't be enumerated, so only the local entries are returned.
+    fn storage_entries(
+        &self,
+        address: ContractAddress,
+    ) -> ProviderResult<Vec<(StorageKey, StorageValue)>> {
+        Ok(local_storage_entries(self.storage.read().get(&address)))
+    }
+
+    fn prefetch_storage(
+        &self,
+        address: ContractAddress,
+        keys: &[StorageKey],
+    ) -> ProviderResult<()> {
+        let keys = remote_storage_keys(self.storage.read().get(&address), keys);
+        StateProvider::prefetch_storage(&self.db, address, &keys)
+    }
 }
 
 impl ContractClassProvider for ForkedStateDb {
@@ -120,6 +138,21 @@ impl StateProvider for LatestStateProvider {
     ) -> ProviderResult<Option<ClassHash>> {
         StateProvider::class_hash_of_contract(&self.0, address)
     }
+
+    fn storage_entries(
+        &self,
+        address: ContractAddress,
+    ) -> ProviderResult<Vec<(StorageKey, StorageValue)>> {
+        StateProvider::storage_entries(&self.0, address)
+    }
+
+    fn prefetch_storage(
+        &self,
+        address: ContractAddress,
+        keys: &[StorageKey],
+    ) -> ProviderResult<()> {
+        StateProvider::prefetch_storage(&self.0, address, keys)
+    }
 }
 
 impl ContractClassProvider for LatestStateProvider {
@@ -180,6 +213,22 @@ impl StateProvider for ForkedSnapshot {
         }
         StateProvider::class_hash_of_contract(&self.inner.db, address)
     }
+
+    fn storage_entries(
+        &self,
+        address: ContractAddress,
+    ) -> ProviderResult<Vec<(StorageKey, StorageValue)>> {
+        Ok(local_storage_entries(self.inner.storage.get(&address)))
+    }
+
+    fn prefetch_storage(
+        &self,
+        address: ContractAddress,
+        keys: &[StorageKey],
+    ) -> ProviderResult<()> {
+        let keys = remote_storage_keys(self.inner.storage.get(&address), keys);
+        StateProvider::prefetch_storage(&self.inner.db, address, &keys)
+    }
 }
 
 impl ContractClassProvider for ForkedSnapshot {
@@ -205,6 +254,29 @@ impl ContractClassProvider for ForkedSnapshot {
 impl StateProofProvider for ForkedSnapshot {}
 impl StateRootProvider for ForkedSnapshot {}
 
+/// Returns the non-zero entries of a contract's local storage, sorted by storage key.
+pub(super) fn local_storage_entries(
+    storage: Option<&HashMap<StorageKey, StorageValue>>,
+) -> Vec<(StorageKey, StorageValue)> {
+    let mut entries = storage
+        .into_iter()
+        .flatten()
+        .filter(|(_, value)| **value != StorageValue::ZERO)
+        .map(|(key, value)| (*key, *value))
+        .collect::<Vec<_>>();
+    entries.sort_unstable_by_key(|(key, _)| *key);
+    entries
+}
+
+/// Returns the keys that aren't in a contract's local storage, ie that must be read from the
+/// remote chain.
+pub(super) fn remote_storage_keys(
+    storage: Option<&HashMap<StorageKey, StorageValue>>,
+    keys: &[StorageKey],
+) -> Vec<StorageKey> {
+    keys.iter().filter(|key| storage.is_none_or(|s| !s.contains_key(key))).copied().collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
@@ -214,7 +286,9 @@ mod tests {
     use starknet::macros::felt;
 
     use super::*;
-    use crate::providers::fork::backend::test_utils::create_forked_backend;
+    use crate::providers::fork::backend::test_utils::{
+        create_forked_backend, start_mock_rpc_server,
+    };
 
     #[test]
     fn test_get_nonce() {
@@ -336,4 +410,32 @@ mod tests {
             assert_eq!(snapshot.nonce(address).unwrap(), Some(Default::default()));
         }
     }
+
+    #[test]
+    fn prefetch_only_fetches_remote_storage() {
+        let response = r#"{"jsonrpc":"2.0","result":"0x123","id":1}"#;
+        let sender = start_mock_rpc_server("127.0.0.1:8091".to_string(), response.to_string());
+        let backend = create_forked_backend("http://127.0.0.1:8091", 1);
+
+        let address = address!("1");
+        let local_key = felt!("0x1");
+        let remote_key = felt!("0x2");
+
+        let remote = SharedStateProvider::new_with_backend(backend);
+        let local = ForkedStateDb::new(remote.clone());
+        local.storage.write().entry(address).or_default().insert(local_key, felt!("0x8080"));
+
+        // let the mock server answer the single request for the remote key
+        sender.send(()).unwrap();
+        local.prefetch_storage(address, &[local_key, remote_key]).unwrap();
+
+        // the remote key is now cached, and the local one was never requested
+        let cached = remote.0.storage.read().get(&address).cloned().unwrap_or_default();
+        assert_eq!(cached.get(&remote_key), Some(&felt!("0x123")));
+        assert!(!cached.contains_key(&local_key));
+
+        // reading the prefetched slots doesn't hit the remote chain anymore
+        assert_eq!(local.storage(address, remote_key).unwrap(), Some(felt!("0x123")));
+        assert_eq!(local.storage(address, local_key).unwrap(), Some(felt!("0x8080")));
+    }
 }
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::hash::Hash;
 use std::sync::Arc;
 
 use katana_db::models::block::StoredBlockBodyIndices;
@@ -55,7 +56,13 @@ impl<Db> CacheStateDb<Db> {
             info.nonce = nonce;
         }
 
-        for (contract_address, class_hash) in updates.state_updates.deployed_contracts {
+        let class_changes = updates
+            .state_updates
+            .deployed_contracts
+            .into_iter()
+            .chain(updates.state_updates.replaced_classes);
+
+        for (contract_address, class_hash) in class_changes {
             let info = contract_state.entry(contract_address).or_default();
             info.class_hash = class_hash;
         }
@@ -75,6 +82,94 @@ impl<Db> CacheStateDb<Db> {
     }
 }
 
+/// The cached values overwritten by a state diff applied with [`CacheStateDb::apply_diff`].
+///
+/// A `None` pre-image means that the entry wasn't cached before the diff was applied, in which
+/// case reverting the diff removes it from the cache and reads fall back to the underlying
+/// database again.
+#[derive(Debug, Default)]
+pub struct StateDiffPreImage {
+    storage: HashMap<(ContractAddress, StorageKey), Option<StorageValue>>,
+    contract_state: HashMap<ContractAddress, Option<GenericContractInfo>>,
+    compiled_class_hashes: HashMap<ClassHash, Option<CompiledClassHash>>,
+}
+
+impl<Db> CacheStateDb<Db> {
+    /// Applies the given state diff to the cache with [`CacheStateDb::insert_updates`] and returns
+    /// the values it overwrote, so that the diff can later be reverted with
+    /// [`CacheStateDb::revert_diff`]. When multiple diffs are applied, they must be reverted in
+    /// the reverse order.
+    pub fn apply_diff(&self, diff: StateUpdatesWithClasses) -> StateDiffPreImage {
+        let mut pre_image = StateDiffPreImage::default();
+        let updates = &diff.state_updates;
+
+        {
+            let storage = self.storage.read();
+            let contract_state = self.contract_state.read();
+            let compiled_class_hashes = self.compiled_class_hashes.read();
+
+            let contracts = updates
+                .nonce_updates
+                .keys()
+                .chain(updates.deployed_contracts.keys())
+                .chain(updates.replaced_classes.keys());
+
+            for address in contracts {
+                let prev = contract_state.get(address).cloned();
+                pre_image.contract_state.entry(*address).or_insert(prev);
+            }
+
+            for (address, storage_changes) in &updates.storage_updates {
+                let contract_storage = storage.get(address);
+                for key in storage_changes.keys() {
+                    let prev = contract_storage.and_then(|s| s.get(key)).copied();
+                    pre_image.storage.insert((*address, *key), prev);
+                }
+            }
+
+            for hash in updates.declared_classes.keys() {
+                let prev = compiled_class_hashes.get(hash).copied();
+                pre_image.compiled_class_hashes.insert(*hash, prev);
+            }
+        }
+
+        self.insert_updates(diff);
+        pre_image
+    }
+
+    /// Reverts a state diff previously applied with [`CacheStateDb::apply_diff`], restoring the
+    /// values that were cached before it was applied.
+    ///
+    /// The classes declared by the diff are kept, as the class cache is shared with the other
+    /// states.
+    pub fn revert_diff(&self, pre_image: StateDiffPreImage) {
+        let mut storage = self.storage.write();
+        let mut contract_state = self.contract_state.write();
+        let mut compiled_class_hashes = self.compiled_class_hashes.write();
+
+        for ((contract_address, key), value) in pre_image.storage {
+            let contract_storage = storage.entry(contract_address).or_default();
+            match value {
+                Some(value) => contract_storage.insert(key, value),
+                None => contract_storage.remove(&key),
+            };
+        }
+
+        restore(&mut contract_state, pre_image.contract_state);
+        restore(&mut compiled_class_hashes, pre_image.compiled_class_hashes);
+    }
+}
+
+/// Restores the pre-images of the entries of `map`, removing the entries that didn't exist.
+fn restore<K: Hash + Eq, V>(map: &mut HashMap<K, V>, pre_images: HashMap<K, Option<V>>) {
+    for (key, value) in pre_images {
+        match value {
+            Some(value) => map.insert(key, value),
+            None => map.remove(&key),
+        };
+    }
+}
+
 #[derive(Debug)]
 pub struct CacheDb<Db> {
     pub(crate) db: Db,
@@ -152,3 +247,77 @@ impl<Db: Clone> CacheStateDb<Db> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use katana_primitives::state::StateUpdates;
+    use katana_primitives::{address, felt};
+
+    use super::*;
+
+    #[test]
+    fn test_apply_and_revert_diff() {
+        let db = CacheStateDb::new(());
+
+        let address = address!("1");
+        let deployed = address!("2");
+        let class_hash = felt!("11");
+        let key = felt!("5");
+
+        // the contract exists before any diff is applied
+        db.insert_updates(StateUpdatesWithClasses {
+            state_updates: StateUpdates {
+                nonce_updates: BTreeMap::from([(address, felt!("1"))]),
+                deployed_contracts: BTreeMap::from([(address, class_hash)]),
+                storage_updates: BTreeMap::from([(address, BTreeMap::from([(key, felt!("100"))]))]),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        let first = StateUpdatesWithClasses {
+            state_updates: StateUpdates {
+                nonce_updates: BTreeMap::from([(address, felt!("2"))]),
+                storage_updates: BTreeMap::from([(address, BTreeMap::from([(key, felt!("200"))]))]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let second = StateUpdatesWithClasses {
+            state_updates: StateUpdates {
+                deployed_contracts: BTreeMap::from([(deployed, class_hash)]),
+                storage_updates: BTreeMap::from([(address, BTreeMap::from([(key, felt!("300"))]))]),
+                declared_classes: BTreeMap::from([(class_hash, felt!("111"))]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let first = db.apply_diff(first);
+        let second = db.apply_diff(second);
+
+        let nonce = |address| db.contract_state.read().get(&address).map(|c| c.nonce);
+        let class_hash_of = |address| db.contract_state.read().get(&address).map(|c| c.class_hash);
+        let storage = || db.storage.read().get(&address).and_then(|s| s.get(&key).copied());
+        let compiled_class_hash = || db.compiled_class_hashes.read().get(&class_hash).copied();
+
+        assert_eq!(nonce(address), Some(felt!("2")));
+        assert_eq!(storage(), Some(felt!("300")));
+        assert_eq!(class_hash_of(deployed), Some(class_hash));
+        assert_eq!(compiled_class_hash(), Some(felt!("111")));
+
+        // reverting the last diff restores the values set by the first one
+        db.revert_diff(second);
+        assert_eq!(storage(), Some(felt!("200")));
+        assert_eq!(class_hash_of(deployed), None);
+        assert_eq!(compiled_class_hash(), None);
+
+        // reverting the first diff restores the values cached before
+        db.revert_diff(first);
+        assert_eq!(nonce(address), Some(felt!("1")));
+        assert_eq!(storage(), Some(felt!("100")));
+        assert_eq!(class_hash_of(address), Some(class_hash));
+    }
+}
@@ -136,6 +136,14 @@ impl StateProvider for EmptyStateProvider {
         let _ = storage_key;
         Ok(None)
     }
+
+    fn storage_entries(
+        &self,
+        address: ContractAddress,
+    ) -> ProviderResult<Vec<(StorageKey, StorageValue)>> {
+        let _ = address;
+        Ok(Vec::new())
+    }
 }
 
 impl ContractClassProvider for EmptyStateProvider {
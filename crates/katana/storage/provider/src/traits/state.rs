@@ -56,6 +56,28 @@ pub trait StateProvider:
     /// Returns the class hash of a contract.
     fn class_hash_of_contract(&self, address: ContractAddress)
     -> ProviderResult<Option<ClassHash>>;
+
+    /// Returns the non-zero storage entries of a contract, sorted by storage key.
+    ///
+    /// States that can't enumerate the whole storage of a contract, eg forked states whose
+    /// storage mostly lives on the remote chain, only return the entries that are known locally.
+    fn storage_entries(
+        &self,
+        address: ContractAddress,
+    ) -> ProviderResult<Vec<(StorageKey, StorageValue)>>;
+
+    /// Loads the values of the given storage slots of a contract ahead of reading them.
+    ///
+    /// States backed by a remote chain, eg forked states, fetch all the slots that aren't known
+    /// locally in a single batch instead of one round-trip per slot. Does nothing by default.
+    fn prefetch_storage(
+        &self,
+        address: ContractAddress,
+        keys: &[StorageKey],
+    ) -> ProviderResult<()> {
+        let _ = (address, keys);
+        Ok(())
+    }
 }
 
 /// A type which can create [`StateProvider`] for states at a particular block.
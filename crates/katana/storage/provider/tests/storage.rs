@@ -69,6 +69,20 @@ mod latest {
     ) -> Result<()> {
         assert_latest_storage_value(provider, expected_storage_entry)
     }
+
+    #[rstest::rstest]
+    fn enumerate_storage_from_db_provider(
+        #[from(provider_with_states)]
+        #[with(db_provider())]
+        provider: BlockchainProvider<DbProvider>,
+    ) -> Result<()> {
+        let state_provider = provider.latest()?;
+        let entries = state_provider.storage_entries(ContractAddress::from(felt!("1337")))?;
+        let expected =
+            vec![(felt!("1"), felt!("111")), (felt!("2"), felt!("222")), (felt!("3"), felt!("77"))];
+        assert_eq!(entries, expected);
+        Ok(())
+    }
 }
 
 mod historical {
@@ -155,4 +169,27 @@ mod historical {
     ) -> Result<()> {
         assert_historical_storage_value(provider, block_num, expected_storage_entry)
     }
+
+    #[rstest::rstest]
+    #[case::block_0(0, vec![])]
+    #[case::block_1(1, vec![(felt!("1"), felt!("100")), (felt!("2"), felt!("101"))])]
+    #[case::block_4(4, vec![(felt!("1"), felt!("111")), (felt!("2"), felt!("222"))])]
+    #[case::block_5(
+        5,
+        vec![(felt!("1"), felt!("111")), (felt!("2"), felt!("222")), (felt!("3"), felt!("77"))]
+    )]
+    fn enumerate_storage_from_db_provider(
+        #[from(provider_with_states)]
+        #[with(db_provider())]
+        provider: BlockchainProvider<DbProvider>,
+        #[case] block_num: BlockNumber,
+        #[case] expected: Vec<(StorageKey, StorageValue)>,
+    ) -> Result<()> {
+        let state_provider = provider
+            .historical(BlockHashOrNumber::Num(block_num))?
+            .expect(ERROR_CREATE_HISTORICAL_PROVIDER);
+        let entries = state_provider.storage_entries(ContractAddress::from(felt!("1337")))?;
+        assert_eq!(entries, expected);
+        Ok(())
+    }
 }
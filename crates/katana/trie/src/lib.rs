@@ -1,10 +1,15 @@
+use std::collections::{BTreeMap, HashMap};
+
 use bitvec::view::AsBits;
 pub use bonsai::{BitVec, MultiProof, Path, ProofNode};
+use bonsai_trie::databases::HashMapDb;
 use bonsai_trie::BonsaiStorage;
 pub use bonsai_trie::{BonsaiDatabase, BonsaiPersistentDatabase, BonsaiStorageConfig};
-use katana_primitives::class::ClassHash;
-use katana_primitives::Felt;
-use starknet_types_core::hash::{Pedersen, StarkHash};
+use katana_primitives::class::{ClassHash, CompiledClassHash};
+use katana_primitives::state::StateUpdates;
+use katana_primitives::{ContractAddress, Felt};
+use starknet::macros::short_string;
+use starknet_types_core::hash::{Pedersen, Poseidon, StarkHash};
 pub use {bitvec, bonsai_trie as bonsai};
 
 mod classes;
@@ -118,6 +123,73 @@ where
     Ok(bs.root_hash(IDENTIFIER).unwrap())
 }
 
+/// Computes the global state root of `state_updates` applied on an empty state.
+///
+/// The tries are only built in memory, so this can be used to know the state root of a state
+/// without committing it to the database, eg the one of a genesis block.
+pub fn compute_state_root(state_updates: &StateUpdates) -> Felt {
+    // https://docs.starknet.io/architecture-and-concepts/network-architecture/starknet-state/#state_commitment
+    Poseidon::hash_array(&[
+        short_string!("STARKNET_STATE_V0"),
+        compute_contracts_root(state_updates),
+        compute_classes_root(&state_updates.declared_classes),
+    ])
+}
+
+/// Computes the root of a classes trie that only contains the given `classes`, in memory.
+pub fn compute_classes_root(classes: &BTreeMap<ClassHash, CompiledClassHash>) -> Felt {
+    let mut trie = ClassesTrie::new(HashMapDb::<CommitId>::default());
+
+    for (class_hash, compiled_hash) in classes {
+        trie.insert(*class_hash, *compiled_hash);
+    }
+
+    trie.commit(0);
+    trie.root()
+}
+
+/// Computes the root of a contracts trie that only contains the contracts of `state_updates`, in
+/// memory.
+pub fn compute_contracts_root(state_updates: &StateUpdates) -> Felt {
+    let mut contract_leafs: HashMap<ContractAddress, ContractLeaf> = HashMap::new();
+
+    for (address, nonce) in &state_updates.nonce_updates {
+        contract_leafs.entry(*address).or_default().nonce = Some(*nonce);
+    }
+
+    for (address, class_hash) in &state_updates.deployed_contracts {
+        contract_leafs.entry(*address).or_default().class_hash = Some(*class_hash);
+    }
+
+    for (address, class_hash) in &state_updates.replaced_classes {
+        contract_leafs.entry(*address).or_default().class_hash = Some(*class_hash);
+    }
+
+    for (address, storage_entries) in &state_updates.storage_updates {
+        let mut storage_trie = StoragesTrie::new(HashMapDb::<CommitId>::default(), *address);
+
+        for (key, value) in storage_entries {
+            storage_trie.insert(*key, *value);
+        }
+
+        storage_trie.commit(0);
+        contract_leafs.entry(*address).or_default().storage_root = Some(storage_trie.root());
+    }
+
+    let mut contract_trie = ContractsTrie::new(HashMapDb::<CommitId>::default());
+
+    for (address, leaf) in contract_leafs {
+        let class_hash = leaf.class_hash.unwrap();
+        let nonce = leaf.nonce.unwrap_or_default();
+        let storage_root = leaf.storage_root.unwrap_or_default();
+        contract_trie
+            .insert(address, compute_contract_state_hash(&class_hash, &storage_root, &nonce));
+    }
+
+    contract_trie.commit(0);
+    contract_trie.root()
+}
+
 // H(H(H(class_hash, storage_root), nonce), 0), where H is the pedersen hash
 pub fn compute_contract_state_hash(
     class_hash: &ClassHash,
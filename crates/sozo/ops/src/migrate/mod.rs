@@ -591,7 +591,7 @@ where
                 if let Some((_, call)) = deployer
                     .deploy_via_udc_getcall(
                         contract.class_hash,
-                        contract.salt,
+                        Some(contract.salt),
                         &contract.raw_constructor_data,
                         Felt::ZERO,
                     )
@@ -952,7 +952,7 @@ where
                 let res = deployer
                     .deploy_via_udc(
                         self.diff.world_info.class_hash,
-                        utils::world_salt(&self.profile_config.world.seed)?,
+                        Some(utils::world_salt(&self.profile_config.world.seed)?),
                         &[self.diff.world_info.class_hash],
                         Felt::ZERO,
                     )